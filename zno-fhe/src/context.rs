@@ -80,14 +80,14 @@ impl core::fmt::Display for ConstructionError {
 }
 
 /// Define the Rust struct to represent the C++ Context class
-pub struct Context<C: FheContext+ cxx::memory::UniquePtrTarget> {
-    inner: cxx::UniquePtr<C>,
+pub struct Context<C: FheContext> {
+    inner: C,
 }
 
 // Define methods for the Rust struct Context.
 // Logic common across implementations belongs here.
-impl<C: FheContext + cxx::memory::UniquePtrTarget> Context<C> {
-    pub fn new<P: FheParameters, E: FheError>(params: P) -> Result<Self, E> {
+impl<C: FheContext> Context<C> {
+    pub fn new(params: C::P) -> Result<Self, C::E> {
         let inner = C::new(params)?;
         Ok(Self { inner })
     }
@@ -109,7 +109,7 @@ impl<C: FheContext + cxx::memory::UniquePtrTarget> Context<C> {
 // }
 
 // Implement Display for printing, debugging, etc.
-impl<C: FheContext + cxx::memory::UniquePtrTarget> core::fmt::Display for Context<C> {
+impl<C: FheContext> core::fmt::Display for Context<C> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "Context") // How this type name should appear
     }
@@ -135,33 +135,33 @@ impl<C: FheContext + cxx::memory::UniquePtrTarget> core::fmt::Display for Contex
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::builder::Builder;
     #[cfg(feature = "seal")]
-    use crate::seal::bgv::parameters::Parameters;
+    use crate::seal::builder::Builder as SealBuilder;
+    #[cfg(feature = "seal")]
+    use crate::seal::bgv::parameters::Parameters as SealParameters;
+    #[cfg(feature = "helib")]
+    use crate::helib::parameters::Parameters as HelibParameters;
 
     #[cfg(feature = "seal")]
     #[test]
     fn test_build_with_valid_builder() {
-        let params = Parameters::default();
-        let builder = Builder::new(params);
-        let context = builder.build();
+        let builder = SealBuilder::new(SealParameters::default()).expect("SEAL builder");
+        let context = builder.build_context();
         assert!(context.is_ok());
     }
 
     #[cfg(feature = "helib")]
     #[test]
     fn test_context_new_helib_bgv() {
-        let params = Parameters::default(); // Replace with actual parameters
-        let context: Context<Bgv> = Context::new(params).unwrap();
-        // Use context
+        let context: Result<Context<zno_helib_sys::prelude::Context>, _> = Context::new(HelibParameters::default());
+        assert!(context.is_ok());
     }
 
     #[cfg(feature = "seal")]
     #[test]
     fn test_context_new_seal_bgv() {
-        let params = Parameters::default(); ; // Replace with actual parameters
-        let context: Context<Schema> = Context::new(params).unwrap();
-        // Use context
+        let context: Result<Context<zno_seal_sys::bgv::Context>, _> = Context::new(SealParameters::default());
+        assert!(context.is_ok());
     }
 
     // #[ignore = "Incomplete SEAL FFI"]
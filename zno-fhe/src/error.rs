@@ -4,35 +4,45 @@ use core::fmt::Display;
 use core::fmt::Formatter;
 
 
+// Both backends define an `MError`, so it is named by its full path below.
 #[cfg(feature = "helib")]
-use crate::helib::error;
-
-#[cfg(feature = "seal")]
-use crate::seal::error;
-#[cfg(feature = "seal")]
-use crate::seal::bgv::MError;
-#[cfg(feature = "seal")]
-use crate::seal::bgv::MErrorKind;
+use crate::helib::bgv::{
+    BitsError, BootstrapError, BootstrappableError, CError, GensError,
+    MvecError, OrdsError, PError, RError,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum BGVError {
-    // BitsError(BitsError),
-    // BootstrapError(BootstrapError),
-    // BootstrappableError(BootstrappableError),
-    // CError(CError),
+    #[cfg(feature = "helib")]
+    BitsError(BitsError),
+    #[cfg(feature = "helib")]
+    BootstrapError(BootstrapError),
+    #[cfg(feature = "helib")]
+    BootstrappableError(BootstrappableError),
+    #[cfg(feature = "helib")]
+    CError(CError),
     ConstructionError(ConstructionError),
     ConversionError {
         from: &'static str,
         to: &'static str,
         reason: String,
     },
+    FFIError(FFIError),
     GenericError(GenericError),
-    // GensError(GensError),
-    MError(MError),
-    // MvecError(MvecError),
-    // OrdsError(OrdsError),
-    // PError(PError),
-    // RError(RError),
+    #[cfg(feature = "helib")]
+    GensError(GensError),
+    #[cfg(feature = "helib")]
+    HelibMError(crate::helib::bgv::MError),
+    #[cfg(feature = "helib")]
+    MvecError(MvecError),
+    #[cfg(feature = "helib")]
+    OrdsError(OrdsError),
+    #[cfg(feature = "helib")]
+    PError(PError),
+    #[cfg(feature = "helib")]
+    RError(RError),
+    #[cfg(feature = "seal")]
+    SealMError(crate::seal::bgv::MError),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -81,7 +91,7 @@ impl std::error::Error for GenericError {}
 /// A more general FFIError type represents other kinds of errors that can occur in the FFI context.
 
 // `NullPointerError`: An error type for null pointer exceptions
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct NullPointerError;
 
 impl std::error::Error for NullPointerError {}
@@ -92,11 +102,14 @@ impl Display for NullPointerError {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum FFIError {
     NullPointer(NullPointerError),
     // Other FFI-related errors can be added here
     CppException(String), // This can represent an exception thrown by C++
+    Io(String), // Reading or writing serialized data failed
+    InvalidData(String), // Deserialized data is not valid for the context
+    MissingKeySwitchMatrix(String), // A rotation needs a key-switching matrix that was not generated
     // ...
 }
 
@@ -107,34 +120,76 @@ impl Display for FFIError {
         match self {
             FFIError::NullPointer(err) => write!(f, "Null pointer error: {}", err),
             FFIError::CppException(err) => write!(f, "C++ exception: {}", err),
+            FFIError::Io(err) => write!(f, "I/O error: {}", err),
+            FFIError::InvalidData(err) => write!(f, "Invalid data: {}", err),
+            FFIError::MissingKeySwitchMatrix(err) => write!(f, "Missing key-switching matrix: {}", err),
             // other cases as needed
         }
     }
 }
 
-// impl From<BitsError> for BGVError {
-//     fn from(error: BitsError) -> BGVError {
-//         BGVError::BitsError(error)
-//     }
-// }
+// The bridge functions return `Result<_, cxx::Exception>`, so exceptions thrown
+// by the C++ library, e.g. `std::invalid_argument`, arrive here instead of aborting.
+impl From<cxx::Exception> for FFIError {
+    fn from(err: cxx::Exception) -> FFIError {
+        FFIError::CppException(err.what().to_string())
+    }
+}
 
-// impl From<BootstrapError> for BGVError {
-//     fn from(error: BootstrapError) -> BGVError {
-//         BGVError::BootstrapError(error)
-//     }
-// }
+// The safe wrappers in zno-seal-sys report the same failures with their own type.
+#[cfg(feature = "seal")]
+impl From<zno_seal_sys::bgv::FFIError> for FFIError {
+    fn from(err: zno_seal_sys::bgv::FFIError) -> FFIError {
+        match err {
+            zno_seal_sys::bgv::FFIError::NullPointer(_) => FFIError::NullPointer(NullPointerError),
+            zno_seal_sys::bgv::FFIError::CppException(what) => FFIError::CppException(what),
+            zno_seal_sys::bgv::FFIError::Io(detail) => FFIError::Io(detail),
+            zno_seal_sys::bgv::FFIError::InvalidData(detail) => FFIError::InvalidData(detail),
+        }
+    }
+}
 
-// impl From<BootstrappableError> for BGVError {
-//     fn from(error: BootstrappableError) -> BGVError {
-//         BGVError::BootstrappableError(error)
-//     }
-// }
+// The safe wrappers in zno-helib-sys report the same failures with their own type.
+#[cfg(feature = "helib")]
+impl From<zno_helib_sys::prelude::FFIError> for FFIError {
+    fn from(err: zno_helib_sys::prelude::FFIError) -> FFIError {
+        match err {
+            zno_helib_sys::prelude::FFIError::NullPointer(_) => FFIError::NullPointer(NullPointerError),
+            zno_helib_sys::prelude::FFIError::CppException(what) => FFIError::CppException(what),
+            zno_helib_sys::prelude::FFIError::MissingKeySwitchMatrix(what) => FFIError::MissingKeySwitchMatrix(what),
+            zno_helib_sys::prelude::FFIError::Io(detail) => FFIError::Io(detail),
+            zno_helib_sys::prelude::FFIError::InvalidData(detail) => FFIError::InvalidData(detail),
+        }
+    }
+}
 
-// impl From<CError> for BGVError {
-//     fn from(error: CError) -> BGVError {
-//         BGVError::CError(error)
-//     }
-// }
+#[cfg(feature = "helib")]
+impl From<BitsError> for BGVError {
+    fn from(error: BitsError) -> BGVError {
+        BGVError::BitsError(error)
+    }
+}
+
+#[cfg(feature = "helib")]
+impl From<BootstrapError> for BGVError {
+    fn from(error: BootstrapError) -> BGVError {
+        BGVError::BootstrapError(error)
+    }
+}
+
+#[cfg(feature = "helib")]
+impl From<BootstrappableError> for BGVError {
+    fn from(error: BootstrappableError) -> BGVError {
+        BGVError::BootstrappableError(error)
+    }
+}
+
+#[cfg(feature = "helib")]
+impl From<CError> for BGVError {
+    fn from(error: CError) -> BGVError {
+        BGVError::CError(error)
+    }
+}
 
 impl std::fmt::Display for BGVError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -152,23 +207,50 @@ impl From<ConstructionError> for BGVError {
     }
 }
 
+impl From<FFIError> for BGVError {
+    fn from(error: FFIError) -> BGVError {
+        BGVError::FFIError(error)
+    }
+}
+
+impl From<cxx::Exception> for BGVError {
+    fn from(error: cxx::Exception) -> BGVError {
+        BGVError::FFIError(FFIError::from(error))
+    }
+}
+
+#[cfg(feature = "seal")]
+impl From<zno_seal_sys::bgv::FFIError> for BGVError {
+    fn from(error: zno_seal_sys::bgv::FFIError) -> BGVError {
+        BGVError::FFIError(FFIError::from(error))
+    }
+}
+
+#[cfg(feature = "helib")]
+impl From<zno_helib_sys::prelude::FFIError> for BGVError {
+    fn from(error: zno_helib_sys::prelude::FFIError) -> BGVError {
+        BGVError::FFIError(FFIError::from(error))
+    }
+}
+
 impl From<GenericError> for BGVError {
     fn from(error: GenericError) -> BGVError {
         BGVError::GenericError(error)
     }
 }
 
-// impl From<GensError> for BGVError {
-//     fn from(error: GensError) -> BGVError {
-//         BGVError::GensError(error)
-//     }
-// }
+#[cfg(feature = "helib")]
+impl From<GensError> for BGVError {
+    fn from(error: GensError) -> BGVError {
+        BGVError::GensError(error)
+    }
+}
 
-impl From<MError> for BGVError {
-    fn from(error: MError) -> BGVError {
-        // BGVError::MError(error)
+#[cfg(feature = "helib")]
+impl From<crate::helib::bgv::MError> for BGVError {
+    fn from(error: crate::helib::bgv::MError) -> BGVError {
         match error.kind {
-            MErrorKind::OutOfRange(reason) => BGVError::ConversionError {
+            crate::helib::bgv::MErrorKind::OutOfRange(reason) => BGVError::ConversionError {
                 from: "i64",
                 to: "M",
                 reason,
@@ -183,29 +265,52 @@ impl From<MError> for BGVError {
     }
 }
 
-// impl From<MvecError> for BGVError {
-//     fn from(error: MvecError) -> BGVError {
-//         BGVError::MvecError(error)
-//     }
-// }
-
-// impl From<OrdsError> for BGVError {
-//     fn from(error: OrdsError) -> BGVError {
-//         BGVError::OrdsError(error)
-//     }
-// }
-
-// impl From<PError> for BGVError {
-//     fn from(error: PError) -> BGVError {
-//         BGVError::PError(error)
-//     }
-// }
-
-// impl From<RError> for BGVError {
-//     fn from(error: RError) -> BGVError {
-//         BGVError::RError(error)
-//     }
-// }
+#[cfg(feature = "seal")]
+impl From<crate::seal::bgv::MError> for BGVError {
+    fn from(error: crate::seal::bgv::MError) -> BGVError {
+        match error.kind {
+            crate::seal::bgv::MErrorKind::OutOfRange(reason) => BGVError::ConversionError {
+                from: "i64",
+                to: "M",
+                reason,
+            },
+            // Map other MErrorKind variants to appropriate BGVError
+            _ => BGVError::ConversionError {
+                from: "M",
+                to: "Metric",
+                reason: format!("{:?}", error.kind),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "helib")]
+impl From<MvecError> for BGVError {
+    fn from(error: MvecError) -> BGVError {
+        BGVError::MvecError(error)
+    }
+}
+
+#[cfg(feature = "helib")]
+impl From<OrdsError> for BGVError {
+    fn from(error: OrdsError) -> BGVError {
+        BGVError::OrdsError(error)
+    }
+}
+
+#[cfg(feature = "helib")]
+impl From<PError> for BGVError {
+    fn from(error: PError) -> BGVError {
+        BGVError::PError(error)
+    }
+}
+
+#[cfg(feature = "helib")]
+impl From<RError> for BGVError {
+    fn from(error: RError) -> BGVError {
+        BGVError::RError(error)
+    }
+}
 
 
 #[derive(Debug, Clone)]
@@ -47,18 +47,14 @@ pub trait FheError {}
 impl<S, M> Fhe<S> for M
 where
     M: FheMetric<S>,
-    S: FheScheme,
+    S: FheScheme + Into<Schema>,
 {
     fn schema(&self) -> Schema {
-        match self.get_schema() {
-            crate::seal::bgv::Schema::Bgv => Schema::Bgv,
-            // Add other cases if seal::bgv::Schema has other variants
-            _ => panic!("Unhandled schema variant"), // or return a Result and use an Err here
-        }
+        FheMetric::get_schema(self).into()
     }
 
     fn get_schema(&self) -> S {
-        self.get_schema()
+        FheMetric::get_schema(self)
     }
 }
 
@@ -76,16 +72,19 @@ pub trait Fhe<S> where S: FheScheme {
 pub trait FheScheme {}
 
 /// The `FheParameters` trait defines a type `E` that implements `std::error::Error` and a method `context`.
-/// The `context` method is generic over `C` where `C` is any type that implements `FheContext`.
+/// The `context` method is generic over `C` where `C` is any type that implements `FheContext` for these parameters.
 /// It takes `self` and returns a `Result<C, Self::E>`.
 /// The `C::new(self)` line calls the `new` method of the `FheContext` trait, which should take a `Parameters` instance and return a `C`.
 ///
 /// Note: We want `type C` to be any type that implements the `FheContext` trait, we could use a trait object.
 /// However, trait objects require dynamic dispatch and can't be used in static contexts.
 /// To keep static dispatch (which is generally more efficient), we use a generic parameter with a trait bound instead.
-pub trait FheParameters {
+pub trait FheParameters: Sized {
     type E: std::error::Error;
-    fn context<C: FheContext>(self) -> Result<C, Self::E>;
+    fn context<C>(self) -> Result<C, Self::E>
+    where
+        C: FheContext<P = Self>,
+        C::E: Into<Self::E>;
 }
 
 pub trait FheContext {
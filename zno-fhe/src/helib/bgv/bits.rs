@@ -304,9 +304,9 @@ impl From<Infallible> for BitsError {
 /// let bits = Bits::default();
 /// assert_eq!(bits.schema(), Schema::Bgv);
 /// ```
-impl Fhe for Bits {
-    fn schema(&self) -> Schema {
-        Schema::Bgv
+impl FheMetric<crate::helib::bgv::Schema> for Bits {
+    fn get_schema(&self) -> crate::helib::bgv::Schema {
+        crate::helib::bgv::Schema::Bgv
     }
 }
 
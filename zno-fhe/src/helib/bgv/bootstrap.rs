@@ -1,12 +1,11 @@
 use crate::helib::bgv::*;
 
-
-use crate::He;
-
 use std::default::Default;
 use std::fmt;
 use std::str::FromStr;
 
+use zno_helib_sys::bgv::{Ctxt, PubKey};
+
 use crate::prelude::*;
 
 /// Represents the bootstrapping mode in the BGV scheme as implemented in HElib.
@@ -87,6 +86,23 @@ impl Bootstrap {
             _ => Err(format!("'{}' is not a valid variant of Bootstrap", s)),
         }
     }
+
+    /// Refreshes the capacity of `ctxt` with this bootstrapping mode.
+    ///
+    /// - `None`: leaves `ctxt` unchanged.
+    /// - `Thin`: `PubKey::thin_re_crypt`, for ciphertexts whose slots hold constants.
+    /// - `Thick`: `PubKey::re_crypt`, for any ciphertext.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BGVError::FFIError` if `public_key` holds no bootstrapping key.
+    pub fn recrypt(&self, public_key: &PubKey, ctxt: &mut Ctxt) -> Result<(), BGVError> {
+        match self {
+            Bootstrap::None => Ok(()),
+            Bootstrap::Thin => Ok(public_key.thin_re_crypt(ctxt)?),
+            Bootstrap::Thick => Ok(public_key.re_crypt(ctxt)?),
+        }
+    }
 }
 
 impl FromStr for Bootstrap {
@@ -194,4 +210,17 @@ mod tests {
     fn test_bootstrap_new_invalid() {
         assert!(Bootstrap::new("invalid").is_err());
     }
+
+    #[test]
+    fn test_recrypt_without_bootstrapping_key() {
+        let context = zno_helib_sys::prelude::Builder::new().build().expect("BGV context creation");
+        let secret_key = crate::helib::keys::generate_keys(&context).expect("SecKey");
+        let public_key = secret_key.public_key().expect("PubKey");
+        let mut ctxt = public_key.encrypt_constant(1).expect("Ctxt");
+
+        assert!(Bootstrap::None.recrypt(&public_key, &mut ctxt).is_ok());
+        assert!(matches!(Bootstrap::Thin.recrypt(&public_key, &mut ctxt), Err(BGVError::FFIError(_))));
+        assert!(matches!(Bootstrap::Thick.recrypt(&public_key, &mut ctxt), Err(BGVError::FFIError(_))));
+        assert_eq!(secret_key.decrypt_constant(&ctxt).unwrap(), 1);
+    }
 }
@@ -1,3 +1,5 @@
+use crate::helib::bgv::*;
+
 use std::fmt;
 use core::str::FromStr;
 
@@ -89,6 +91,20 @@ impl Bootstrappable {
     }
 }
 
+/// Converts from `Bootstrappable` to `Metric`.
+///
+/// # Examples
+///
+/// ```
+/// let bootstrappable = Bootstrappable::Enabled;
+/// let metric: Metric = bootstrappable.into();
+/// ```
+impl Into<Metric> for Bootstrappable {
+    fn into(self) -> Metric {
+        Metric::Bootstrappable(self)
+    }
+}
+
 impl Default for Bootstrappable {
     fn default() -> Self {
         Bootstrappable::None
@@ -295,9 +295,9 @@ impl From<Infallible> for CError {
 /// let c = C::default();
 /// assert_eq!(c.schema(), Schema::Bgv);
 /// ```
-impl Fhe for C {
-    fn schema(&self) -> Schema {
-        Schema::Bgv
+impl FheMetric<crate::helib::bgv::Schema> for C {
+    fn get_schema(&self) -> crate::helib::bgv::Schema {
+        crate::helib::bgv::Schema::Bgv
     }
 }
 
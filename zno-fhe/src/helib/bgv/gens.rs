@@ -1,3 +1,5 @@
+use crate::helib::bgv::*;
+
 use std::num::{NonZeroU32, ParseIntError};
 use std::fmt;
 use std::str::FromStr;
@@ -60,6 +62,7 @@ pub struct GensError {
 pub enum GensErrorKind {
     Zero,
     ParseError(ParseIntError),
+    OutOfRange(String),
     // You can add other error kinds if necessary
 }
 
@@ -78,6 +81,11 @@ impl Gens {
 
         Ok(Gens(non_zero_values))
     }
+
+    /// Returns the values as plain integers, in order.
+    pub fn to_vec(&self) -> Vec<u32> {
+        self.0.iter().map(|value| value.get()).collect()
+    }
 }
 
 /// Provides a default `Gens` value.
@@ -95,6 +103,25 @@ impl From<ParseIntError> for GensError {
     }
 }
 
+/// Converts the generators a HElib `Context` reports, as `i64`, into `Gens`.
+///
+/// # Errors
+///
+/// Returns `GensErrorKind::OutOfRange` if a value does not fit in a `u32`,
+/// and `GensErrorKind::Zero` if a value is zero.
+impl TryFrom<Vec<i64>> for Gens {
+    type Error = GensError;
+
+    fn try_from(values: Vec<i64>) -> Result<Self, Self::Error> {
+        let values = values.into_iter()
+            .map(|value| u32::try_from(value).map_err(|_| GensError {
+                kind: GensErrorKind::OutOfRange(format!("Value {} is out of range for Gens", value)),
+            }))
+            .collect::<Result<Vec<_>, _>>()?;
+        Gens::new(values)
+    }
+}
+
 impl FromStr for Gens {
     type Err = GensError;
 
@@ -138,6 +165,7 @@ impl core::fmt::Display for GensError {
         match &self.kind {
             GensErrorKind::Zero => write!(f, "zero is not allowed"),
             GensErrorKind::ParseError(e) => e.fmt(f),
+            GensErrorKind::OutOfRange(s) => write!(f, "{}", s),
             // Handle other kinds of errors if they are added in the future
         }
     }
@@ -153,6 +181,12 @@ mod tests {
         assert!(gens.is_ok());
     }
 
+    #[test]
+    fn test_to_vec() {
+        let gens = Gens::new(vec![2341, 3277, 911]).unwrap();
+        assert_eq!(gens.to_vec(), vec![2341, 3277, 911]);
+    }
+
     #[test]
     fn test_invalid_gens_values() {
         let gens = Gens::new(vec![0, 5, 7]);
@@ -170,4 +204,11 @@ mod tests {
         let gens: Result<Gens, _> = "3,-5,7".parse();
         assert!(matches!(gens, Err(_)));
     }
+
+    #[test]
+    fn test_try_from_i64_vec() {
+        assert_eq!(Gens::try_from(vec![2_i64, 3, 5]).unwrap().to_vec(), vec![2, 3, 5]);
+        assert!(matches!(Gens::try_from(vec![2_i64, -3]), Err(GensError { kind: GensErrorKind::OutOfRange(_) })));
+        assert!(matches!(Gens::try_from(vec![2_i64, 0]), Err(GensError { kind: GensErrorKind::Zero })));
+    }
 }
@@ -313,9 +313,9 @@ impl From<Infallible> for MError {
 /// let m = M::default();
 /// assert_eq!(m.schema(), Schema::Bgv);
 /// ```
-impl Fhe for M {
-    fn schema(&self) -> Schema {
-        Schema::Bgv
+impl FheMetric<crate::helib::bgv::Schema> for M {
+    fn get_schema(&self) -> crate::helib::bgv::Schema {
+        crate::helib::bgv::Schema::Bgv
     }
 }
 
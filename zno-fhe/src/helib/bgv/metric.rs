@@ -0,0 +1,23 @@
+use super::m::M;
+use super::p::P;
+use super::r::R;
+use super::c::C;
+use super::bits::Bits;
+use super::gens::Gens;
+use super::ords::Ords;
+use super::mvec::Mvec;
+use super::bootstrap::Bootstrap;
+use super::bootstrappable::Bootstrappable;
+
+pub enum Metric {
+    Bits(Bits),
+    Bootstrap(Bootstrap),
+    Bootstrappable(Bootstrappable),
+    C(C),
+    Gens(Gens),
+    M(M),
+    Mvec(Mvec),
+    Ords(Ords),
+    P(P),
+    R(R),
+}
@@ -14,7 +14,7 @@ pub mod bootstrappable;
 // pub mod error;
 // pub mod getters;
 // pub mod setters;
-// pub mod metric;
+pub mod metric;
 
 // Re-export the types for external use as `crate::bgv::<type>`
 pub use self::m::*;
@@ -32,7 +32,9 @@ pub use self::bootstrappable::*;
 // pub use self::error::*;
 // pub use self::getters::*;
 // pub use self::setters::*;
-// pub use self::metric::*;
+pub use self::metric::*;
+
+use crate::prelude::*;
 
 /// Enumerates the various homomorphic encryption schemas available.
 ///
@@ -45,55 +47,32 @@ pub use self::bootstrappable::*;
 /// # use your_crate::Schema;
 /// let schema = Schema::Bgv;
 /// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Schema {
     /// The Brakerski-Gentry-Vaikuntanathan (BGV) scheme.
     Bgv,
     // Bfv, // The Brakerski/Fan-Vercauteren (BFV) scheme.
-    // Ckks, // The Cheon-Kim-Kim-Song (CKKS) scheme.
+    /// The Cheon-Kim-Kim-Song (CKKS) scheme, built with `zno_helib_sys::CKKSBuilder`.
+    Ckks,
 }
 
-/// A trait for types that represent a homomorphic encryption scheme
-/// and can be converted into a `Metric`.
-///
-/// Types implementing `Scheme` can be used with structures or functions
-/// expecting a homomorphic encryption context, and they can also be
-/// converted into a `Metric` to provide a standardized interface for
-/// metrics or statistics collection.
-///
-/// # Examples
-///
-/// Assuming `Bgv` is a struct representing the BGV scheme and `Metric` is
-/// an enum that can represent various aspects of schemes:
-///
-/// ```
-/// # use crate::{He, Scheme, Schema, Metric};
-/// struct Bgv;
-///
-/// impl Fhe for Bgv {
-///     fn schema(&self) -> Schema {
-///         Schema::Bgv
-///     }
-/// }
-///
-/// // Assume Metric has a variant for BGV
-/// impl From<Bgv> for Metric {
-///     fn from(_: Bgv) -> Self {
-///         Metric::BgvMetrics { /* ... */ }
-///     }
-/// }
-///
-/// impl Scheme for Bgv {}
-///
-/// // Now Bgv can be used wherever Scheme is required
-/// ```
-///
-/// # Errors
-///
-/// Implementations should ensure that the conversion to `Metric` via
-/// `Into<Metric>` does not fail. If a failure is possible, it is advised
-/// to implement `TryInto` trait and provide details in the implementation's
-/// documentation.
-pub trait Scheme: He + Into<Metric> {}
+/// Returns the default value for `Schema`, which is `Schema::Bgv`.
+impl Default for Schema {
+    fn default() -> Self {
+        Schema::Bgv
+    }
+}
+
+impl FheScheme for Schema {}
+
+impl From<Schema> for crate::fhe::Schema {
+    fn from(schema: Schema) -> Self {
+        match schema {
+            Schema::Bgv => crate::fhe::Schema::Bgv,
+            Schema::Ckks => crate::fhe::Schema::Ckks,
+        }
+    }
+}
 
 // Implement the Scheme marker trait for required types
 // This enforces implementation of required traits: He, Into<Metric>
@@ -102,7 +81,7 @@ pub trait Scheme: He + Into<Metric> {}
 // impl Scheme for Bootstrappable {}
 // impl Scheme for C {}
 // impl Scheme for Gens {}
-impl Scheme for M {}
+// impl Scheme for M {}
 // impl Scheme for Mvec {}
 // impl Scheme for Ords {}
 // impl Scheme for P {}
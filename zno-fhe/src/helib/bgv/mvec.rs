@@ -1,3 +1,5 @@
+use crate::helib::bgv::*;
+
 use std::num::{NonZeroU32, ParseIntError};
 use std::fmt;
 use std::str::FromStr;
@@ -64,6 +66,7 @@ pub struct MvecError {
 pub enum MvecErrorKind {
     ZeroValue,
     ParseError(ParseIntError),
+    OutOfRange(String),
 }
 
 impl Mvec {
@@ -81,6 +84,11 @@ impl Mvec {
 
         Ok(Mvec { values: non_zero_values })
     }
+
+    /// Returns the values as plain integers, in order.
+    pub fn to_vec(&self) -> Vec<u32> {
+        self.values.iter().map(|value| value.get()).collect()
+    }
 }
 
 /// Provides a default `Mvec` value.
@@ -117,10 +125,30 @@ impl core::fmt::Display for MvecError {
         match &self.kind {
             MvecErrorKind::ZeroValue => write!(f, "zero value in mvec is not allowed"),
             MvecErrorKind::ParseError(e) => e.fmt(f),
+            MvecErrorKind::OutOfRange(s) => write!(f, "{}", s),
         }
     }
 }
 
+/// Converts the factors a HElib `Context` reports, as `i64`, into `Mvec`.
+///
+/// # Errors
+///
+/// Returns `MvecErrorKind::OutOfRange` if a value does not fit in a `u32`,
+/// and `MvecErrorKind::ZeroValue` if a value is zero.
+impl TryFrom<Vec<i64>> for Mvec {
+    type Error = MvecError;
+
+    fn try_from(values: Vec<i64>) -> Result<Self, Self::Error> {
+        let values = values.into_iter()
+            .map(|value| u32::try_from(value).map_err(|_| MvecError {
+                kind: MvecErrorKind::OutOfRange(format!("Value {} is out of range for Mvec", value)),
+            }))
+            .collect::<Result<Vec<_>, _>>()?;
+        Mvec::new(values)
+    }
+}
+
 impl FromStr for Mvec {
     type Err = MvecError;
 
@@ -164,6 +192,12 @@ mod tests {
         assert!(matches!(mvec, Ok(_)));
     }
 
+    #[test]
+    fn test_to_vec() {
+        let mvec = Mvec::new(vec![7, 5, 9, 13]).unwrap();
+        assert_eq!(mvec.to_vec(), vec![7, 5, 9, 13]);
+    }
+
     #[test]
     fn test_invalid_mvec_value() {
         let mvec = Mvec::new(vec![0, 3, 5]);
@@ -175,4 +209,11 @@ mod tests {
         let mvec = "[2, 3, 5]".parse::<Mvec>();
         assert!(matches!(mvec, Ok(_)));
     }
+
+    #[test]
+    fn test_try_from_i64_vec() {
+        assert_eq!(Mvec::try_from(vec![2_i64, 3, 5]).unwrap().to_vec(), vec![2, 3, 5]);
+        assert!(matches!(Mvec::try_from(vec![2_i64, -3]), Err(MvecError { kind: MvecErrorKind::OutOfRange(_) })));
+        assert!(matches!(Mvec::try_from(vec![2_i64, 0]), Err(MvecError { kind: MvecErrorKind::ZeroValue })));
+    }
 }
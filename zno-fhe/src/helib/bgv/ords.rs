@@ -1,3 +1,5 @@
+use crate::helib::bgv::*;
+
 use core::str::FromStr;
 use std::fmt;
 use std::num::{NonZeroU32, ParseIntError};
@@ -68,6 +70,7 @@ pub struct OrdsError {
 pub enum OrdsErrorKind {
     Zero,
     ParseError(ParseIntError),
+    OutOfRange(String),
 }
 
 impl Ords {
@@ -85,6 +88,11 @@ impl Ords {
 
         Ok(Ords { values: non_zero_values })
     }
+
+    /// Returns the values as plain integers, in order.
+    pub fn to_vec(&self) -> Vec<u32> {
+        self.values.iter().map(|value| value.get()).collect()
+    }
 }
 
 /// Provides a default `Ords` value.
@@ -102,6 +110,25 @@ impl From<ParseIntError> for OrdsError {
     }
 }
 
+/// Converts the orders a HElib `Context` reports, as `i64`, into `Ords`.
+///
+/// # Errors
+///
+/// Returns `OrdsErrorKind::OutOfRange` if a value does not fit in a `u32`,
+/// and `OrdsErrorKind::Zero` if a value is zero.
+impl TryFrom<Vec<i64>> for Ords {
+    type Error = OrdsError;
+
+    fn try_from(values: Vec<i64>) -> Result<Self, Self::Error> {
+        let values = values.into_iter()
+            .map(|value| u32::try_from(value).map_err(|_| OrdsError {
+                kind: OrdsErrorKind::OutOfRange(format!("Value {} is out of range for Ords", value)),
+            }))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ords::new(values)
+    }
+}
+
 // Implementing the FromStr trait for Ords, assuming a comma-separated list of numbers in a string
 impl FromStr for Ords {
     type Err = OrdsError;
@@ -153,6 +180,7 @@ impl core::fmt::Display for OrdsError {
         match &self.kind {
             OrdsErrorKind::Zero => write!(f, "zero is not allowed in ords"),
             OrdsErrorKind::ParseError(e) => e.fmt(f),
+            OrdsErrorKind::OutOfRange(s) => write!(f, "{}", s),
         }
     }
 }
@@ -167,6 +195,12 @@ mod tests {
         assert!(ords.is_ok());
     }
 
+    #[test]
+    fn test_to_vec() {
+        let ords = Ords::new(vec![6, 4, 6]).unwrap();
+        assert_eq!(ords.to_vec(), vec![6, 4, 6]);
+    }
+
     #[test]
     fn test_invalid_ords_values() {
         let ords = Ords::new(vec![0, 3, 5]);
@@ -184,4 +218,11 @@ mod tests {
         let ords: Result<Ords, _> = "[-1, 2, 3]".parse();
         assert!(ords.is_err());
     }
+
+    #[test]
+    fn test_try_from_i64_vec() {
+        assert_eq!(Ords::try_from(vec![2_i64, 3, 5]).unwrap().to_vec(), vec![2, 3, 5]);
+        assert!(matches!(Ords::try_from(vec![2_i64, -3]), Err(OrdsError { kind: OrdsErrorKind::OutOfRange(_) })));
+        assert!(matches!(Ords::try_from(vec![2_i64, 0]), Err(OrdsError { kind: OrdsErrorKind::Zero })));
+    }
 }
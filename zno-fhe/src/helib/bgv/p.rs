@@ -259,9 +259,9 @@ impl From<Infallible> for PError {
 /// let p = P::default();
 /// assert_eq!(p.schema(), Schema::Bgv);
 /// ```
-impl Fhe for P {
-    fn schema(&self) -> Schema {
-        Schema::Bgv
+impl FheMetric<crate::helib::bgv::Schema> for P {
+    fn get_schema(&self) -> crate::helib::bgv::Schema {
+        crate::helib::bgv::Schema::Bgv
     }
 }
 
@@ -278,9 +278,9 @@ impl From<Infallible> for RError {
 /// let r = R::default();
 /// assert_eq!(r.schema(), Schema::Bgv);
 /// ```
-impl Fhe for R {
-    fn schema(&self) -> Schema {
-        Schema::Bgv
+impl FheMetric<crate::helib::bgv::Schema> for R {
+    fn get_schema(&self) -> crate::helib::bgv::Schema {
+        crate::helib::bgv::Schema::Bgv
     }
 }
 
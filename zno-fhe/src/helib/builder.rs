@@ -0,0 +1,257 @@
+// Logic specific to the HElib BGV implementation belongs here.
+// `zno_helib_sys::prelude::Builder` takes plain integers; the setters below
+// take the validated parameter types of `crate::helib::bgv` instead.
+use zno_helib_sys::helib::bgv::ffi;
+use zno_helib_sys::prelude::Builder;
+
+use crate::helib::bgv::*;
+use crate::helib::setters::{SetError, Setters};
+use crate::prelude::*;
+
+// Delegate to the setter for each parameter.
+fn metric_set(builder: Builder, metric: Metric) -> Result<Builder, BGVError> {
+    match metric {
+        Metric::Bits(value) => builder.set_bits(value),
+        Metric::Bootstrap(value) => set_bootstrap(builder, value),
+        Metric::Bootstrappable(value) => set_bootstrappable(builder, value),
+        Metric::C(value) => builder.set_c(value),
+        Metric::Gens(value) => builder.set_gens(value),
+        Metric::M(value) => builder.set_m(value),
+        Metric::Mvec(value) => builder.set_mvec(value),
+        Metric::Ords(value) => builder.set_ords(value),
+        Metric::P(value) => builder.set_p(value),
+        Metric::R(value) => builder.set_r(value),
+    }
+}
+
+// `Bootstrap::None` keeps HElib's default, thin bootstrapping.
+// Either kind only takes effect once the context is bootstrappable.
+fn set_bootstrap(mut builder: Builder, value: Bootstrap) -> Result<Builder, BGVError> {
+    builder.inner = match value {
+        Bootstrap::None => builder.inner,
+        Bootstrap::Thin => ffi::set_thinboot(builder.inner)?,
+        Bootstrap::Thick => ffi::set_thickboot(builder.inner)?,
+    };
+    Ok(builder)
+}
+
+// `Bootstrappable::None` keeps HElib's default, not bootstrappable.
+fn set_bootstrappable(mut builder: Builder, value: Bootstrappable) -> Result<Builder, BGVError> {
+    builder.inner = match value {
+        Bootstrappable::None => builder.inner,
+        Bootstrappable::Enabled => ffi::is_bootstrappable(builder.inner, true)?,
+        Bootstrappable::Disabled => ffi::is_bootstrappable(builder.inner, false)?,
+    };
+    Ok(builder)
+}
+
+fn to_u32<T, E>(value: T) -> Result<u32, BGVError>
+where
+    T: ToU32<E>,
+    E: Into<SetError>,
+{
+    value.to_u32().map_err(Into::<SetError>::into).map_err(Into::<BGVError>::into)
+}
+
+fn to_i64s(values: Vec<u32>) -> Vec<i64> {
+    values.into_iter().map(i64::from).collect()
+}
+
+impl Setters for Builder {
+    fn set(self, value: Metric) -> Result<Self, BGVError> {
+        metric_set(self, value)
+    }
+
+    fn try_set<T>(self, value: T) -> Result<Self, BGVError>
+    where
+        T: TryInto<Metric, Error=BGVError>,
+    {
+        // Convert `value` into `Metric`, since `TryInto` is fallible
+        let metric = value.try_into()?;
+
+        metric_set(self, metric)
+    }
+
+    fn set_bits<T, E>(mut self, value: T) -> Result<Self, BGVError>
+    where
+        T: ToU32<E>,
+        E: Into<SetError>,
+    {
+        self.inner = ffi::set_bits(self.inner, to_u32(value)?)?;
+        Ok(self)
+    }
+
+    fn set_c<T, E>(mut self, value: T) -> Result<Self, BGVError>
+    where
+        T: ToU32<E>,
+        E: Into<SetError>,
+    {
+        self.inner = ffi::set_c(self.inner, to_u32(value)?)?;
+        Ok(self)
+    }
+
+    fn set_m<T, E>(mut self, value: T) -> Result<Self, BGVError>
+    where
+        T: ToU32<E>,
+        E: Into<SetError>,
+    {
+        self.inner = ffi::set_m(self.inner, to_u32(value)?)?;
+        Ok(self)
+    }
+
+    fn set_p<T, E>(mut self, value: T) -> Result<Self, BGVError>
+    where
+        T: ToU32<E>,
+        E: Into<SetError>,
+    {
+        self.inner = ffi::set_p(self.inner, to_u32(value)?)?;
+        Ok(self)
+    }
+
+    fn set_r<T, E>(mut self, value: T) -> Result<Self, BGVError>
+    where
+        T: ToU32<E>,
+        E: Into<SetError>,
+    {
+        self.inner = ffi::set_r(self.inner, to_u32(value)?)?;
+        Ok(self)
+    }
+
+    fn set_gens(mut self, value: Gens) -> Result<Self, BGVError> {
+        self.inner = ffi::set_gens(self.inner, &to_i64s(value.to_vec()))?;
+        Ok(self)
+    }
+
+    fn set_mvec(mut self, value: Mvec) -> Result<Self, BGVError> {
+        self.inner = ffi::set_mvec(self.inner, &to_i64s(value.to_vec()))?;
+        Ok(self)
+    }
+
+    fn set_ords(mut self, value: Ords) -> Result<Self, BGVError> {
+        self.inner = ffi::set_ords(self.inner, &to_i64s(value.to_vec()))?;
+        Ok(self)
+    }
+}
+
+// Primarily test success scenarios as the `try_from(...)` implementations
+// handle the error scenarios.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zno_helib_sys::prelude::{Context, Ptxt, SecKey};
+
+    // HElib's bootstrappable BGV parameters (BGV_binary_arithmetic.cpp): `gens`,
+    // `ords` and `mvec` describe the hypercube of m = 4095 = 7 * 5 * 9 * 13.
+    fn setup_bootstrappable_context() -> Context {
+        Builder::new()
+            .set(M::try_from(4095).unwrap().into())
+            .and_then(|builder| builder.set(P::try_from(2).unwrap().into()))
+            .and_then(|builder| builder.set(R::try_from(1).unwrap().into()))
+            .and_then(|builder| builder.set(Bits::try_from(500).unwrap().into()))
+            .and_then(|builder| builder.set(C::try_from(2).unwrap().into()))
+            .and_then(|builder| builder.set(Gens::new(vec![2341, 3277, 911]).unwrap().into()))
+            .and_then(|builder| builder.set(Ords::new(vec![6, 4, 6]).unwrap().into()))
+            .and_then(|builder| builder.set(Mvec::new(vec![7, 5, 9, 13]).unwrap().into()))
+            .and_then(|builder| builder.set(Bootstrappable::Enabled.into()))
+            // Thick recryption needs the slot unpacking maps too.
+            .and_then(|builder| builder.set(Bootstrap::Thick.into()))
+            .expect("HElib parameters")
+            .build()
+            .expect("BGV context creation")
+    }
+
+    #[test]
+    fn test_set_bits_success() {
+        let builder = Builder::new();
+        let result = builder.set(Bits::try_from(500).unwrap().into());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_set_c_success() {
+        let builder = Builder::new();
+        let result = builder.set(C::try_from(2).unwrap().into());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_set_m_success() {
+        let builder = Builder::new();
+        let result = builder.set(M::try_from(42).unwrap().into());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_set_p_success() {
+        let builder = Builder::new();
+        let result = builder.set(P::try_from(2).unwrap().into());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_set_r_success() {
+        let builder = Builder::new();
+        let result = builder.set(R::try_from(1).unwrap().into());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_set_gens_success() {
+        let builder = Builder::new();
+        let result = builder.set(Gens::new(vec![2341, 3277, 911]).unwrap().into());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_set_ords_success() {
+        let builder = Builder::new();
+        let result = builder.set(Ords::new(vec![6, 4, 6]).unwrap().into());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_set_mvec_success() {
+        let builder = Builder::new();
+        let result = builder.set(Mvec::new(vec![7, 5, 9, 13]).unwrap().into());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_set_bootstrappable_success() {
+        let context = Builder::new()
+            .set(Bootstrappable::Disabled.into())
+            .and_then(|builder| builder.set(Bootstrap::None.into()))
+            .expect("HElib parameters")
+            .build()
+            .expect("BGV context creation");
+        assert!(!context.is_bootstrappable());
+    }
+
+    #[test]
+    fn test_recrypt() {
+        let context = setup_bootstrappable_context();
+        assert!(context.is_bootstrappable());
+        let ea = context.ea();
+        let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+        secret_key.gen_sec_key().unwrap();
+        secret_key.add_some_1d_matrices().unwrap();
+        secret_key.add_frb_matrices().unwrap();
+        secret_key.gen_recrypt_data().unwrap();
+        let public_key = secret_key.public_key().expect("PubKey");
+        assert!(public_key.is_bootstrappable());
+
+        // Bits are their own squares, so squaring spends capacity without changing the slots.
+        let bits: Vec<i64> = (0..ea.size() as i64).map(|i| i % 2).collect();
+        let mut ctxt = public_key.encrypt(&Ptxt::from_slots(&context, &bits).unwrap()).expect("Ctxt");
+        for _ in 0..4 {
+            ctxt.square().unwrap();
+        }
+
+        Bootstrap::Thin.recrypt(&public_key, &mut ctxt).expect("thin recryption");
+        assert_eq!(secret_key.decrypt(&ctxt).unwrap().to_vec(), bits);
+        ctxt.square().unwrap();
+
+        Bootstrap::Thick.recrypt(&public_key, &mut ctxt).expect("thick recryption");
+        assert_eq!(secret_key.decrypt(&ctxt).unwrap().to_vec(), bits);
+    }
+}
@@ -1,18 +1,25 @@
-// Define methods for the Rust struct seal::Context.
-// Logic specific to the SEAL implementation belongs here.
+use zno_helib_sys::prelude::{Builder, CKKSBuilder, Context};
+
+use crate::error::BGVError;
+use crate::helib::bgv::Schema;
+use crate::helib::parameters::Parameters;
+use crate::helib::setters::Setters;
+use crate::prelude::*;
 
 // Define methods for the Rust struct helib::Context.
 // Logic specific to the HElib implementation belongs here.
 impl FheContext for Context {
-    type P = crate::helib::bgv::Parameters;
+    type P = Parameters;
     type E = BGVError;
 
-
     // Create a new instance of the C++ object Context.
     // This is safe because we're not exposing the inner pointer directly.
     // Logic specific to the HElib implementation belongs here.
     fn new(params: Self::P) -> Result<Self, Self::E> {
-        let mut params = params; // Make params mutable
+        if params.schema == Schema::Ckks {
+            return new_ckks(params);
+        }
+
         let cb: Builder = Builder::new()
                      .set(params.m.into())?
                      .set(params.p.into())?
@@ -31,31 +38,52 @@ impl FheContext for Context {
                     //     buildModChain must be called BEFORE the context is made
                     //     botstrappable (else the "powerful" basis is not initialized correctly.
                     // .set(params.modulus_chain.into())
+                     .set(params.bootstrappable.into())?
                      .set(params.bootstrap.into())?
                      ;
 
         // Build BGV context. Consume the instance of Builder.
-        // return a UniquePtr<ffi::Context>
-        let cntxt = cb.build();
-        match cntxt {
-            Ok(context) => {
-                // If successful, wrap the inner ffi::Context in a UniquePtr and return.
-                let inner = context.inner;  // assuming context is of type ffi::Context
-                Ok(Self { inner })
-            },
-            Err(_) => {
-                // If there's an error during construction, return a corresponding BGVError.
-                let e = ConstructionError::new(ConstructionErrorKind::Generic("An error occurred while building the BGV Context".into()));
-                Err(BGVError::ConstructionError(e))
-            }
-        }
+        // HElib's exception message is kept in `BGVError::FFIError`.
+        cb.build().map_err(BGVError::from)
     }
+}
 
+// Build a CKKS context. HElib's CKKS builder takes no `p`, `r`, generators or
+// bootstrapping options, so only `m`, `bits` and `c` are forwarded; the
+// precision keeps HElib's default.
+fn new_ckks(params: Parameters) -> Result<Context, BGVError> {
+    let context = CKKSBuilder::new()
+        .set_m(params.m.to_u32()?)?
+        .set_bits(params.bits.to_u32()?)?
+        .set_c(params.c.to_u32()?)?
+        .build()?;
+    Ok(context)
 }
 
-// Implement Display for printing, debugging, etc.
-impl core::fmt::Display for Context {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "Context") // How this type name should appear
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helib::bgv::{Bits, C, M};
+
+    #[test]
+    fn test_context_ckks() {
+        let params = Parameters {
+            schema: Schema::Ckks,
+            m: M::new(16 * 1024).expect("M"),
+            bits: Bits::new(119).expect("Bits"),
+            c: C::new(2).expect("C"),
+            ..Default::default()
+        };
+        let context: Context = params.context().expect("CKKS context creation");
+        assert_eq!(context.m(), 16 * 1024);
+        // CKKS packs m / 4 slots.
+        assert_eq!(context.ea().size(), 4 * 1024);
+    }
+
+    #[test]
+    fn test_context_ckks_m_not_power_of_two() {
+        let params = Parameters { schema: Schema::Ckks, ..Default::default() };
+        let result: Result<Context, _> = params.context();
+        assert!(matches!(result, Err(BGVError::FFIError(_))));
     }
 }
@@ -8,5 +8,3 @@ use super::bgv::mvec::MvecError;
 use super::bgv::ords::OrdsError;
 use super::bgv::p::PError;
 use super::bgv::r::RError;
-
-use super::context::ConstructionError;
@@ -1,8 +1,181 @@
 pub use super::*;
 // use mockall::automock;
 
+use zno_helib_sys::prelude::Context;
+
+use crate::error::ConversionError;
+use super::bgv::{
+    Bits, BitsError, Bootstrappable, BootstrappableError, C, CError, Gens, GensError,
+    M, MError, Mvec, MvecError, Ords, OrdsError, P, PError, R, RError,
+};
+
+/// Reads the parameters back from a context, so it can be audited after construction.
 pub trait Getters {
-    // fn get_m(&self) -> Result<M, MError>;
-    // fn get_p(&self) -> Result<P, PError>;
-    // add other getter methods here . . .
+    /// Returns the cyclotomic order `m`.
+    fn get_m(&self) -> Result<M, MError>;
+    /// Returns the plaintext prime `p`.
+    fn get_p(&self) -> Result<P, PError>;
+    /// Returns the Hensel lifting `r`.
+    fn get_r(&self) -> Result<R, RError>;
+    /// Returns the number of columns of the key-switching matrices.
+    fn get_c(&self) -> Result<C, CError>;
+    /// Returns the size of the modulus chain in bits.
+    fn get_bits(&self) -> Result<Bits, BitsError>;
+    /// Returns the generators of `(Z/mZ)^* / (p)`.
+    fn get_gens(&self) -> Result<Gens, GensError>;
+    /// Returns the orders of the generators.
+    fn get_ords(&self) -> Result<Ords, OrdsError>;
+    /// Returns the factorization of `m` used for bootstrapping, empty if there is none.
+    fn get_mvec(&self) -> Result<Mvec, MvecError>;
+    /// Returns whether the context has been made bootstrappable.
+    fn get_bootstrappable(&self) -> Result<Bootstrappable, BootstrappableError>;
+    /// Returns the number of plaintext slots.
+    fn get_slots(&self) -> Result<u32, ConversionError>;
+    /// Returns `phi(m)`, the degree of the cyclotomic polynomial.
+    fn get_phi_m(&self) -> Result<u32, ConversionError>;
+    /// Returns HElib's estimate of the security level, in bits.
+    fn get_security_level(&self) -> Result<f64, ConversionError>;
+    /// Returns the number of primes in the modulus chain.
+    fn get_modulus_chain_length(&self) -> Result<u32, ConversionError>;
+}
+
+fn to_u32(value: i64) -> Result<u32, ConversionError> {
+    if value < 0 {
+        return Err(ConversionError::NegativeValue);
+    }
+    u32::try_from(value).map_err(ConversionError::OutOfRange)
+}
+
+impl Getters for Context {
+    fn get_m(&self) -> Result<M, MError> {
+        M::try_from(self.m())
+    }
+
+    fn get_p(&self) -> Result<P, PError> {
+        P::try_from(self.p())
+    }
+
+    fn get_r(&self) -> Result<R, RError> {
+        R::try_from(self.r())
+    }
+
+    fn get_c(&self) -> Result<C, CError> {
+        C::try_from(self.c())
+    }
+
+    fn get_bits(&self) -> Result<Bits, BitsError> {
+        Bits::try_from(self.bits())
+    }
+
+    fn get_gens(&self) -> Result<Gens, GensError> {
+        Gens::try_from(self.gens())
+    }
+
+    fn get_ords(&self) -> Result<Ords, OrdsError> {
+        Ords::try_from(self.ords())
+    }
+
+    fn get_mvec(&self) -> Result<Mvec, MvecError> {
+        Mvec::try_from(self.mvec())
+    }
+
+    fn get_bootstrappable(&self) -> Result<Bootstrappable, BootstrappableError> {
+        if self.is_bootstrappable() {
+            Ok(Bootstrappable::Enabled)
+        } else {
+            Ok(Bootstrappable::Disabled)
+        }
+    }
+
+    fn get_slots(&self) -> Result<u32, ConversionError> {
+        to_u32(self.slots())
+    }
+
+    fn get_phi_m(&self) -> Result<u32, ConversionError> {
+        to_u32(self.phi_m())
+    }
+
+    fn get_security_level(&self) -> Result<f64, ConversionError> {
+        Ok(self.security_level())
+    }
+
+    fn get_modulus_chain_length(&self) -> Result<u32, ConversionError> {
+        to_u32(self.modulus_chain_length())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zno_helib_sys::prelude::Builder;
+
+    use crate::helib::setters::Setters;
+    use crate::prelude::*;
+
+    // m = 4095 = 3^2 * 5 * 7 * 13, so phi(m) = 1728. Both 4999 and 2 have
+    // order 12 modulo 4095, which leaves 1728 / 12 = 144 slots.
+    const PHI_M: u32 = 1728;
+    const SLOTS: u32 = 144;
+
+    #[test]
+    fn test_getters() {
+        let context = Builder::new()
+            .set(M::try_from(4095).unwrap().into())
+            .and_then(|builder| builder.set(P::try_from(4999).unwrap().into()))
+            .and_then(|builder| builder.set(R::try_from(1).unwrap().into()))
+            .and_then(|builder| builder.set(Bits::try_from(300).unwrap().into()))
+            .and_then(|builder| builder.set(C::try_from(2).unwrap().into()))
+            .expect("HElib parameters")
+            .build()
+            .expect("BGV context creation");
+
+        assert_eq!(context.get_m().unwrap(), M::try_from(4095).unwrap());
+        assert_eq!(context.get_p().unwrap(), P::try_from(4999).unwrap());
+        assert_eq!(context.get_r().unwrap(), R::try_from(1).unwrap());
+        assert_eq!(context.get_c().unwrap(), C::try_from(2).unwrap());
+        // The chain is built up to at least the requested size.
+        assert!(context.get_bits().unwrap().to_u32().unwrap() >= 300);
+        assert_eq!(context.get_phi_m().unwrap(), PHI_M);
+        assert_eq!(context.get_slots().unwrap(), SLOTS);
+        assert_eq!(context.get_slots().unwrap() as usize, context.ea().size());
+        // HElib picks the generators itself; their orders still span the slots.
+        let ords = context.get_ords().unwrap().to_vec();
+        assert_eq!(context.get_gens().unwrap().to_vec().len(), ords.len());
+        assert_eq!(ords.iter().product::<u32>(), SLOTS);
+        assert_eq!(context.get_mvec().unwrap().to_vec(), Vec::<u32>::new());
+        assert_eq!(context.get_bootstrappable().unwrap(), Bootstrappable::Disabled);
+        assert!(context.get_modulus_chain_length().unwrap() > 0);
+        assert!(context.get_security_level().unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_getters_bootstrappable() {
+        let context = Builder::new()
+            .set(M::try_from(4095).unwrap().into())
+            .and_then(|builder| builder.set(P::try_from(2).unwrap().into()))
+            .and_then(|builder| builder.set(R::try_from(1).unwrap().into()))
+            .and_then(|builder| builder.set(Bits::try_from(500).unwrap().into()))
+            .and_then(|builder| builder.set(C::try_from(2).unwrap().into()))
+            .and_then(|builder| builder.set(Gens::new(vec![2341, 3277, 911]).unwrap().into()))
+            .and_then(|builder| builder.set(Ords::new(vec![6, 4, 6]).unwrap().into()))
+            .and_then(|builder| builder.set(Mvec::new(vec![7, 5, 9, 13]).unwrap().into()))
+            .and_then(|builder| builder.set(Bootstrappable::Enabled.into()))
+            .expect("HElib parameters")
+            .build()
+            .expect("BGV context creation");
+
+        assert_eq!(context.get_m().unwrap(), M::try_from(4095).unwrap());
+        assert_eq!(context.get_p().unwrap(), P::try_from(2).unwrap());
+        assert_eq!(context.get_r().unwrap(), R::try_from(1).unwrap());
+        assert_eq!(context.get_c().unwrap(), C::try_from(2).unwrap());
+        assert!(context.get_bits().unwrap().to_u32().unwrap() >= 500);
+        assert_eq!(context.get_gens().unwrap().to_vec(), vec![2341, 3277, 911]);
+        assert_eq!(context.get_ords().unwrap().to_vec(), vec![6, 4, 6]);
+        assert_eq!(context.get_phi_m().unwrap(), PHI_M);
+        assert_eq!(context.get_slots().unwrap(), SLOTS);
+        assert_eq!(context.get_bootstrappable().unwrap(), Bootstrappable::Enabled);
+        let mvec = context.get_mvec().unwrap().to_vec();
+        assert!(!mvec.is_empty());
+        assert_eq!(mvec, vec![7, 5, 9, 13]);
+    }
 }
@@ -0,0 +1,47 @@
+// Key generation for the HElib BGV scheme.
+// The key types themselves live in zno-helib-sys.
+use crate::prelude::*;
+
+pub use zno_helib_sys::bgv::{Ctxt, PubKey, SecKey};
+
+/// Generates a `SecKey` for `context`, together with the key-switching
+/// matrices that rotations and Frobenius automorphisms need.
+///
+/// This is the setup every HElib BGV program starts with, the equivalent of:
+///
+/// ```text
+/// helib::SecKey secret_key(context);
+/// secret_key.GenSecKey();
+/// helib::addSome1DMatrices(secret_key);
+/// helib::addFrbMatrices(secret_key);
+/// const helib::PubKey& public_key = secret_key;
+/// ```
+///
+/// Call `public_key()` on the result to encrypt.
+///
+/// # Errors
+///
+/// Returns `BGVError::FFIError` if HElib fails to generate the key or the matrices.
+pub fn generate_keys(context: &zno_helib_sys::bgv::Context) -> Result<SecKey<'_>, BGVError> {
+    let mut secret_key = SecKey::new(context)?;
+    secret_key.gen_sec_key()?;
+    secret_key.add_some_1d_matrices()?;
+    secret_key.add_frb_matrices()?;
+    Ok(secret_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_keys() {
+        let context = zno_helib_sys::prelude::Builder::new().build().expect("BGV context creation");
+        let secret_key = generate_keys(&context).expect("SecKey");
+        let public_key = secret_key.public_key().expect("PubKey");
+        assert!(public_key.key_exists(0));
+
+        let ctxt = public_key.encrypt_constant(1).expect("Ctxt");
+        assert_eq!(secret_key.decrypt_constant(&ctxt).unwrap(), 1);
+    }
+}
@@ -1,7 +1,9 @@
-mod bgv;
-mod context;
-mod error;
-mod getters;
-mod parameters;
-mod schema;
-mod setters;
+pub mod bgv;
+pub mod builder;
+pub mod context;
+pub mod error;
+pub mod getters;
+pub mod keys;
+pub mod parameters;
+pub mod schema;
+pub mod setters;
@@ -8,7 +8,10 @@ use super::bgv::ords::Ords;
 use super::bgv::mvec::Mvec;
 use super::bgv::bootstrap::Bootstrap;
 use super::bgv::bootstrappable::Bootstrappable;
-use super::bgv::BGVError;
+use super::bgv::Schema;
+use crate::FheContext;
+use crate::FheParameters;
+use crate::error::BGVError;
 use core::fmt;
 
 #[cfg(not(any(feature = "helib", feature = "openfhe", feature = "seal")))]
@@ -51,6 +54,10 @@ compile_error!("You must enable one of the features: `helib` or `seal`"); // or
 ///  std::vector<long> ords = {6, 4, 6};
 /// ```
 ///
+/// With `schema: Schema::Ckks` the context is built by HElib's CKKS builder
+/// instead, which reads `m` (a power of two), `bits` and `c` and ignores the
+/// rest.
+///
 /// # Errors
 ///
 /// While the `Parameters` struct aggregates various parameters and does not directly produce errors,
@@ -125,8 +132,9 @@ compile_error!("You must enable one of the features: `helib` or `seal`"); // or
 /// # Example
 ///
 /// ```
-/// # use zno::bgv::{M, P, R, C, Bits, Gens, Ords, Mvec, Bootstrappable};
+/// # use zno::bgv::{Schema, M, P, R, C, Bits, Gens, Ords, Mvec, Bootstrappable};
 /// let params = Parameters {
+///     schema: Schema::Bgv,
 ///     m: M::new(4095).unwrap(),
 ///     p: P::new(2).unwrap(),
 ///     r: R::new(1).unwrap(),
@@ -143,6 +151,8 @@ compile_error!("You must enable one of the features: `helib` or `seal`"); // or
 
 #[repr(C)]
 pub struct Parameters {
+    /// Selects the HElib builder: `Schema::Ckks` uses `m`, `bits` and `c` only.
+    pub schema: Schema,
     pub m: M,
     pub p: P,
     pub r: R,
@@ -159,6 +169,7 @@ impl Default for Parameters {
     // Use this if all-zero/empty Parameters makes sense in your context
     fn default() -> Self {
         Parameters {
+            schema: Default::default(),
             m: Default::default(),
             p: Default::default(),
             r: Default::default(),
@@ -180,23 +191,15 @@ impl core::fmt::Display for Parameters {
     }
 }
 
-impl Parameters {
-    #[cfg(feature = "helib")]
-    pub fn context(self) -> Result<crate::bgv::Context, BGVError> {
-        // Note: the `inner` attribute contains cxx::UniquePtr<crate::helib::bgv::ffi::Context>
-        crate::bgv::Context::new(self)
-    }
-    #[cfg(feature = "openfhe")]
-    pub fn context(self) -> Result<crate::bgv::Context, BGVError> {
-        todo!();
-        // Note: the `inner` attribute contains cxx::UniquePtr<crate::openfhe::Context>
-        crate::openfhe::Context::new(self)
-    }
-    #[cfg(feature = "seal")]
-    pub fn context(self) -> Result<crate::bgv::Context, BGVError> {
-        todo!();
-        // Note: the `inner` attribute contains cxx::UniquePtr<crate::seal::Context>
-        crate::seal::Context::new(self)
+impl FheParameters for Parameters {
+    type E = BGVError;
+
+    fn context<C>(self) -> Result<C, Self::E>
+    where
+        C: FheContext<P = Self>,
+        C::E: Into<BGVError>,
+    {
+        C::new(self).map_err(Into::into)
     }
 }
 
@@ -1,3 +1,5 @@
+use crate::fhe::Schema;
+
 /// Converts a `zno_fhe::Schema` into an `crate::Schema`.
 ///
 impl From<Schema> for zno_helib_sys::Schema {
@@ -12,7 +14,6 @@ impl From<Schema> for zno_helib_sys::Schema {
     /// The converted `Schema` variant.
     fn from(schema: Schema) -> Self {
         match schema {
-            Schema::None => zno_helib_sys::Schema::default(),
             Schema::Bfv => zno_helib_sys::Schema::Bfv,
             Schema::Ckks => zno_helib_sys::Schema::Ckks,
             Schema::Bgv => zno_helib_sys::Schema::Bgv,
@@ -3,6 +3,11 @@ use std::ops::DerefMut;
 
 pub use super::*;
 
+use crate::helib::bgv::*;
+
+pub use crate::error::*;
+pub use crate::prelude::*;
+
 // use mockall::automock;
 
 pub trait Setters {
@@ -45,6 +50,18 @@ pub trait Setters {
         Self: Sized,
         T: ToU32<E>,
         E: Into<SetError>;
+
+    fn set_gens(self, value: Gens) -> Result<Self, BGVError>
+    where
+        Self: Sized;
+
+    fn set_mvec(self, value: Mvec) -> Result<Self, BGVError>
+    where
+        Self: Sized;
+
+    fn set_ords(self, value: Ords) -> Result<Self, BGVError>
+    where
+        Self: Sized;
 }
 
 // // Example type implementing the Setter trait
@@ -193,7 +210,7 @@ impl From<SetError> for BGVError {
             SetError::Bootstrappable(error) => BGVError::BootstrappableError(error),
             SetError::C(error) => BGVError::CError(error),
             SetError::Gens(error) => BGVError::GensError(error),
-            SetError::M(error) => BGVError::MError(error),
+            SetError::M(error) => BGVError::HelibMError(error),
             SetError::Mvec(error) => BGVError::MvecError(error),
             SetError::Ords(error) => BGVError::OrdsError(error),
             SetError::P(error) => BGVError::PError(error),
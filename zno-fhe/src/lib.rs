@@ -34,6 +34,8 @@ pub use self::helib::context::*;
 #[cfg(feature = "helib")]
 pub use self::helib::getters::*;
 #[cfg(feature = "helib")]
+pub use self::helib::keys::*;
+#[cfg(feature = "helib")]
 pub use self::helib::setters::*;
 #[cfg(feature = "helib")]
 pub use self::helib::parameters::*;
@@ -50,6 +52,8 @@ pub use self::seal::setters::*;
 pub use self::seal::parameters::*;
 #[cfg(feature = "seal")]
 pub use self::seal::schema::*;
+#[cfg(feature = "seal")]
+pub use self::seal::bfv;
 
 // use zno::FheState;
 
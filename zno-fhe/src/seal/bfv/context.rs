@@ -0,0 +1,75 @@
+use zno_seal_sys::bgv::ffi;
+use zno_seal_sys::bgv::ffi::BGVContextBuilder as FFIBGVBuilder;
+
+use crate::seal::builder::Builder;
+use super::parameters::Parameters;
+use crate::error::*;
+
+/// `Context` holds a SEAL context built for the BFV scheme.
+///
+/// Keys, encoders and evaluators are created from a `Context`, and only work
+/// with ciphertexts of the same `Context`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use zno_fhe::bfv::*;
+/// let context = Context::new(Parameters::default()).expect("BFV context creation");
+/// let keygen = KeyGenerator::new(context.inner()).expect("KeyGenerator creation");
+/// ```
+pub struct Context {
+    inner: zno_seal_sys::bgv::Context,
+}
+
+impl Context {
+    /// Creates a BFV context from the given parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BGVError::GenericError` if a parameter is out of range, and
+    /// `BGVError::ConstructionError` if SEAL rejects the combination, e.g. a
+    /// coefficient modulus too large for the degree.
+    pub fn new(params: Parameters) -> Result<Self, BGVError> {
+        let builder = Builder::<FFIBGVBuilder>::with_scheme(ffi::SchemeType::Bfv)?
+            .set_poly_modulus_degree(params.poly_modulus_degree)?;
+        let builder = match &params.coeff_modulus_bit_sizes {
+            Some(bit_sizes) => builder.set_coeff_modulus_bit_sizes(bit_sizes)?,
+            None => builder.set_coeff_modulus_bfv_default()?,
+        };
+        let inner = builder
+            .set_plain_modulus_batching(params.plain_modulus_bit_size)?
+            .build_context()?;
+
+        if !inner.parameters_set() {
+            let e = ConstructionError::new(ConstructionErrorKind::Generic(inner.parameter_error_message()));
+            return Err(BGVError::ConstructionError(e));
+        }
+        Ok(Self { inner })
+    }
+
+    /// Returns the underlying SEAL context, to create keys, an `Encryptor` or a `Decryptor`.
+    pub fn inner(&self) -> &zno_seal_sys::bgv::Context {
+        &self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bfv_context_new() {
+        let context = Context::new(Parameters::default()).expect("BFV context creation");
+        assert!(context.inner().parameters_set());
+    }
+
+    #[test]
+    fn test_bfv_context_rejects_insecure_parameters() {
+        let params = Parameters {
+            poly_modulus_degree: 1024,
+            coeff_modulus_bit_sizes: Some(vec![60, 60]),
+            plain_modulus_bit_size: 20,
+        };
+        assert!(matches!(Context::new(params), Err(BGVError::ConstructionError(_))));
+    }
+}
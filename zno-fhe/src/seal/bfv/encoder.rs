@@ -0,0 +1,53 @@
+use zno_seal_sys::bgv::BatchEncoder;
+
+use super::context::Context;
+use super::Plaintext;
+use crate::error::BGVError;
+
+/// `Encoder` packs signed integers into the slots of a BFV `Plaintext`.
+///
+/// There are `poly_modulus_degree` slots, and homomorphic operations act on
+/// all of them at once. Values must be at most `plain_modulus / 2` in
+/// absolute value; results wrap around modulo the plaintext modulus.
+pub struct Encoder {
+    inner: BatchEncoder,
+}
+
+impl Encoder {
+    /// Creates an `Encoder` for a BFV `Context`.
+    pub fn new(context: &Context) -> Result<Self, BGVError> {
+        let inner = BatchEncoder::new(context.inner())?;
+        Ok(Self { inner })
+    }
+
+    /// Returns the number of slots in a `Plaintext`.
+    pub fn slot_count(&self) -> usize {
+        self.inner.slot_count()
+    }
+
+    /// Encodes `values` into a new `Plaintext`; slots beyond `values.len()` are zero.
+    pub fn encode(&self, values: &[i64]) -> Result<Plaintext, BGVError> {
+        Ok(self.inner.encode_i64(values)?)
+    }
+
+    /// Decodes the `slot_count()` slots of a `Plaintext`.
+    pub fn decode(&self, plain: &Plaintext) -> Result<Vec<i64>, BGVError> {
+        Ok(self.inner.decode_i64(plain)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::seal::bfv::Parameters;
+
+    #[test]
+    fn test_encode_decode() {
+        let context = Context::new(Parameters::default()).expect("BFV context creation");
+        let encoder = Encoder::new(&context).expect("Encoder creation");
+        assert_eq!(encoder.slot_count(), 8192);
+
+        let decoded = encoder.decode(&encoder.encode(&[-1, 0, 1]).unwrap()).unwrap();
+        assert_eq!(&decoded[..3], &[-1, 0, 1]);
+    }
+}
@@ -0,0 +1,100 @@
+use super::context::Context;
+use super::{Ciphertext, Plaintext, RelinKeys};
+use crate::error::BGVError;
+
+/// `Evaluator` computes on BFV ciphertexts without decrypting them.
+///
+/// `multiply` relinearizes its result, so every ciphertext the `Evaluator`
+/// returns has size 2. Each multiplication consumes noise budget; check it
+/// with `Decryptor::invariant_noise_budget`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use zno_fhe::bfv::*;
+/// let context = Context::new(Parameters::default()).expect("BFV context creation");
+/// let mut keygen = KeyGenerator::new(context.inner()).unwrap();
+/// let encryptor = Encryptor::new(context.inner(), &keygen.create_public_key().unwrap()).unwrap();
+/// let evaluator = Evaluator::new(&context, keygen.create_relin_keys().unwrap()).unwrap();
+/// let encoder = Encoder::new(&context).unwrap();
+///
+/// let x = encryptor.encrypt(&encoder.encode(&[3]).unwrap()).unwrap();
+/// let x_squared = evaluator.multiply(&x, &x).unwrap();
+/// ```
+pub struct Evaluator {
+    inner: zno_seal_sys::bgv::Evaluator,
+    relin_keys: RelinKeys,
+}
+
+impl Evaluator {
+    /// Creates an `Evaluator` for a BFV `Context`, relinearizing with `relin_keys`.
+    pub fn new(context: &Context, relin_keys: RelinKeys) -> Result<Self, BGVError> {
+        let inner = zno_seal_sys::bgv::Evaluator::new(context.inner())?;
+        Ok(Self { inner, relin_keys })
+    }
+
+    /// Returns `-encrypted`.
+    pub fn negate(&self, encrypted: &Ciphertext) -> Result<Ciphertext, BGVError> {
+        Ok(self.inner.negate(encrypted)?)
+    }
+
+    /// Returns `encrypted1 + encrypted2`.
+    pub fn add(&self, encrypted1: &Ciphertext, encrypted2: &Ciphertext) -> Result<Ciphertext, BGVError> {
+        Ok(self.inner.add(encrypted1, encrypted2)?)
+    }
+
+    /// Returns `encrypted1 - encrypted2`.
+    pub fn sub(&self, encrypted1: &Ciphertext, encrypted2: &Ciphertext) -> Result<Ciphertext, BGVError> {
+        Ok(self.inner.sub(encrypted1, encrypted2)?)
+    }
+
+    /// Returns `encrypted1 * encrypted2`, relinearized.
+    pub fn multiply(&self, encrypted1: &Ciphertext, encrypted2: &Ciphertext) -> Result<Ciphertext, BGVError> {
+        let mut product = self.inner.multiply(encrypted1, encrypted2)?;
+        self.inner.relinearize_inplace(&mut product, &self.relin_keys)?;
+        Ok(product)
+    }
+
+    /// Returns `encrypted + plain`.
+    pub fn add_plain(&self, encrypted: &Ciphertext, plain: &Plaintext) -> Result<Ciphertext, BGVError> {
+        Ok(self.inner.add_plain(encrypted, plain)?)
+    }
+
+    /// Returns `encrypted * plain`.
+    pub fn multiply_plain(&self, encrypted: &Ciphertext, plain: &Plaintext) -> Result<Ciphertext, BGVError> {
+        Ok(self.inner.multiply_plain(encrypted, plain)?)
+    }
+
+    /// Switches `encrypted` down to the next level of the modulus chain,
+    /// which makes later operations faster.
+    pub fn mod_switch_to_next(&self, encrypted: &Ciphertext) -> Result<Ciphertext, BGVError> {
+        Ok(self.inner.mod_switch_to_next(encrypted)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::seal::bfv::*;
+
+    #[test]
+    fn test_bfv_arithmetic() {
+        let context = Context::new(Parameters::default()).expect("BFV context creation");
+        let mut keygen = KeyGenerator::new(context.inner()).expect("KeyGenerator creation");
+        let encryptor = Encryptor::new(context.inner(), &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+        let mut decryptor = Decryptor::new(context.inner(), &keygen.secret_key().unwrap()).expect("Decryptor creation");
+        let evaluator = Evaluator::new(&context, keygen.create_relin_keys().unwrap()).expect("Evaluator creation");
+        let encoder = Encoder::new(&context).expect("Encoder creation");
+
+        // (x * y + 1) - x
+        let x = encryptor.encrypt(&encoder.encode(&[2, -3, 4]).unwrap()).unwrap();
+        let y = encryptor.encrypt(&encoder.encode(&[5, 6, -7]).unwrap()).unwrap();
+        let result = evaluator.multiply(&x, &y).unwrap();
+        assert_eq!(result.size(), 2);
+        let result = evaluator.add_plain(&result, &encoder.encode(&[1, 1, 1]).unwrap()).unwrap();
+        let result = evaluator.sub(&result, &x).unwrap();
+
+        let decoded = encoder.decode(&decryptor.decrypt(&result).unwrap()).unwrap();
+        assert_eq!(&decoded[..3], &[9, -14, -31]);
+    }
+}
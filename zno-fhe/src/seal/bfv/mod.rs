@@ -0,0 +1,41 @@
+// Include the modules
+pub mod parameters;
+pub mod context;
+pub mod encoder;
+pub mod evaluator;
+
+// Re-export the types for external use as `crate::bfv::<type>`
+pub use self::parameters::*;
+pub use self::context::*;
+pub use self::encoder::*;
+pub use self::evaluator::*;
+
+// The keys, encryptor and decryptor are shared with BGV in zno-seal-sys.
+pub use zno_seal_sys::bgv::{
+    Ciphertext, Decryptor, Encryptor, GaloisKeys, KeyGenerator, Plaintext, PublicKey, RelinKeys, SecretKey,
+};
+
+use crate::prelude::*;
+
+/// Enumerates the homomorphic encryption schemas of this module.
+///
+/// # Examples
+///
+/// ```
+/// # use zno_fhe::bfv::Schema;
+/// let schema = Schema::Bfv;
+/// ```
+pub enum Schema {
+    /// The Brakerski/Fan-Vercauteren (BFV) scheme.
+    Bfv,
+}
+
+impl FheScheme for Schema {}
+
+impl From<Schema> for crate::fhe::Schema {
+    fn from(schema: Schema) -> Self {
+        match schema {
+            Schema::Bfv => crate::fhe::Schema::Bfv,
+        }
+    }
+}
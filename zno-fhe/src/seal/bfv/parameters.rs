@@ -0,0 +1,66 @@
+use super::context::Context;
+
+use crate::error::BGVError;
+
+use core::fmt;
+
+/// Represents the BFV parameters as used by SEAL.
+///
+/// BFV and BGV share their parameters in SEAL: the degree of the polynomial
+/// modulus, the coefficient modulus and the plaintext modulus. The plaintext
+/// modulus is always chosen to support batching, so that `Encoder` can pack
+/// `poly_modulus_degree` integers into one plaintext.
+///
+/// # Defaults
+///
+/// A degree of 8192, SEAL's default coefficient modulus for 128-bit security,
+/// and a 20-bit batching prime as plaintext modulus.
+///
+/// # Example
+///
+/// ```no_run
+/// # use zno_fhe::bfv::Parameters;
+/// let params = Parameters {
+///     poly_modulus_degree: 4096,
+///     coeff_modulus_bit_sizes: Some(vec![36, 36, 37]),
+///     plain_modulus_bit_size: 20,
+/// };
+/// let context = params.context().expect("BFV context creation");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Parameters {
+    /// The degree of the polynomial modulus, a power of two.
+    pub poly_modulus_degree: u64,
+    /// The bit sizes of the coefficient modulus primes, `None` for SEAL's 128-bit default.
+    pub coeff_modulus_bit_sizes: Option<Vec<i32>>,
+    /// The bit size of the batching prime used as plaintext modulus.
+    pub plain_modulus_bit_size: i32,
+}
+
+impl Parameters {
+    /// Creates a BFV `Context` from these parameters.
+    pub fn context(self) -> Result<Context, BGVError> {
+        Context::new(self)
+    }
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Parameters {
+            poly_modulus_degree: 8192,
+            coeff_modulus_bit_sizes: None,
+            plain_modulus_bit_size: 20,
+        }
+    }
+}
+
+impl fmt::Display for Parameters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Parameters(poly_modulus_degree={}, coeff_modulus_bit_sizes=", self.poly_modulus_degree)?;
+        match &self.coeff_modulus_bit_sizes {
+            Some(bit_sizes) => write!(f, "{:?}", bit_sizes)?,
+            None => write!(f, "default")?,
+        }
+        write!(f, ", plain_modulus_bit_size={})", self.plain_modulus_bit_size)
+    }
+}
@@ -1,4 +1,5 @@
 use crate::seal::bgv::*;
+use crate::fhe::Schema;
 
 
 use core::convert::TryFrom;
@@ -307,9 +308,9 @@ impl From<Infallible> for BitsError {
 /// let bits = Bits::default();
 /// assert_eq!(bits.schema(), Schema::Bgv);
 /// ```
-impl Fhe for Bits {
-    fn schema(&self) -> Schema {
-        Schema::Bgv
+impl FheMetric<crate::seal::bgv::Schema> for Bits {
+    fn get_schema(&self) -> crate::seal::bgv::Schema {
+        crate::seal::bgv::Schema::Bgv
     }
 }
 
@@ -216,13 +216,13 @@ impl ToU32<MError> for M {
 
 /// Returns the default value for `M`.
 ///
-/// This is the smallest non-zero `u32` value that is permitted within `M`, namely `4095`.
-/// It is a constant, not arbitrary, chosen with purpose.
+/// SEAL uses power-of-two cyclotomics, so `m` is twice the polynomial modulus
+/// degree. The default, `16384`, is a degree of 8192.
 ///
 /// # Panics
 ///
 /// This function will panic if the default value cannot be represented as a `NonZeroU32`.
-/// Such a panic is not a concern in practical use; `4095` is a valid non-zero `u32` value.
+/// Such a panic is not a concern in practical use; `16384` is a valid non-zero `u32` value.
 ///
 /// # Examples
 ///
@@ -230,11 +230,11 @@ impl ToU32<MError> for M {
 ///
 /// ```
 /// let m = M::default();
-/// assert_eq!(m.unwrap().get(), 4095);
+/// assert_eq!(m.unwrap().get(), 16384);
 /// ```
 impl Default for M {
     fn default() -> Self {
-        M::Some(core::num::NonZeroU32::new(4095).expect("4095 is a valid non-zero u32 value."))
+        M::Some(core::num::NonZeroU32::new(16384).expect("16384 is a valid non-zero u32 value."))
     }
 }
 
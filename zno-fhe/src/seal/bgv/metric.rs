@@ -1,8 +1,8 @@
 use super::m::M;
-// use super::bgv::p::P;
+use super::p::P;
 // use super::bgv::r::R;
 // use super::bgv::c::C;
-// use super::bgv::bits::Bits;
+use super::bits::Bits;
 // use super::bgv::gens::Gens;
 // use super::bgv::ords::Ords;
 // use super::bgv::mvec::Mvec;
@@ -12,7 +12,7 @@ use super::m::M;
 use crate::prelude::*;
 
 pub enum Metric {
-    // Bits(Bits),
+    Bits(Bits),
     // Bootstrap(Bootstrap),
     // Bootstrappable(Bootstrappable),
     // C(C),
@@ -20,7 +20,7 @@ pub enum Metric {
     M(M),
     // Mvec(Mvec),
     // Ords(Ords),
-    // P(P),
+    P(P),
     // R(R),
 }
 
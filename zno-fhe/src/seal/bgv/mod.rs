@@ -1,9 +1,9 @@
 // Include the modules
 pub mod m;
-// pub mod p;
+pub mod p;
 // pub mod r;
 // pub mod c;
-// pub mod bits;
+pub mod bits;
 // pub mod gens;
 // pub mod ords;
 // pub mod mvec;
@@ -18,10 +18,10 @@ pub mod metric;
 
 // Re-export the types for external use as `crate::bgv::<type>`
 pub use self::m::*;
-// pub use self::p::*;
+pub use self::p::*;
 // pub use self::r::*;
 // pub use self::c::*;
-// pub use self::bits::*;
+pub use self::bits::*;
 // pub use self::gens::*;
 // pub use self::ords::*;
 // pub use self::mvec::*;
@@ -56,6 +56,14 @@ pub enum Schema {
 
 impl FheScheme for Schema {}
 
+impl From<Schema> for crate::fhe::Schema {
+    fn from(schema: Schema) -> Self {
+        match schema {
+            Schema::Bgv => crate::fhe::Schema::Bgv,
+        }
+    }
+}
+
 // Implement the Scheme marker trait for required types
 // This enforces implementation of required traits: He, Into<Metric>
 // impl Scheme for Bits {}
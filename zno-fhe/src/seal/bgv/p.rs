@@ -1,4 +1,5 @@
 use crate::seal::bgv::*;
+use crate::fhe::Schema;
 
 use crate::prelude::*;
 
@@ -259,9 +260,9 @@ impl From<Infallible> for PError {
 /// let p = P::default();
 /// assert_eq!(p.schema(), Schema::Bgv);
 /// ```
-impl Fhe for P {
-    fn schema(&self) -> Schema {
-        Schema::Bgv
+impl FheMetric<crate::seal::bgv::Schema> for P {
+    fn get_schema(&self) -> crate::seal::bgv::Schema {
+        crate::seal::bgv::Schema::Bgv
     }
 }
 
@@ -190,7 +190,7 @@ impl FheParameters for Parameters {
 
     fn context<C>(self) -> Result<C, Self::E>
     where
-        C: FheContext,
+        C: FheContext<P = Self>,
         C::E: Into<BGVError>,
     {
         C::new(self).map_err(Into::into)
@@ -15,17 +15,17 @@ use crate::prelude::*;
 
 use cxx;
 
-impl crate::seal::builder::FheBuilder for FFIBGVBuilder{
+impl crate::seal::builder::FheBuilder for FFIBGVBuilder {
     type P = Parameters;
     type E = BGVError;
 
-    fn new(params: Self::P) -> Result<Self, Self::E> {
+    fn new(_params: Self::P) -> Result<cxx::UniquePtr<Self>, Self::E> {
         // Call the ffi function to initialize the BGVContextBuilder
-        let builder = ffi::init(params.scheme); // Replace `params.scheme` with the actual field or method to get the scheme from `params`
+        let builder = ffi::init(ffi::SchemeType::Bgv.repr)?;
 
         // Check if the builder is initialized correctly
         if builder.is_null() {
-            return Err(Self::E::new("Failed to initialize BGVContextBuilder")); // Replace with the actual method to create a new FheError
+            return Err(crate::error::ConstructionError::new(crate::error::ConstructionErrorKind::NullPointer).into());
         }
 
         Ok(builder)
@@ -52,18 +52,18 @@ pub struct BGVBuilder {
 
 impl BGVBuilder {
     // Add methods to initialize and configure the builder
-    pub fn new<P: FheParameters, E: FheError>(params: P) -> Result<Self, E> {
+    pub fn new(params: Parameters) -> Result<Self, BGVError> {
         let builder = Builder::<FFIBGVBuilder>::new(params)?;
         Ok(Self { builder })
     }
 
     // Add other methods to configure the builder and build the final object
 }
-pub trait FheBuilder {
+pub trait FheBuilder: cxx::memory::UniquePtrTarget {
     type P: FheParameters;
     type E: FheError;
 
-    fn new(params: Self::P) -> Result<Self, Self::E>
+    fn new(params: Self::P) -> Result<cxx::UniquePtr<Self>, Self::E>
     where
         Self: Sized;
 }
@@ -73,7 +73,7 @@ pub trait FheBuilder {
 // Logic common across implementations belongs here.
 impl<B: FheBuilder + cxx::memory::UniquePtrTarget> Builder<B> {
 
-    pub fn new<P: FheParameters, E: FheError>(params: P) -> Result<Self, E> {
+    pub fn new(params: B::P) -> Result<Self, B::E> {
         let inner = B::new(params)?;
         Ok(Self { inner })
     }
@@ -86,90 +86,160 @@ impl<B: FheBuilder + cxx::memory::UniquePtrTarget> Builder<B> {
 
 }
 
-impl<B: FheBuilder + cxx::memory::UniquePtrTarget> Setters for Builder<B> {
-
-    // fn set_m<T, E>(mut self, value: T) -> Result<Self, BGVError>
-    // where
-    //     Self: Sized,
-    //     T: ToU32<E>,
-    //     E: Into<SetError>,
-    // {
-    //     let u32_value = value.to_u32().map_err(Into::<SetError>::into).map_err(Into::<BGVError>::into)?;
-    //     // Assumes `ffi::set_m` returns Result<(), MError>
-    //     self.inner = ffi::set_m(self.inner, u32_value);
-    //     Ok(self)
-    // }
+// Logic specific to the SEAL BGV implementation belongs here.
+// Optimal parameters for BGV produced by fhegen need only the
+// polynomial modulus degree, the coefficient modulus and the plain modulus.
+impl Builder<FFIBGVBuilder> {
 
-    // fn set_bits<T, E>(mut self, value: T) -> Result<Self, BGVError>
-    // where
-    //     Self: Sized,
-    //     T: ToU32<E>,
-    //     E: Into<SetError>,
-    // {
-    //     todo!()
-    //     // let u32_value = value.to_u32().map_err(Into::<SetError>::into).map_err(Into::<BGVError>::into)?;
-    //     // // Assuming `ffi::set_bits` returns Result<(), BitsError>
-    //     // self.inner = ffi::set_bits(self.inner, u32_value);
-    //     // Ok(self)
-    // }
+    /// Starts a builder for the given SEAL scheme.
+    ///
+    /// The scheme is fixed for the lifetime of the builder.
+    pub fn with_scheme(scheme: ffi::SchemeType) -> Result<Self, BGVError> {
+        let inner = ffi::init(scheme.repr)?;
+        Ok(Self { inner })
+    }
 
-//     fn set_c<T, E>(mut self, value: T) -> Result<Self, BGVError>
-//     where
-//         Self: Sized,
-//         T: ToU32<E>,
-//         E: Into<SetError>,
-//     {
-//         let u32_value = value.to_u32().map_err(Into::<SetError>::into).map_err(Into::<BGVError>::into)?;
-//         // Assuming `ffi::set_c` returns Result<(), CError>
-//         self.inner = ffi::set_c(self.inner, u32_value);
-//         Ok(self)
-//     }
-
-//     fn set_p<T, E>(mut self, value: T) -> Result<Self, BGVError>
-//     where
-//         Self: Sized,
-//         T: ToU32<E>,
-//         E: Into<SetError>,
-//     {
-//         let u32_value = value.to_u32().map_err(Into::<SetError>::into).map_err(Into::<BGVError>::into)?;
-//         // Assuming `ffi::set_p` returns Result<(), PError>
-//         self.inner = ffi::set_p(self.inner, u32_value);
-//         Ok(self)
-//     }
-
-//     fn set_r<T, E>(mut self, value: T) -> Result<Self, BGVError>
-//     where
-//         Self: Sized,
-//         T: ToU32<E>,
-//         E: Into<SetError>,
-//     {
-//         let u32_value = value.to_u32().map_err(Into::<SetError>::into).map_err(Into::<BGVError>::into)?;
-//         // Assuming `ffi::set_r` returns Result<(), RError>
-//         self.inner = ffi::set_r(self.inner, u32_value);
-//         Ok(self)
-//     }
-
-    fn set<S, M>(self, value: M) -> Result<Self, BGVError>
-    where
-        M: FheMetric<S> + Into<M>,
-        S: FheScheme,
-    {
-        // Convert `value` into `Metric`, since `Into` is infallible
-        let metric = value.into();
+    /// Returns the SEAL scheme the builder was started with.
+    pub fn scheme(&self) -> ffi::SchemeType {
+        ffi::scheme_type(&self.inner)
+    }
+
+    /// Builds the SEAL context, consuming the builder.
+    ///
+    /// SEAL builds a context even from invalid parameters; check
+    /// `parameters_set` on the result.
+    pub fn build_context(self) -> Result<zno_seal_sys::bgv::Context, BGVError> {
+        Ok(zno_seal_sys::bgv::Context::new(self.inner)?)
+    }
+
+    /// Sets the degree of the polynomial modulus, a power of two such as 4096 or 8192.
+    ///
+    /// Set the degree first: the `*_bfv_default`, `*_bit_sizes` and `*_batching`
+    /// setters derive their moduli from it.
+    pub fn set_poly_modulus_degree(mut self, degree: u64) -> Result<Self, BGVError> {
+        if !degree.is_power_of_two() || degree < 2 || degree > 131072 {
+            return Err(invalid_input(format!("poly_modulus_degree {} is not a power of two in [2, 131072]", degree)));
+        }
+        self.inner = ffi::set_poly_modulus_degree(self.inner, degree)?;
+        Ok(self)
+    }
+
+    /// Sets the coefficient modulus from the values of its primes.
+    pub fn set_coeff_modulus(mut self, moduli: &[u64]) -> Result<Self, BGVError> {
+        if moduli.is_empty() || moduli.len() > 64 {
+            return Err(invalid_input(format!("coeff_modulus must have between 1 and 64 primes, not {}", moduli.len())));
+        }
+        if let Some(modulus) = moduli.iter().find(|&&modulus| modulus < 2 || modulus >> 60 != 0) {
+            return Err(invalid_input(format!("coeff_modulus prime {} is not in [2, 2^60)", modulus)));
+        }
+        self.inner = ffi::set_coeff_modulus(self.inner, moduli)?;
+        Ok(self)
+    }
+
+    /// Sets the coefficient modulus to SEAL's default for 128-bit security,
+    /// `CoeffModulus::BFVDefault`.
+    pub fn set_coeff_modulus_bfv_default(self) -> Result<Self, BGVError> {
+        let degree = self.poly_modulus_degree();
+        if !(1024..=32768).contains(&degree) {
+            return Err(invalid_input(format!("no default coeff_modulus for poly_modulus_degree {}", degree)));
+        }
+        let moduli = ffi::coeff_modulus_bfv_default(degree)?;
+        self.set_coeff_modulus(&moduli)
+    }
+
+    /// Sets the coefficient modulus to new primes of the given bit sizes,
+    /// `CoeffModulus::Create`.
+    pub fn set_coeff_modulus_bit_sizes(self, bit_sizes: &[i32]) -> Result<Self, BGVError> {
+        let degree = self.poly_modulus_degree();
+        if degree == 0 {
+            return Err(invalid_input("poly_modulus_degree must be set before coeff_modulus".to_string()));
+        }
+        if bit_sizes.is_empty() || bit_sizes.len() > 64 {
+            return Err(invalid_input(format!("coeff_modulus must have between 1 and 64 primes, not {}", bit_sizes.len())));
+        }
+        if let Some(bit_size) = bit_sizes.iter().find(|&&bit_size| !(2..=60).contains(&bit_size)) {
+            return Err(invalid_input(format!("coeff_modulus bit size {} is not in [2, 60]", bit_size)));
+        }
+        let moduli = ffi::coeff_modulus_create(degree, bit_sizes)?;
+        self.set_coeff_modulus(&moduli)
+    }
+
+    /// Sets the plaintext modulus.
+    ///
+    /// BFV and BGV only: CKKS encodes at a scale instead, and SEAL rejects a
+    /// plaintext modulus for it.
+    pub fn set_plain_modulus(mut self, modulus: u64) -> Result<Self, BGVError> {
+        if modulus < 2 || modulus >> 60 != 0 {
+            return Err(invalid_input(format!("plain_modulus {} is not in [2, 2^60)", modulus)));
+        }
+        self.inner = ffi::set_plain_modulus(self.inner, modulus)?;
+        Ok(self)
+    }
+
+    /// Sets the plaintext modulus to a prime of the given bit size that
+    /// supports batching, `PlainModulus::Batching`.
+    pub fn set_plain_modulus_batching(self, bit_size: i32) -> Result<Self, BGVError> {
+        let degree = self.poly_modulus_degree();
+        if degree == 0 {
+            return Err(invalid_input("poly_modulus_degree must be set before plain_modulus".to_string()));
+        }
+        if !(2..=60).contains(&bit_size) {
+            return Err(invalid_input(format!("plain_modulus bit size {} is not in [2, 60]", bit_size)));
+        }
+        let modulus = ffi::plain_modulus_batching(degree, bit_size)?;
+        self.set_plain_modulus(modulus)
+    }
+
+    fn poly_modulus_degree(&self) -> u64 {
+        self.inner.poly_modulus_degree() as u64
+    }
+}
+
+fn invalid_input(detail: String) -> BGVError {
+    GenericError::new(GenericErrorKind::InvalidInput(detail)).into()
+}
 
-        self.metric_set(metric)
+// SEAL takes the cyclotomic order `m` as the polynomial modulus degree `m / 2`,
+// and the coefficient modulus as primes of at most 60 bits each.
+fn metric_set(builder: Builder<FFIBGVBuilder>, metric: Metric) -> Result<Builder<FFIBGVBuilder>, BGVError> {
+    match metric {
+        Metric::Bits(value) => {
+            let bits = value.to_u32().map_err(|e| invalid_input(e.to_string()))?;
+            builder.set_coeff_modulus_bit_sizes(&coeff_modulus_bit_sizes(bits))
+        }
+        Metric::M(value) => {
+            let m = value.to_u32()?;
+            if m % 2 != 0 {
+                return Err(invalid_input(format!("m {} is not twice a power of two", m)));
+            }
+            builder.set_poly_modulus_degree(u64::from(m / 2))
+        }
+        Metric::P(value) => {
+            let p = value.to_u32().map_err(|e| invalid_input(e.to_string()))?;
+            builder.set_plain_modulus(u64::from(p))
+        }
     }
+}
+
+// Splits `bits` as evenly as possible over the fewest primes of at most 60 bits.
+fn coeff_modulus_bit_sizes(bits: u32) -> Vec<i32> {
+    let count = bits.div_ceil(60).max(1);
+    (0..count).map(|i| (bits / count + u32::from(i < bits % count)) as i32).collect()
+}
 
-    fn try_set<S, M>(self, value: M) -> Result<Self, BGVError>
+impl Setters for Builder<FFIBGVBuilder> {
+    fn set(self, value: Metric) -> Result<Self, BGVError> {
+        metric_set(self, value)
+    }
+
+    fn try_set<T>(self, value: T) -> Result<Self, BGVError>
     where
-        M: FheMetric<S> + TryInto<M, Error=BGVError>,
-        S: FheScheme,
-        Self: Sized,
+        T: TryInto<Metric, Error=BGVError>,
     {
-        // Convert `value` into `M`, since `TryInto` is fallible
+        // Convert `value` into `Metric`, since `TryInto` is fallible
         let metric = value.try_into()?;
 
-        self.metric_set(metric)
+        metric_set(self, metric)
     }
 }
 
@@ -181,10 +251,76 @@ mod tests {
     #[test]
     fn test_build_with_valid_builder() {
         let params = Parameters::default();
-        let builder = Builder::new(params).unwrap();
-        let context = builder.build();
+        let builder = Builder::<FFIBGVBuilder>::new(params).unwrap();
+        let context = builder.build_context();
         assert!(context.is_ok());
     }
+
+    #[test]
+    fn test_set_moduli() {
+        let builder = Builder::<FFIBGVBuilder> { inner: ffi::init(0x3).unwrap() }
+            .set_poly_modulus_degree(8192)
+            .and_then(|builder| builder.set_coeff_modulus_bfv_default())
+            .and_then(|builder| builder.set_plain_modulus_batching(20))
+            .expect("SEAL moduli");
+        assert_eq!(builder.poly_modulus_degree(), 8192);
+    }
+
+    #[test]
+    fn test_set_metrics() {
+        let context = Builder::<FFIBGVBuilder>::with_scheme(ffi::SchemeType::Bgv)
+            .and_then(|builder| builder.set(M::try_from(16384).unwrap().into()))
+            // Four primes of 55, 55, 54 and 54 bits.
+            .and_then(|builder| builder.set(Bits::try_from(218).unwrap().into()))
+            .and_then(|builder| builder.set(P::try_from(1032193).unwrap().into()))
+            .and_then(|builder| builder.build_context())
+            .expect("SEAL context");
+        assert!(context.parameters_set());
+        assert_eq!(coeff_modulus_bit_sizes(218), vec![55, 55, 54, 54]);
+    }
+
+    #[test]
+    fn test_set_m_odd() {
+        let builder = Builder::<FFIBGVBuilder>::with_scheme(ffi::SchemeType::Bgv).unwrap();
+        assert!(builder.set(M::try_from(4095).unwrap().into()).is_err());
+    }
+
+    #[test]
+    fn test_set_poly_modulus_degree_not_power_of_two() {
+        let builder = Builder::<FFIBGVBuilder> { inner: ffi::init(0x3).unwrap() };
+        assert!(builder.set_poly_modulus_degree(8000).is_err());
+    }
+
+    #[test]
+    fn test_set_coeff_modulus_above_60_bits() {
+        // 2^61 - 1 is prime, but SEAL caps coefficient-modulus primes at 60 bits.
+        let builder = Builder::<FFIBGVBuilder> { inner: ffi::init(0x3).unwrap() };
+        assert!(builder.set_coeff_modulus(&[(1 << 61) - 1]).is_err());
+    }
+
+    #[test]
+    fn test_set_plain_modulus_batching_before_degree() {
+        let builder = Builder::<FFIBGVBuilder> { inner: ffi::init(0x3).unwrap() };
+        assert!(builder.set_plain_modulus_batching(20).is_err());
+    }
+
+    #[test]
+    fn test_ckks_context() {
+        // 0x2 is Schema::Ckks
+        let builder = Builder::<FFIBGVBuilder> { inner: ffi::init(0x2).unwrap() }
+            .set_poly_modulus_degree(8192)
+            .and_then(|builder| builder.set_coeff_modulus_bit_sizes(&[60, 40, 40, 60]))
+            .expect("SEAL moduli");
+        assert!(builder.set_plain_modulus(1032193).is_err());
+
+        let builder = Builder::<FFIBGVBuilder> { inner: ffi::init(0x2).unwrap() }
+            .set_poly_modulus_degree(8192)
+            .and_then(|builder| builder.set_coeff_modulus_bit_sizes(&[60, 40, 40, 60]))
+            .expect("SEAL moduli");
+        let context = zno_seal_sys::bgv::Context::new(builder.inner).expect("CKKS context creation");
+        assert!(context.parameters_set());
+        assert!(zno_seal_sys::bgv::CKKSEncoder::new(&context).is_ok());
+    }
     // #[ignore = "Incomplete HELib FFI"]
     // #[test]
     // fn test_bgv_context_new() {
@@ -1,8 +1,82 @@
 pub use super::*;
 // use mockall::automock;
 
+use zno_seal_sys::bgv::{Context, ContextData};
+use zno_seal_sys::bgv::ffi::SchemeType;
+
+use crate::error::ConversionError;
+use crate::seal::bgv::{Bits, BitsError, BitsErrorKind, M, MError, MErrorKind, P, PError, PErrorKind};
+
+/// Reads the parameters back from a context, so it can be audited after construction.
+///
+/// SEAL has no `r`, `c`, `gens`, `ords`, `mvec` or bootstrapping: those are
+/// HElib parameters, see `crate::helib::getters::Getters`.
 pub trait Getters {
-    // fn get_m(&self) -> Result<M, MError>;
-    // fn get_p(&self) -> Result<P, PError>;
-    // add other getter methods here . . .
+    /// Returns the cyclotomic order `m`, twice the polynomial modulus degree.
+    fn get_m(&self) -> Result<M, MError>;
+    /// Returns the plaintext modulus `p`.
+    fn get_p(&self) -> Result<P, PError>;
+    /// Returns the size of the coefficient modulus in bits.
+    fn get_bits(&self) -> Result<Bits, BitsError>;
+    /// Returns the number of plaintext slots: `n` for BFV and BGV, `n / 2` for CKKS.
+    ///
+    /// Returns `ConversionError::NoValue` for a BFV or BGV context whose plain
+    /// modulus does not support batching, as it has no slots.
+    fn get_slots(&self) -> Result<u32, ConversionError>;
+    /// Returns `phi(m)`, the polynomial modulus degree.
+    fn get_phi_m(&self) -> Result<u32, ConversionError>;
+    /// Returns the security level in bits, `0` if the parameters do not meet the HomomorphicEncryption.org standard.
+    fn get_security_level(&self) -> Result<f64, ConversionError>;
+    /// Returns the number of levels in the modulus chain that ciphertexts can be at.
+    fn get_modulus_chain_length(&self) -> Result<u32, ConversionError>;
+}
+
+// The parameters of the whole chain are those of the key level.
+fn key_context_data(context: &Context) -> Result<ContextData, ConversionError> {
+    context.key_context_data().ok_or(ConversionError::NoValue)
+}
+
+fn to_u32(value: usize) -> Result<u32, ConversionError> {
+    u32::try_from(value).map_err(ConversionError::OutOfRange)
+}
+
+impl Getters for Context {
+    fn get_m(&self) -> Result<M, MError> {
+        let data = self.key_context_data().ok_or_else(|| MError::new(MErrorKind::InvalidContext, "Context", "M"))?;
+        // SEAL uses power-of-two cyclotomics, so m = 2n.
+        M::try_from(2 * data.poly_modulus_degree())
+    }
+
+    fn get_p(&self) -> Result<P, PError> {
+        let data = self.key_context_data().ok_or_else(|| PError::new(PErrorKind::InvalidContext, "Context", "P"))?;
+        P::try_from(data.plain_modulus())
+    }
+
+    fn get_bits(&self) -> Result<Bits, BitsError> {
+        let data = self.key_context_data().ok_or_else(|| BitsError::new(BitsErrorKind::InvalidContext, "Context", "Bits"))?;
+        Bits::try_from(data.total_coeff_modulus_bit_count())
+    }
+
+    fn get_slots(&self) -> Result<u32, ConversionError> {
+        let data = key_context_data(self)?;
+        match data.scheme() {
+            // CKKS packs complex numbers, which come in conjugate pairs.
+            SchemeType::Ckks => to_u32(data.poly_modulus_degree() / 2),
+            _ if data.qualifiers().using_batching => to_u32(data.poly_modulus_degree()),
+            _ => Err(ConversionError::NoValue),
+        }
+    }
+
+    fn get_phi_m(&self) -> Result<u32, ConversionError> {
+        to_u32(key_context_data(self)?.poly_modulus_degree())
+    }
+
+    fn get_security_level(&self) -> Result<f64, ConversionError> {
+        Ok(f64::from(key_context_data(self)?.qualifiers().sec_level))
+    }
+
+    fn get_modulus_chain_length(&self) -> Result<u32, ConversionError> {
+        let data = self.first_context_data().ok_or(ConversionError::NoValue)?;
+        to_u32(data.chain_index() + 1)
+    }
 }
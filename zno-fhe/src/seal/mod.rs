@@ -1,3 +1,4 @@
+pub mod bfv;
 pub mod bgv;
 pub mod builder;
 pub mod error;
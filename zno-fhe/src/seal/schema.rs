@@ -25,7 +25,6 @@ impl From<crate::fhe::Schema> for Schema {
             crate::fhe::Schema::Bfv => Self::Bfv,
             crate::fhe::Schema::Bgv => Self::Bgv,
             crate::fhe::Schema::Ckks => Self::Ckks,
-            _ => crate::fhe::Schema::default(),
         }
     }
 }
@@ -44,10 +43,10 @@ impl From<Schema> for crate::fhe::Schema {
     /// The converted `zno_fhe::Schema`.
     fn from(schema: Schema) -> Self {
         match schema {
-            Self::Bfv  => crate::fhe::Schema::Bfv,
-            Self::Ckks => crate::fhe::Schema::Ckks,
-            Self::Bgv  => crate::fhe::Schema::Bgv,
-            _ => crate::fhe::Schema::default(),
+            Schema::Bfv  => crate::fhe::Schema::Bfv,
+            Schema::Ckks => crate::fhe::Schema::Ckks,
+            Schema::Bgv  => crate::fhe::Schema::Bgv,
+            Schema::None => crate::fhe::Schema::default(),
         }
     }
 }
@@ -65,7 +64,7 @@ impl Default for Schema {
     ///
     /// The default value for `Schema`, which is `Schema::Bgv`.
     fn default() -> Self {
-        crate::fhe::Schema::Bgv
+        Schema::Bgv
     }
 }
 
@@ -3,7 +3,7 @@ use std::ops::DerefMut;
 
 pub use super::*;
 
-use crate::seal::bgv::MError;
+use crate::seal::bgv::{Metric, MError};
 
 pub use crate::error::*;
 pub use crate::fhe::*;
@@ -14,17 +14,14 @@ pub use crate::prelude::*;
 
 pub trait Setters {
 
-        fn set<S, M>(self, value: M) -> Result<Self, BGVError>
-        where
-            Self: Sized,
-            M: FheMetric<S>, // The `Into` `M` conversion doesn't fail
-            S: FheScheme;
+    fn set(self, value: Metric) -> Result<Self, BGVError>
+    where
+        Self: Sized; // The `Into` `M` conversion doesn't fail
 
-        fn try_set<S, M>(self, value: M) -> Result<Self, BGVError>
-        where
-            Self: Sized,
-            M: FheMetric<S> + TryInto<M, Error=BGVError>,
-            S: FheScheme;
+    fn try_set<T>(self, value: T) -> Result<Self, BGVError>
+    where
+        Self: Sized,
+        T: TryInto<Metric, Error=BGVError>;
 
     // fn set_m<T, E>(self, value: T) -> Result<Self, BGVError>
     // where
@@ -203,7 +200,7 @@ impl From<SetError> for BGVError {
             // SetError::Bootstrappable(error) => BGVError::BootstrappableError(error),
             // SetError::C(error) => BGVError::CError(error),
             // SetError::Gens(error) => BGVError::GensError(error),
-            SetError::M(error) => BGVError::MError(error),
+            SetError::M(error) => BGVError::SealMError(error),
             // SetError::Mvec(error) => BGVError::MvecError(error),
             // SetError::Ords(error) => BGVError::OrdsError(error),
             // SetError::P(error) => BGVError::PError(error),
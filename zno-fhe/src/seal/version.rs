@@ -1,5 +1,5 @@
 pub fn version() -> String {
-    zno_seal_sys::bgv::ffi::version()
+    zno_seal_sys::bgv::ffi::version().to_string()
 }
 
 #[cfg(test)]
@@ -1,5 +1,9 @@
 #include "ffi_wrapper.h"
 
+#include <cctype>
+#include <cstring>
+#include <string_view>
+
 /**
  * This file contains the implementation of various functions related to the Foreign Function Interface (FFI) for the helib library.
  * The FFI allows Rust and C++ to interoperate by providing functions that can be called from Rust code.
@@ -140,43 +144,76 @@ namespace helib {
     return builder; // Return the unique_ptr.
   }
 
+  /**
+   * Sets the Hamming weight of the secret key in the BGVContextBuilder.
+   *
+   * @param builder The BGVContextBuilder object.
+   * @param sk_hwt The Hamming weight of the secret key, 0 for HElib's default.
+   * @return A unique pointer to the updated BGVContextBuilder object.
+   */
+  std::unique_ptr<::helib::BGVContextBuilder> set_sk_hwt(std::unique_ptr<::helib::BGVContextBuilder> builder, uint32_t sk_hwt) {
+    builder->skHwt(sk_hwt);
+    return builder; // Return the unique_ptr.
+  }
+
+  /**
+   * Sets the bit resolution of the modulus chain in the BGVContextBuilder.
+   *
+   * @param builder The BGVContextBuilder object.
+   * @param bits The bit resolution used when choosing the primes of the chain.
+   * @return A unique pointer to the updated BGVContextBuilder object.
+   */
+  std::unique_ptr<::helib::BGVContextBuilder> set_resolution(std::unique_ptr<::helib::BGVContextBuilder> builder, uint32_t bits) {
+    builder->resolution(bits);
+    return builder; // Return the unique_ptr.
+  }
+
+  /**
+   * Sets the number of bits of the special primes in the BGVContextBuilder.
+   *
+   * @param builder The BGVContextBuilder object.
+   * @param bits The size in bits of the special primes, used for key switching, 0 for HElib's default.
+   * @return A unique pointer to the updated BGVContextBuilder object.
+   */
+  std::unique_ptr<::helib::BGVContextBuilder> set_bits_in_special_primes(std::unique_ptr<::helib::BGVContextBuilder> builder, uint32_t bits) {
+    builder->bitsInSpecialPrimes(bits);
+    return builder; // Return the unique_ptr.
+  }
+
   /**
    * Sets the value of `gens` in the BGVContextBuilder.
    *
    * @param builder The BGVContextBuilder object.
-   * @param gens The value of `gens` to be set.
-   * @return The modified BGVContextBuilder object.
+   * @param gens The generators of `(Z/mZ)^*`, one per dimension of the hypercube.
+   * @return A unique pointer to the updated BGVContextBuilder object.
    */
-  BGVContextBuilder& set_gens(BGVContextBuilder& builder, const rust::cxxbridge1::Vec<long int>& gens) {
-    auto gens_std_ptr = to_std_vector(gens); // This is now a std::unique_ptr<std::vector<long int>>
-    builder.gens(*gens_std_ptr); // Dereference the std::unique_ptr to get the std::vector
-    return builder;
+  std::unique_ptr<::helib::BGVContextBuilder> set_gens(std::unique_ptr<::helib::BGVContextBuilder> builder, const rust::Vec<int64_t>& gens) {
+    builder->gens(std::vector<long>(gens.begin(), gens.end()));
+    return builder; // Return the unique_ptr.
   }
 
   /**
    * Sets the value of `ords` in the BGVContextBuilder.
    *
    * @param builder The BGVContextBuilder object.
-   * @param ords The value of `ords` to be set.
-   * @return The modified BGVContextBuilder object.
+   * @param ords The order of each generator in `gens`, negative for a bad dimension.
+   * @return A unique pointer to the updated BGVContextBuilder object.
    */
-  BGVContextBuilder& set_ords(BGVContextBuilder& builder, const rust::cxxbridge1::Vec<long int>& ords) {
-    auto ords_std_ptr = to_std_vector(ords); // This is now a std::unique_ptr<std::vector<long int>>
-    builder.ords(*ords_std_ptr); // Dereference the std::unique_ptr to get the std::vector
-    return builder;
+  std::unique_ptr<::helib::BGVContextBuilder> set_ords(std::unique_ptr<::helib::BGVContextBuilder> builder, const rust::Vec<int64_t>& ords) {
+    builder->ords(std::vector<long>(ords.begin(), ords.end()));
+    return builder; // Return the unique_ptr.
   }
 
   /**
    * Sets the value of `mvec` in the BGVContextBuilder.
    *
    * @param builder The BGVContextBuilder object.
-   * @param mvec The value of `mvec` to be set.
-   * @return The modified BGVContextBuilder object.
+   * @param mvec The factorization of `m` into coprime factors, whose product is `m`.
+   * @return A unique pointer to the updated BGVContextBuilder object.
    */
-  BGVContextBuilder& set_mvec(BGVContextBuilder& builder, const rust::cxxbridge1::Vec<long int>& mvec) {
-    auto mvec_std_ptr = to_std_vector(mvec); // This is now a std::unique_ptr<std::vector<long int>>
-    builder.mvec(*mvec_std_ptr); // Dereference the std::unique_ptr to get the std::vector
-    return builder;
+  std::unique_ptr<::helib::BGVContextBuilder> set_mvec(std::unique_ptr<::helib::BGVContextBuilder> builder, const rust::Vec<int64_t>& mvec) {
+    builder->mvec(std::vector<long>(mvec.begin(), mvec.end()));
+    return builder; // Return the unique_ptr.
   }
 
   /**
@@ -213,6 +250,73 @@ namespace helib {
     return builder;
   }
 
+  /**
+   * Creates a new CKKSContextBuilder object.
+   *
+   * @return A unique pointer to the newly created ContextBuilder<CKKS> object.
+   */
+  std::unique_ptr<::helib::CKKSContextBuilder> init_ckks() {
+    return std::make_unique<::helib::CKKSContextBuilder>();
+  }
+
+  /**
+   * Builds a CKKS Context.
+   *
+   * @param builder The CKKSContextBuilder object, consumed.
+   * @return A unique pointer to the newly created Context object.
+   */
+  std::unique_ptr<::helib::Context> build_ckks(std::unique_ptr<::helib::CKKSContextBuilder> builder) {
+    return std::unique_ptr<::helib::Context>(builder->buildPtr());
+  }
+
+  /**
+   * Sets the value of `m` in the CKKSContextBuilder. For CKKS, `m` must be a power of two.
+   *
+   * @param builder The CKKSContextBuilder object.
+   * @param m The value of `m` to be set.
+   * @return A unique pointer to the updated CKKSContextBuilder object.
+   */
+  std::unique_ptr<::helib::CKKSContextBuilder> ckks_set_m(std::unique_ptr<::helib::CKKSContextBuilder> builder, uint32_t m) {
+    builder->m(m);
+    return builder; // Return the unique_ptr.
+  }
+
+  /**
+   * Sets the number of bits of the modulus chain in the CKKSContextBuilder.
+   *
+   * @param builder The CKKSContextBuilder object.
+   * @param bits The value of bits to be set.
+   * @return A unique pointer to the updated CKKSContextBuilder object.
+   */
+  std::unique_ptr<::helib::CKKSContextBuilder> ckks_set_bits(std::unique_ptr<::helib::CKKSContextBuilder> builder, uint32_t bits) {
+    builder->bits(bits);
+    return builder; // Return the unique_ptr.
+  }
+
+  /**
+   * Sets the bits of precision of the slots in the CKKSContextBuilder.
+   *
+   * @param builder The CKKSContextBuilder object.
+   * @param precision The bits of precision, which HElib uses as `r`.
+   * @return A unique pointer to the updated CKKSContextBuilder object.
+   */
+  std::unique_ptr<::helib::CKKSContextBuilder> ckks_set_precision(std::unique_ptr<::helib::CKKSContextBuilder> builder, uint32_t precision) {
+    builder->precision(precision);
+    return builder; // Return the unique_ptr.
+  }
+
+  /**
+   * Sets the number of columns of the key-switching matrices in the CKKSContextBuilder.
+   *
+   * @param builder The CKKSContextBuilder object.
+   * @param c The value of c to be set.
+   * @return A unique pointer to the updated CKKSContextBuilder object.
+   */
+  std::unique_ptr<::helib::CKKSContextBuilder> ckks_set_c(std::unique_ptr<::helib::CKKSContextBuilder> builder, uint32_t c) {
+    builder->c(c);
+    return builder; // Return the unique_ptr.
+  }
+
   /**
    * Gets the value of `m` from the Context object.
    *
@@ -221,9 +325,1492 @@ namespace helib {
    */
   long get_m(const Context& context) { return context.getM(); }
 
-  // Define the C++ helper function for the FFI
-  void multiplyBy(std::unique_ptr<::helib::Ctxt>& ciphertext, std::unique_ptr<::helib::Ctxt>& other) {
-    ciphertext->multiplyBy(*other);
+  /**
+   * Creates a SecKey object for the Context. Call GenSecKey before using it.
+   *
+   * @param context The Context, which must outlive the key.
+   * @return A unique pointer to the newly created SecKey object.
+   */
+  std::unique_ptr<::helib::SecKey> new_sec_key(const ::helib::Context& context) {
+    return std::make_unique<::helib::SecKey>(context);
+  }
+
+  /**
+   * Copies the public part of a SecKey object, i.e. the PubKey it derives from.
+   *
+   * @param secret_key The SecKey object, with its key-switching matrices already added.
+   * @return A unique pointer to the copy of the PubKey.
+   */
+  std::unique_ptr<::helib::PubKey> public_key(const ::helib::SecKey& secret_key) {
+    return std::make_unique<::helib::PubKey>(static_cast<const ::helib::PubKey&>(secret_key));
+  }
+
+  /**
+   * Creates an empty Ctxt object encrypted under the PubKey.
+   *
+   * @param public_key The PubKey, which must outlive the ciphertext.
+   * @return A unique pointer to the newly created Ctxt object.
+   */
+  std::unique_ptr<::helib::Ctxt> new_ctxt(const ::helib::PubKey& public_key) {
+    return std::make_unique<::helib::Ctxt>(public_key);
+  }
+
+  /**
+   * Encrypts a constant into every slot of a new Ctxt object.
+   *
+   * @param public_key The PubKey to encrypt with.
+   * @param constant The constant, reduced modulo the plaintext space.
+   * @return A unique pointer to the newly created Ctxt object.
+   */
+  std::unique_ptr<::helib::Ctxt> encrypt_constant(const ::helib::PubKey& public_key, int64_t constant) {
+    auto ctxt = std::make_unique<::helib::Ctxt>(public_key);
+    NTL::ZZX poly;
+    NTL::SetCoeff(poly, 0, NTL::conv<NTL::ZZ>(static_cast<long>(constant)));
+    public_key.Encrypt(*ctxt, poly);
+    return ctxt;
+  }
+
+  /**
+   * Decrypts a Ctxt object that encrypts a constant.
+   *
+   * @param secret_key The SecKey to decrypt with.
+   * @param ctxt The Ctxt object to decrypt.
+   * @return The constant term of the plaintext polynomial, in [0, p^r).
+   */
+  int64_t decrypt_constant(const ::helib::SecKey& secret_key, const ::helib::Ctxt& ctxt) {
+    NTL::ZZX poly;
+    secret_key.Decrypt(poly, ctxt);
+    return NTL::conv<long>(NTL::ConstTerm(poly));
+  }
+
+  /**
+   * Initialises the recryption data of the Context.
+   *
+   * @param context The Context, built with `bootstrappable(true)`.
+   * @param mvec The factorization of `m` into coprime factors, whose product is `m`.
+   * @param build_cache Whether to precompute the linear maps, faster but larger.
+   * @param also_thick Whether to also initialise the data for thick bootstrapping.
+   */
+  void enable_bootstrapping(::helib::Context& context, const rust::Vec<int64_t>& mvec, bool build_cache, bool also_thick) {
+    NTL::Vec<long> ntl_mvec;
+    ntl_mvec.SetLength(mvec.size());
+    for (size_t i = 0; i < mvec.size(); ++i) {
+      ntl_mvec[i] = static_cast<long>(mvec[i]);
+    }
+    context.enableBootStrapping(ntl_mvec, build_cache, also_thick);
+  }
+
+  /**
+   * Returns the generators of (Z/mZ)^* /(p) of the Context.
+   *
+   * @param context The Context.
+   * @return The generators, one per dimension of the hypercube.
+   */
+  rust::Vec<int64_t> context_gens(const ::helib::Context& context) {
+    const ::helib::PAlgebra& zm_star = context.getZMStar();
+    rust::Vec<int64_t> gens;
+    for (long i = 0; i < zm_star.numOfGens(); ++i) {
+      gens.push_back(zm_star.ZmStarGen(i));
+    }
+    return gens;
+  }
+
+  /**
+   * Returns the orders of the generators of the Context.
+   *
+   * @param context The Context.
+   * @return The orders, positive also for the dimensions the builder marks negative.
+   */
+  rust::Vec<int64_t> context_ords(const ::helib::Context& context) {
+    const ::helib::PAlgebra& zm_star = context.getZMStar();
+    rust::Vec<int64_t> ords;
+    for (long i = 0; i < zm_star.numOfGens(); ++i) {
+      ords.push_back(zm_star.OrderOf(i));
+    }
+    return ords;
+  }
+
+  /**
+   * Returns the factorization of `m` the recryption data was initialised with.
+   *
+   * @param context The Context.
+   * @return The factors, empty until `enable_bootstrapping` was called.
+   */
+  rust::Vec<int64_t> context_mvec(const ::helib::Context& context) {
+    const NTL::Vec<long>& mvec = context.getRcData().mvec;
+    rust::Vec<int64_t> factors;
+    for (long i = 0; i < mvec.length(); ++i) {
+      factors.push_back(mvec[i]);
+    }
+    return factors;
+  }
+
+  /**
+   * Returns the number of columns of the key-switching matrices, i.e. the number of digits.
+   *
+   * @param context The Context.
+   * @return The number of digits, at most the `c` the builder was given.
+   */
+  int64_t context_c(const ::helib::Context& context) {
+    return static_cast<int64_t>(context.getDigits().size());
+  }
+
+  /**
+   * Returns the size of the modulus chain, the product of the ciphertext primes, in bits.
+   *
+   * @param context The Context.
+   * @return The number of bits, rounded up.
+   */
+  int64_t context_bits(const ::helib::Context& context) {
+    return static_cast<int64_t>(std::ceil(context.logOfProduct(context.getCtxtPrimes()) / std::log(2.0)));
+  }
+
+  /**
+   * Returns the length of the modulus chain.
+   *
+   * @param context The Context.
+   * @return The number of ciphertext primes.
+   */
+  int64_t context_chain_length(const ::helib::Context& context) {
+    return context.getCtxtPrimes().card();
+  }
+
+  /**
+   * Copies a Ctxt object.
+   *
+   * @param ctxt The Ctxt object to copy.
+   * @return A unique pointer to the copy, encrypted under the same public key.
+   */
+  std::unique_ptr<::helib::Ctxt> ctxt_clone(const ::helib::Ctxt& ctxt) {
+    return std::make_unique<::helib::Ctxt>(ctxt);
+  }
+
+  /**
+   * Adds `other` to `ctxt`, i.e. `ctxt += other`.
+   *
+   * @param ctxt The Ctxt object to modify.
+   * @param other The Ctxt object to add, encrypted under the same public key.
+   */
+  void add_assign(::helib::Ctxt& ctxt, const ::helib::Ctxt& other) {
+    ctxt += other;
+  }
+
+  /**
+   * Subtracts `other` from `ctxt`, i.e. `ctxt -= other`.
+   *
+   * @param ctxt The Ctxt object to modify.
+   * @param other The Ctxt object to subtract, encrypted under the same public key.
+   */
+  void sub_assign(::helib::Ctxt& ctxt, const ::helib::Ctxt& other) {
+    ctxt -= other;
+  }
+
+  /**
+   * Multiplies `ctxt` by `other` and relinearizes the result, i.e. `ctxt *= other`.
+   *
+   * @param ctxt The Ctxt object to modify.
+   * @param other The Ctxt object to multiply by, encrypted under the same public key.
+   */
+  void mul_assign(::helib::Ctxt& ctxt, const ::helib::Ctxt& other) {
+    ctxt *= other;
+  }
+
+  /**
+   * Multiplies every slot of `ctxt` by a constant.
+   *
+   * @param ctxt The Ctxt object to modify.
+   * @param constant The constant, reduced modulo the plaintext space.
+   */
+  void mult_by_constant(::helib::Ctxt& ctxt, int64_t constant) {
+    ctxt.multByConstant(static_cast<long>(constant));
+  }
+
+  /**
+   * Adds a constant to every slot of `ctxt`.
+   *
+   * @param ctxt The Ctxt object to modify.
+   * @param constant The constant, reduced modulo the plaintext space.
+   */
+  void add_constant(::helib::Ctxt& ctxt, int64_t constant) {
+    ctxt.addConstant(static_cast<long>(constant));
+  }
+
+  /**
+   * Gets the EncryptedArray of the Context.
+   *
+   * `Context::shareEA` returns `const std::shared_ptr<const EncryptedArray>&`,
+   * which cxx spells without the inner `const`, so the generated binding does
+   * not compile. Rather than patch HElib, this returns the reference from
+   * `getEA`: cxx binds `const EncryptedArray&` as `&EncryptedArray`, and the
+   * Context keeps ownership.
+   *
+   * @param context The Context, which must outlive the reference.
+   * @return A reference to the EncryptedArray owned by the Context.
+   */
+  const ::helib::EncryptedArray& get_ea(const ::helib::Context& context) {
+    return context.getEA();
+  }
+
+  /**
+   * Encodes one value per slot into a plaintext polynomial.
+   *
+   * @param ea The EncryptedArray of the Context.
+   * @param slots The slot values, at most `ea.size()` of them; the rest are zero.
+   * @return A unique pointer to the plaintext polynomial.
+   */
+  std::unique_ptr<NTL::ZZX> ea_encode(const ::helib::EncryptedArray& ea, const rust::Vec<int64_t>& slots) {
+    std::vector<long> array(ea.size(), 0);
+    if (slots.size() > array.size()) {
+      throw ::helib::OutOfRangeError("More values than slots");
+    }
+    std::copy(slots.begin(), slots.end(), array.begin());
+    auto poly = std::make_unique<NTL::ZZX>();
+    ea.encode(*poly, array);
+    return poly;
+  }
+
+  /**
+   * Decodes a plaintext polynomial into one value per slot.
+   *
+   * @param ea The EncryptedArray of the Context.
+   * @param poly The plaintext polynomial.
+   * @return The `ea.size()` slot values.
+   */
+  rust::Vec<int64_t> ea_decode(const ::helib::EncryptedArray& ea, const NTL::ZZX& poly) {
+    std::vector<long> array;
+    ea.decode(array, poly);
+    rust::Vec<int64_t> slots;
+    slots.reserve(array.size());
+    for (long value : array) {
+      slots.push_back(value);
+    }
+    return slots;
+  }
+
+  /**
+   * Checks that the key-switching matrices of the key `ctxt` is encrypted
+   * under reach the automorphisms that rotate along dimension `i` by `k`.
+   *
+   * A non-native dimension, whose generator has a different order in Z_m^*
+   * than in Z_m^* / (p), also needs the automorphism by `-ord`.
+   *
+   * @param ea The EncryptedArray of the Context.
+   * @param ctxt The Ctxt object to rotate.
+   * @param i The dimension, in [0, ea.dimension()).
+   * @param k The number of slots to rotate by.
+   * @return true if `Ctxt::smartAutomorph` can apply every automorphism needed.
+   */
+  static bool rotation_reachable(const ::helib::EncryptedArray& ea, const ::helib::Ctxt& ctxt, long i, long k) {
+    const ::helib::PAlgebra& zMStar = ea.getPAlgebra();
+    const ::helib::PubKey& public_key = ctxt.getPubKey();
+    long key_id = ctxt.getKeyID();
+    long ord = zMStar.OrderOf(i);
+    k = ((k % ord) + ord) % ord;
+    if (k == 0) {
+      return true;
+    }
+    return public_key.isReachable(zMStar.genToPow(i, k), key_id) &&
+           (ea.nativeDimension(i) || public_key.isReachable(zMStar.genToPow(i, -ord), key_id));
+  }
+
+  /**
+   * Checks that the key-switching matrices of the key `ctxt` is encrypted
+   * under reach the automorphism that shifts along dimension `i` by `k`.
+   * Shifting by `ord` or more zeroes the slots instead.
+   *
+   * @param ea The EncryptedArray of the Context.
+   * @param ctxt The Ctxt object to shift.
+   * @param i The dimension, in [0, ea.dimension()).
+   * @param k The number of slots to shift by.
+   * @return true if `Ctxt::smartAutomorph` can apply the automorphism needed.
+   */
+  static bool shift_reachable(const ::helib::EncryptedArray& ea, const ::helib::Ctxt& ctxt, long i, long k) {
+    const ::helib::PAlgebra& zMStar = ea.getPAlgebra();
+    long ord = zMStar.OrderOf(i);
+    if (k == 0 || k <= -ord || k >= ord) {
+      return true;
+    }
+    return ctxt.getPubKey().isReachable(zMStar.genToPow(i, k), ctxt.getKeyID());
+  }
+
+  /**
+   * Checks that every rotation and shift along every dimension is reachable,
+   * as the rotations and shifts of the linear array combine them.
+   *
+   * @param ea The EncryptedArray of the Context.
+   * @param ctxt The Ctxt object to rotate.
+   * @return true if `Ctxt::smartAutomorph` can apply every automorphism needed.
+   */
+  static bool rotations_reachable(const ::helib::EncryptedArray& ea, const ::helib::Ctxt& ctxt) {
+    for (long i = 0; i < ea.dimension(); ++i) {
+      for (long k = 1; k < ea.sizeOfDimension(i); ++k) {
+        if (!rotation_reachable(ea, ctxt, i, k) || !shift_reachable(ea, ctxt, i, -k)) {
+          return false;
+        }
+      }
+    }
+    return true;
+  }
+
+  /**
+   * Rotates the slots of `ctxt` right by `k`, as a linear array.
+   *
+   * @param ea The EncryptedArray of the Context.
+   * @param ctxt The Ctxt object to modify.
+   * @param k The number of slots to rotate by, negative to rotate left.
+   * @return false, leaving `ctxt` unchanged, if a key-switching matrix is
+   *         missing. Rotating by 0 needs none.
+   */
+  bool ea_rotate(const ::helib::EncryptedArray& ea, ::helib::Ctxt& ctxt, int64_t k) {
+    if (k == 0) {
+      return true;
+    }
+    if (!rotations_reachable(ea, ctxt)) {
+      return false;
+    }
+    ea.rotate(ctxt, static_cast<long>(k));
+    return true;
+  }
+
+  /**
+   * Shifts the slots of `ctxt` right by `k`, as a linear array, filling with zeros.
+   *
+   * @param ea The EncryptedArray of the Context.
+   * @param ctxt The Ctxt object to modify.
+   * @param k The number of slots to shift by, negative to shift left.
+   * @return false, leaving `ctxt` unchanged, if a key-switching matrix is
+   *         missing. Shifting by 0 needs none.
+   */
+  bool ea_shift(const ::helib::EncryptedArray& ea, ::helib::Ctxt& ctxt, int64_t k) {
+    if (k == 0) {
+      return true;
+    }
+    if (!rotations_reachable(ea, ctxt)) {
+      return false;
+    }
+    ea.shift(ctxt, static_cast<long>(k));
+    return true;
+  }
+
+  /**
+   * Rotates the slots of `ctxt` by `k` along dimension `i` of the hypercube.
+   *
+   * @param ea The EncryptedArray of the Context.
+   * @param ctxt The Ctxt object to modify.
+   * @param i The dimension, in [0, ea.dimension()); HElib throws otherwise.
+   * @param k The number of slots to rotate by.
+   * @return false, leaving `ctxt` unchanged, if a key-switching matrix is missing.
+   */
+  bool ea_rotate_1d(const ::helib::EncryptedArray& ea, ::helib::Ctxt& ctxt, int64_t i, int64_t k) {
+    if (i >= 0 && i < ea.dimension() && !rotation_reachable(ea, ctxt, i, k)) {
+      return false;
+    }
+    ea.rotate1D(ctxt, static_cast<long>(i), static_cast<long>(k));
+    return true;
+  }
+
+  /**
+   * Shifts the slots of `ctxt` by `k` along dimension `i` of the hypercube, filling with zeros.
+   *
+   * @param ea The EncryptedArray of the Context.
+   * @param ctxt The Ctxt object to modify.
+   * @param i The dimension, in [0, ea.dimension()); HElib throws otherwise.
+   * @param k The number of slots to shift by.
+   * @return false, leaving `ctxt` unchanged, if a key-switching matrix is missing.
+   */
+  bool ea_shift_1d(const ::helib::EncryptedArray& ea, ::helib::Ctxt& ctxt, int64_t i, int64_t k) {
+    if (i >= 0 && i < ea.dimension() && !shift_reachable(ea, ctxt, i, k)) {
+      return false;
+    }
+    ea.shift1D(ctxt, static_cast<long>(i), static_cast<long>(k));
+    return true;
+  }
+
+  /**
+   * Replaces every slot of `ctxt` by the sum of all slots.
+   *
+   * @param ea The EncryptedArray of the Context.
+   * @param ctxt The Ctxt object to modify.
+   * @return false, leaving `ctxt` unchanged, if a key-switching matrix is missing.
+   */
+  bool total_sums(const ::helib::EncryptedArray& ea, ::helib::Ctxt& ctxt) {
+    if (!rotations_reachable(ea, ctxt)) {
+      return false;
+    }
+    ::helib::totalSums(ea, ctxt);
+    return true;
+  }
+
+  /**
+   * Replaces slot `i` of `ctxt` by the sum of slots `0..=i`.
+   *
+   * @param ea The EncryptedArray of the Context.
+   * @param ctxt The Ctxt object to modify.
+   * @return false, leaving `ctxt` unchanged, if a key-switching matrix is missing.
+   */
+  bool running_sums(const ::helib::EncryptedArray& ea, ::helib::Ctxt& ctxt) {
+    if (!rotations_reachable(ea, ctxt)) {
+      return false;
+    }
+    ::helib::runningSums(ea, ctxt);
+    return true;
+  }
+
+  /**
+   * Creates a Ptxt<BGV> object with every slot set to zero.
+   *
+   * @param context The Context, which must outlive the plaintext.
+   * @return A unique pointer to the newly created Ptxt<BGV> object.
+   */
+  std::unique_ptr<::helib::PtxtBGV> new_ptxt(const ::helib::Context& context) {
+    return std::make_unique<::helib::PtxtBGV>(context);
+  }
+
+  /**
+   * Creates a Ptxt<BGV> object from one value per slot.
+   *
+   * @param context The Context, which must outlive the plaintext.
+   * @param slots The slot values, at most `context.getNSlots()` of them; the rest are zero.
+   * @return A unique pointer to the newly created Ptxt<BGV> object.
+   */
+  std::unique_ptr<::helib::PtxtBGV> ptxt_from_slots(const ::helib::Context& context, const rust::Vec<int64_t>& slots) {
+    std::vector<long> data(slots.begin(), slots.end());
+    return std::make_unique<::helib::PtxtBGV>(context, data);
+  }
+
+  /**
+   * Copies a Ptxt<BGV> object.
+   *
+   * @param ptxt The Ptxt<BGV> object to copy.
+   * @return A unique pointer to the copy.
+   */
+  std::unique_ptr<::helib::PtxtBGV> ptxt_clone(const ::helib::PtxtBGV& ptxt) {
+    return std::make_unique<::helib::PtxtBGV>(ptxt);
+  }
+
+  /**
+   * Gets the value of a slot.
+   *
+   * @param ptxt The Ptxt<BGV> object.
+   * @param index The slot, which must be less than `ptxt.size()`.
+   * @return The constant term of the slot, in [0, p^r).
+   */
+  int64_t ptxt_get(const ::helib::PtxtBGV& ptxt, size_t index) {
+    return static_cast<long>(ptxt.at(static_cast<long>(index)));
+  }
+
+  /**
+   * Sets the value of a slot.
+   *
+   * @param ptxt The Ptxt<BGV> object to modify.
+   * @param index The slot, which must be less than `ptxt.size()`.
+   * @param value The value, reduced modulo the plaintext space.
+   */
+  void ptxt_set(::helib::PtxtBGV& ptxt, size_t index, int64_t value) {
+    ptxt.at(static_cast<long>(index)) = static_cast<long>(value);
+  }
+
+  /**
+   * Copies the slot values of a Ptxt<BGV> object.
+   *
+   * @param ptxt The Ptxt<BGV> object.
+   * @return The constant term of every slot, in [0, p^r).
+   */
+  rust::Vec<int64_t> ptxt_to_vec(const ::helib::PtxtBGV& ptxt) {
+    rust::Vec<int64_t> slots;
+    slots.reserve(ptxt.size());
+    for (const auto& slot : ptxt.getSlotRepr()) {
+      slots.push_back(static_cast<long>(slot));
+    }
+    return slots;
+  }
+
+  /**
+   * Encrypts a Ptxt<BGV> object into a new Ctxt object.
+   *
+   * @param public_key The PubKey to encrypt with.
+   * @param ptxt The Ptxt<BGV> object, for the same Context as the key.
+   * @return A unique pointer to the newly created Ctxt object.
+   */
+  std::unique_ptr<::helib::Ctxt> encrypt_ptxt(const ::helib::PubKey& public_key, const ::helib::PtxtBGV& ptxt) {
+    auto ctxt = std::make_unique<::helib::Ctxt>(public_key);
+    public_key.Encrypt(*ctxt, ptxt);
+    return ctxt;
+  }
+
+  /**
+   * Decrypts a Ctxt object into a new Ptxt<BGV> object.
+   *
+   * @param secret_key The SecKey to decrypt with.
+   * @param ctxt The Ctxt object to decrypt.
+   * @return A unique pointer to the newly created Ptxt<BGV> object.
+   */
+  std::unique_ptr<::helib::PtxtBGV> decrypt_ptxt(const ::helib::SecKey& secret_key, const ::helib::Ctxt& ctxt) {
+    auto ptxt = std::make_unique<::helib::PtxtBGV>(ctxt.getContext());
+    secret_key.Decrypt(*ptxt, ctxt);
+    return ptxt;
+  }
+
+  /**
+   * Creates a PtxtArray object with every slot set to zero.
+   *
+   * @param context The Context, which must outlive the plaintext.
+   * @return A unique pointer to the newly created PtxtArray object.
+   */
+  std::unique_ptr<::helib::PtxtArray> new_ptxt_array(const ::helib::Context& context) {
+    return std::make_unique<::helib::PtxtArray>(context);
+  }
+
+  /**
+   * Copies a PtxtArray object.
+   *
+   * @param ptxt The PtxtArray object to copy.
+   * @return A unique pointer to the copy.
+   */
+  std::unique_ptr<::helib::PtxtArray> ptxt_array_clone(const ::helib::PtxtArray& ptxt) {
+    return std::make_unique<::helib::PtxtArray>(ptxt);
+  }
+
+  /**
+   * Loads one real number per slot into a PtxtArray.
+   *
+   * @param ptxt The PtxtArray object to modify.
+   * @param values The slot values, at most `ptxt.size()` of them; the rest are zero.
+   */
+  void ptxt_array_load_f64(::helib::PtxtArray& ptxt, const rust::Vec<double>& values) {
+    if (values.size() > static_cast<size_t>(ptxt.size())) {
+      throw ::helib::OutOfRangeError("More values than slots");
+    }
+    ptxt.load(std::vector<double>(values.begin(), values.end()));
+  }
+
+  /**
+   * Loads one complex number per slot into a PtxtArray.
+   *
+   * @param ptxt The PtxtArray object to modify.
+   * @param parts The real and imaginary parts of the slot values, interleaved.
+   */
+  void ptxt_array_load_complex(::helib::PtxtArray& ptxt, const rust::Vec<double>& parts) {
+    if (parts.size() / 2 > static_cast<size_t>(ptxt.size())) {
+      throw ::helib::OutOfRangeError("More values than slots");
+    }
+    std::vector<::helib::cx_double> values;
+    values.reserve(parts.size() / 2);
+    for (size_t i = 0; i + 1 < parts.size(); i += 2) {
+      values.emplace_back(parts[i], parts[i + 1]);
+    }
+    ptxt.load(values);
+  }
+
+  /**
+   * Stores the slots of a PtxtArray as real numbers, discarding the imaginary parts.
+   *
+   * @param ptxt The PtxtArray object.
+   * @return The `ptxt.size()` slot values.
+   */
+  rust::Vec<double> ptxt_array_store_f64(const ::helib::PtxtArray& ptxt) {
+    std::vector<double> values;
+    ptxt.store(values);
+    rust::Vec<double> result;
+    result.reserve(values.size());
+    for (double value : values) {
+      result.push_back(value);
+    }
+    return result;
+  }
+
+  /**
+   * Stores the slots of a PtxtArray as complex numbers.
+   *
+   * @param ptxt The PtxtArray object.
+   * @return The real and imaginary parts of the `ptxt.size()` slot values, interleaved.
+   */
+  rust::Vec<double> ptxt_array_store_complex(const ::helib::PtxtArray& ptxt) {
+    std::vector<::helib::cx_double> values;
+    ptxt.store(values);
+    rust::Vec<double> result;
+    result.reserve(2 * values.size());
+    for (const auto& value : values) {
+      result.push_back(value.real());
+      result.push_back(value.imag());
+    }
+    return result;
+  }
+
+  /**
+   * Encrypts a PtxtArray with a public key.
+   *
+   * @param public_key The PubKey to encrypt with.
+   * @param ptxt The PtxtArray to encrypt.
+   * @return A unique pointer to the ciphertext.
+   */
+  std::unique_ptr<::helib::Ctxt> encrypt_ptxt_array(const ::helib::PubKey& public_key, const ::helib::PtxtArray& ptxt) {
+    auto ctxt = std::make_unique<::helib::Ctxt>(public_key);
+    ptxt.encrypt(*ctxt);
+    return ctxt;
+  }
+
+  /**
+   * Decrypts a ciphertext into a PtxtArray.
+   *
+   * @param secret_key The SecKey to decrypt with.
+   * @param ctxt The Ctxt to decrypt.
+   * @return A unique pointer to the plaintext.
+   */
+  std::unique_ptr<::helib::PtxtArray> decrypt_ptxt_array(const ::helib::SecKey& secret_key, const ::helib::Ctxt& ctxt) {
+    auto ptxt = std::make_unique<::helib::PtxtArray>(ctxt.getContext());
+    ptxt->decrypt(ctxt, secret_key);
+    return ptxt;
+  }
+
+  /**
+   * Adds a real constant to every slot of `ctxt`.
+   *
+   * @param ctxt The Ctxt object to modify.
+   * @param constant The constant.
+   */
+  void add_constant_f64(::helib::Ctxt& ctxt, double constant) {
+    ctxt += constant;
+  }
+
+  /**
+   * Multiplies every slot of `ctxt` by a real constant.
+   *
+   * @param ctxt The Ctxt object to modify.
+   * @param constant The constant.
+   */
+  void mult_by_constant_f64(::helib::Ctxt& ctxt, double constant) {
+    ctxt *= constant;
+  }
+
+  /**
+   * Adds a PtxtArray to `ctxt`, slot by slot.
+   *
+   * @param ctxt The Ctxt object to modify.
+   * @param ptxt The PtxtArray to add.
+   */
+  void add_ptxt_array(::helib::Ctxt& ctxt, const ::helib::PtxtArray& ptxt) {
+    ctxt += ptxt;
+  }
+
+  /**
+   * Subtracts a PtxtArray from `ctxt`, slot by slot.
+   *
+   * @param ctxt The Ctxt object to modify.
+   * @param ptxt The PtxtArray to subtract.
+   */
+  void sub_ptxt_array(::helib::Ctxt& ctxt, const ::helib::PtxtArray& ptxt) {
+    ctxt -= ptxt;
+  }
+
+  /**
+   * Multiplies `ctxt` by a PtxtArray, slot by slot.
+   *
+   * @param ctxt The Ctxt object to modify.
+   * @param ptxt The PtxtArray to multiply by.
+   */
+  void mult_by_ptxt_array(::helib::Ctxt& ctxt, const ::helib::PtxtArray& ptxt) {
+    ctxt *= ptxt;
+  }
+
+  // The binary circuits take their inputs as `const CtPtrs&`, but
+  // `CtPtrs_vectorCt` only wraps a non-const `std::vector<Ctxt>&`. The
+  // inputs below are wrapped through `const_cast`; they are only read.
+
+  /**
+   * Encrypts one number per slot, bit-sliced into `bit_size` Ctxt objects.
+   *
+   * @param public_key The PubKey to encrypt with, for a Context with p = 2.
+   * @param ea The EncryptedArray of the Context.
+   * @param nums The numbers, at most `ea.size()` of them; the rest of the slots are zero.
+   * @param bit_size The number of bits to encrypt of every number.
+   * @return A unique pointer to the `bit_size` Ctxt objects, least significant bit first.
+   */
+  std::unique_ptr<::helib::CtxtVector> encrypt_binary_nums(const ::helib::PubKey& public_key, const ::helib::EncryptedArray& ea, const rust::Vec<int64_t>& nums, size_t bit_size) {
+    if (nums.size() > static_cast<size_t>(ea.size())) {
+      throw ::helib::OutOfRangeError("More numbers than slots");
+    }
+    auto bits = std::make_unique<::helib::CtxtVector>(bit_size, ::helib::Ctxt(public_key));
+    std::vector<long> slots(ea.size(), 0);
+    for (size_t i = 0; i < bit_size; ++i) {
+      for (size_t j = 0; j < nums.size(); ++j) {
+        slots[j] = (nums[j] >> i) & 1;
+      }
+      ea.encrypt((*bits)[i], public_key, slots);
+    }
+    return bits;
+  }
+
+  /**
+   * Decrypts bit-sliced numbers, one per slot.
+   *
+   * @param bits The Ctxt objects, least significant bit first.
+   * @param secret_key The SecKey to decrypt with.
+   * @param ea The EncryptedArray of the Context.
+   * @param twos_complement Whether the numbers are signed, in 2's complement.
+   * @return The `ea.size()` numbers.
+   */
+  rust::Vec<int64_t> decrypt_binary_nums(const ::helib::CtxtVector& bits, const ::helib::SecKey& secret_key, const ::helib::EncryptedArray& ea, bool twos_complement) {
+    std::vector<long> nums;
+    ::helib::decryptBinaryNums(nums, ::helib::CtPtrs_vectorCt(const_cast<::helib::CtxtVector&>(bits)), secret_key, ea, twos_complement);
+    rust::Vec<int64_t> result;
+    result.reserve(nums.size());
+    for (long num : nums) {
+      result.push_back(num);
+    }
+    return result;
+  }
+
+  /**
+   * Copies bit-sliced numbers.
+   *
+   * @param bits The Ctxt objects to copy.
+   * @return A unique pointer to the copies.
+   */
+  std::unique_ptr<::helib::CtxtVector> ctxt_vector_clone(const ::helib::CtxtVector& bits) {
+    return std::make_unique<::helib::CtxtVector>(bits);
+  }
+
+  /**
+   * Adds two bit-sliced numbers.
+   *
+   * @param lhs The left hand side.
+   * @param rhs The right hand side.
+   * @param size_limit The number of bits of the sum to compute, 0 for all of them.
+   * @return A unique pointer to the sum, one bit longer than the longer operand unless limited.
+   */
+  std::unique_ptr<::helib::CtxtVector> add_two_numbers(const ::helib::CtxtVector& lhs, const ::helib::CtxtVector& rhs, int64_t size_limit) {
+    auto sum = std::make_unique<::helib::CtxtVector>();
+    ::helib::CtPtrs_vectorCt sum_wrapper(*sum);
+    ::helib::addTwoNumbers(sum_wrapper,
+                           ::helib::CtPtrs_vectorCt(const_cast<::helib::CtxtVector&>(lhs)),
+                           ::helib::CtPtrs_vectorCt(const_cast<::helib::CtxtVector&>(rhs)),
+                           static_cast<long>(size_limit));
+    return sum;
+  }
+
+  /**
+   * Subtracts `rhs` from `lhs`, both in 2's complement.
+   *
+   * @param lhs The left hand side.
+   * @param rhs The right hand side.
+   * @return A unique pointer to the difference, in 2's complement.
+   */
+  std::unique_ptr<::helib::CtxtVector> subtract_binary(const ::helib::CtxtVector& lhs, const ::helib::CtxtVector& rhs) {
+    auto difference = std::make_unique<::helib::CtxtVector>();
+    ::helib::CtPtrs_vectorCt difference_wrapper(*difference);
+    ::helib::subtractBinary(difference_wrapper,
+                            ::helib::CtPtrs_vectorCt(const_cast<::helib::CtxtVector&>(lhs)),
+                            ::helib::CtPtrs_vectorCt(const_cast<::helib::CtxtVector&>(rhs)));
+    return difference;
+  }
+
+  /**
+   * Multiplies two bit-sliced numbers.
+   *
+   * @param lhs The left hand side, unsigned.
+   * @param rhs The right hand side.
+   * @param rhs_twos_complement Whether `rhs` is signed, in 2's complement.
+   * @param size_limit The number of bits of the product to compute, 0 for all of them.
+   * @return A unique pointer to the product.
+   */
+  std::unique_ptr<::helib::CtxtVector> mult_two_numbers(const ::helib::CtxtVector& lhs, const ::helib::CtxtVector& rhs, bool rhs_twos_complement, int64_t size_limit) {
+    auto product = std::make_unique<::helib::CtxtVector>();
+    ::helib::CtPtrs_vectorCt product_wrapper(*product);
+    ::helib::multTwoNumbers(product_wrapper,
+                            ::helib::CtPtrs_vectorCt(const_cast<::helib::CtxtVector&>(lhs)),
+                            ::helib::CtPtrs_vectorCt(const_cast<::helib::CtxtVector&>(rhs)),
+                            rhs_twos_complement,
+                            static_cast<long>(size_limit));
+    return product;
+  }
+
+  /**
+   * Compares two bit-sliced numbers, slot by slot.
+   *
+   * @param mu Set to an encryption of 1 where `a > b`, 0 elsewhere.
+   * @param ni Set to an encryption of 1 where `a < b`, 0 elsewhere.
+   * @param a The first number.
+   * @param b The second number.
+   * @param twos_complement Whether the numbers are signed, in 2's complement.
+   */
+  void compare_two_numbers(::helib::Ctxt& mu, ::helib::Ctxt& ni, const ::helib::CtxtVector& a, const ::helib::CtxtVector& b, bool twos_complement) {
+    ::helib::compareTwoNumbers(mu,
+                               ni,
+                               ::helib::CtPtrs_vectorCt(const_cast<::helib::CtxtVector&>(a)),
+                               ::helib::CtPtrs_vectorCt(const_cast<::helib::CtxtVector&>(b)),
+                               twos_complement);
+  }
+
+
+  // Copies serialized bytes out of a stream.
+  static rust::Vec<uint8_t> to_byte_vec(const std::ostringstream& stream) {
+    const std::string str = stream.str();
+    rust::Vec<uint8_t> bytes;
+    bytes.reserve(str.size());
+    for (char c : str) {
+      bytes.push_back(static_cast<uint8_t>(c));
+    }
+    return bytes;
+  }
+
+  // Copies serialized bytes into a string, to read them with a `std::istringstream`.
+  static std::string byte_string(rust::Slice<const uint8_t> bytes) {
+    return std::string(reinterpret_cast<const char*>(bytes.data()), bytes.size());
+  }
+
+  // The binary readers of HElib do not check the stream, so running out of
+  // input is checked after them.
+  static void check_stream(const std::istream& stream) {
+    if (stream.fail()) {
+      throw ::helib::IOError("Unexpected end of input");
+    }
+  }
+
+  static void read_bytes(std::istream& stream, char* bytes, std::streamsize size) {
+    stream.read(bytes, size);
+    check_stream(stream);
+  }
+
+  // HElib's binary writers put integers in little-endian order, 4 or 8 bytes wide.
+  static long read_raw(std::istream& stream, int size) {
+    unsigned char bytes[8] = {0};
+    read_bytes(stream, reinterpret_cast<char*>(bytes), size);
+    uint64_t value = 0;
+    for (int i = size - 1; i >= 0; --i) {
+      value = (value << 8) | bytes[i];
+    }
+    return size == 4 ? static_cast<int32_t>(value) : static_cast<long>(value);
+  }
+
+  static void skip_raw(std::istream& stream, std::streamsize size) {
+    if (stream.ignore(size).gcount() != size) {
+      throw ::helib::IOError("Unexpected end of input");
+    }
+  }
+
+  // The binary format of HElib, from src/binio.h, which is not installed with
+  // its headers. An eye catcher is 4 bytes (binio.h:36-53).
+  static const std::streamsize EYE_CATCHER_SIZE = 4;
+  static const char HEADER_BEGIN[] = "|HE[";
+  static const char HEADER_END[] = "]HE|";
+  static const char PK_BEGIN[] = "|PK[";
+  static const char SK_BEGIN[] = "|SK[";
+  static const char CTXT_BEGIN[] = "|CX[";
+  static const char CTXT_END[] = "]CX|";
+  // `Binio::VERSION_0_0_1_0` (binio.h:28-34), the only version the readers accept.
+  static const char BINIO_VERSION[] = {0, 0, 1, 0};
+  // `nameToStructId` (binio.h:67-86).
+  static const char PUB_KEY_ID = 10;
+  static const char SEC_KEY_ID = 15;
+  static const char CTXT_ID = 20;
+  // `write_raw_int` (binio.cpp:75-87) writes a long in 8 bytes, and
+  // `write_raw_xdouble` (binio.cpp:165-171) an xdouble as a double mantissa
+  // and a long exponent, 8 bytes each.
+  static const int RAW_INT_SIZE = 8;
+  static const std::streamsize RAW_XDOUBLE_SIZE = 2 * 8;
+
+  /**
+   * Reads a `SerializeHeader` (binio.h:90-145), 24 bytes written as they lie
+   * in memory: the begin eye catcher (bytes 0-3), the format version (4-7),
+   * the version of HElib that wrote it (8-11), the struct id (12), 7 reserved
+   * bytes (13-19) and the end eye catcher (20-23).
+   *
+   * @param stream The stream to read from.
+   * @param struct_id The struct id of the object that should follow.
+   */
+  static void read_header(std::istream& stream, char struct_id) {
+    char header[24];
+    read_bytes(stream, header, sizeof(header));
+    if (std::memcmp(header, HEADER_BEGIN, EYE_CATCHER_SIZE) != 0 ||
+        std::memcmp(header + 20, HEADER_END, EYE_CATCHER_SIZE) != 0) {
+      throw ::helib::IOError("Eye catchers for header mismatch");
+    }
+    if (std::memcmp(header + 4, BINIO_VERSION, sizeof(BINIO_VERSION)) != 0) {
+      throw ::helib::IOError("Header: version not supported");
+    }
+    if (header[12] != struct_id) {
+      throw ::helib::IOError("Header: not the expected object");
+    }
+  }
+
+  // Reads an eye catcher, which HElib's `readEyeCatcher` (binio.cpp:19-25) compares.
+  static void read_eye_catcher(std::istream& stream, const char* expected) {
+    char eye[EYE_CATCHER_SIZE];
+    read_bytes(stream, eye, EYE_CATCHER_SIZE);
+    if (std::memcmp(eye, expected, EYE_CATCHER_SIZE) != 0) {
+      throw ::helib::IOError(std::string("Could not find the eye catcher ") + expected);
+    }
+  }
+
+  // Reads a serialized context, and compares it with `context`.
+  static bool read_context_is(std::istream& stream, const ::helib::Context& context) {
+    bool is = ::helib::Context::readFrom(stream) == context;
+    check_stream(stream);
+    return is;
+  }
+
+  // HElib's JSON documents are parsed by nlohmann::json, which is private to
+  // HElib. The checks below need a few members only, so they walk the
+  // document with these and keep the text of each value. Whatever they do
+  // not look at is left to HElib's readers.
+  static void skip_json_space(const std::string& json, size_t& pos) {
+    while (pos < json.size() && std::isspace(static_cast<unsigned char>(json[pos]))) {
+      ++pos;
+    }
+  }
+
+  static void expect_json(const std::string& json, size_t& pos, char c) {
+    skip_json_space(json, pos);
+    if (pos >= json.size() || json[pos] != c) {
+      throw ::helib::IOError(std::string("Malformed JSON: expected '") + c + "'");
+    }
+    ++pos;
+  }
+
+  // Returns the position after the JSON string that starts at `pos`.
+  static size_t json_string_end(const std::string& json, size_t pos) {
+    for (++pos; pos < json.size(); ++pos) {
+      if (json[pos] == '\\') {
+        ++pos;
+      } else if (json[pos] == '"') {
+        return pos + 1;
+      }
+    }
+    throw ::helib::IOError("Malformed JSON: unterminated string");
+  }
+
+  // Returns the text of the JSON value at `pos`, leaving `pos` after it.
+  static std::string json_value(const std::string& json, size_t& pos) {
+    skip_json_space(json, pos);
+    const size_t begin = pos;
+    if (pos < json.size() && json[pos] == '"') {
+      pos = json_string_end(json, pos);
+    } else if (pos < json.size() && (json[pos] == '{' || json[pos] == '[')) {
+      long depth = 0;
+      do {
+        if (pos >= json.size()) {
+          throw ::helib::IOError("Malformed JSON: unexpected end of input");
+        }
+        if (json[pos] == '"') {
+          pos = json_string_end(json, pos);
+          continue;
+        }
+        if (json[pos] == '{' || json[pos] == '[') {
+          ++depth;
+        } else if (json[pos] == '}' || json[pos] == ']') {
+          --depth;
+        }
+        ++pos;
+      } while (depth > 0);
+    } else {
+      while (pos < json.size() && std::string_view(",:]} \t\r\n").find(json[pos]) == std::string_view::npos) {
+        ++pos;
+      }
+    }
+    if (pos == begin) {
+      throw ::helib::IOError("Malformed JSON: expected a value");
+    }
+    return json.substr(begin, pos - begin);
+  }
+
+  // Returns the text of the member `key` of a JSON object.
+  static std::string json_member(const std::string& object, const std::string& key) {
+    const std::string name = "\"" + key + "\"";
+    size_t pos = 0;
+    expect_json(object, pos, '{');
+    skip_json_space(object, pos);
+    if (pos < object.size() && object[pos] == '}') {
+      throw ::helib::IOError("Malformed JSON: missing member " + key);
+    }
+    while (true) {
+      const std::string member = json_value(object, pos);
+      expect_json(object, pos, ':');
+      std::string value = json_value(object, pos);
+      if (member == name) {
+        return value;
+      }
+      skip_json_space(object, pos);
+      if (pos >= object.size() || object[pos] != ',') {
+        throw ::helib::IOError("Malformed JSON: missing member " + key);
+      }
+      ++pos;
+    }
+  }
+
+  // Returns the texts of the elements of a JSON array.
+  static std::vector<std::string> json_elements(const std::string& array) {
+    std::vector<std::string> elements;
+    size_t pos = 0;
+    expect_json(array, pos, '[');
+    skip_json_space(array, pos);
+    if (pos < array.size() && array[pos] == ']') {
+      return elements;
+    }
+    while (true) {
+      elements.push_back(json_value(array, pos));
+      skip_json_space(array, pos);
+      if (pos < array.size() && array[pos] == ']') {
+        return elements;
+      }
+      expect_json(array, pos, ',');
+    }
+  }
+
+  static long json_long(const std::string& number) {
+    try {
+      size_t end = 0;
+      long value = std::stol(number, &end);
+      if (end == number.size()) {
+        return value;
+      }
+    } catch (const std::logic_error&) {
+      // std::invalid_argument or std::out_of_range, reported below.
+    }
+    throw ::helib::IOError("Malformed JSON: " + number + " is not an integer");
+  }
+
+  // Reads the "context" member of a JSON document, and compares it with `context`.
+  static bool json_context_is(const std::string& json, const ::helib::Context& context) {
+    std::istringstream stream(json_member(json, "context"));
+    return ::helib::Context::readFromJSON(stream) == context;
+  }
+
+  static bool ptxt_space_divides(long ptxt_space, const ::helib::PubKey& public_key) {
+    return ptxt_space >= 1 && public_key.getPtxtSpace() % ptxt_space == 0;
+  }
+
+  // Adds an index of a prime set, if it is a prime of `context`. The indices
+  // are checked one by one, as an `IndexSet` is sized by its largest index.
+  static bool add_prime(long i, const ::helib::Context& context, ::helib::IndexSet& prime_set) {
+    if (i < 0 || i >= context.numPrimes() || !context.allPrimes().contains(i)) {
+      return false;
+    }
+    prime_set.insert(i);
+    return true;
+  }
+
+  // Reads a binary `IndexSet` (IndexSet.cpp:288-297): its size, then each
+  // index, as raw ints.
+  static bool read_prime_set(std::istream& stream, const ::helib::Context& context, ::helib::IndexSet& prime_set) {
+    long size = read_raw(stream, RAW_INT_SIZE);
+    for (long n = 0; n < size; ++n) {
+      if (!add_prime(read_raw(stream, RAW_INT_SIZE), context, prime_set)) {
+        return false;
+      }
+    }
+    return true;
+  }
+
+  // Reads a JSON `IndexSet`, an array of the indices.
+  static bool json_prime_set(const std::string& json, const ::helib::Context& context, ::helib::IndexSet& prime_set) {
+    for (const std::string& i : json_elements(json)) {
+      if (!add_prime(json_long(i), context, prime_set)) {
+        return false;
+      }
+    }
+    return true;
+  }
+
+  /**
+   * Checks whether a ciphertext written by `ctxt_to_bytes` belongs to the
+   * context of `public_key`, before `Ctxt::readFrom` builds it.
+   *
+   * The binary reader of HElib takes the prime set, the rows and the secret
+   * key of each part as they come, and sizes its buffers by them. Here every
+   * part must hold a row of `phi(m)` residues below its prime for each prime
+   * of the prime set, and belong to a secret key of `public_key`.
+   *
+   * The layout is that of `Ctxt::writeTo` (Ctxt.cpp:2584-2605): the header
+   * and the CTXT_BEGIN eye catcher, ptxtSpace and intFactor as raw ints,
+   * ptxtMag, ratFactor and noiseBound as xdoubles, the prime set, the parts
+   * (`write_raw_vector`, binio.h:173-181: their number as a raw int, then
+   * each part), and the CTXT_END eye catcher.
+   *
+   * @param stream The stream to read from, left after the ciphertext.
+   * @param public_key The public key the ciphertext was encrypted with.
+   * @return Whether the ciphertext belongs to the context.
+   */
+  static bool ctxt_stream_for_key(std::istream& stream, const ::helib::PubKey& public_key) {
+    const ::helib::Context& context = public_key.getContext();
+    read_header(stream, CTXT_ID);
+    read_eye_catcher(stream, CTXT_BEGIN);
+    if (!ptxt_space_divides(read_raw(stream, RAW_INT_SIZE), public_key)) {
+      return false;
+    }
+    // intFactor, then ptxtMag, ratFactor and noiseBound.
+    skip_raw(stream, RAW_INT_SIZE + 3 * RAW_XDOUBLE_SIZE);
+    ::helib::IndexSet prime_set;
+    if (!read_prime_set(stream, context, prime_set)) {
+      return false;
+    }
+
+    long parts = read_raw(stream, RAW_INT_SIZE);
+    for (long part = 0; part < parts; ++part) {
+      // A part is a DoubleCRT (`DoubleCRT::writeTo`, DoubleCRT.cpp:1530-1540):
+      // its index set, then a row for each index.
+      ::helib::IndexSet part_set;
+      if (!read_prime_set(stream, context, part_set) || part_set != prime_set) {
+        return false;
+      }
+      for (long i : prime_set) {
+        // A row (`write_ntl_vec_long`, binio.cpp:103-122): its length and the
+        // width of its residues as 4-byte ints, then the residues.
+        long size = read_raw(stream, 4);
+        long int_size = read_raw(stream, 4);
+        if (int_size != 4 && int_size != 8) {
+          throw ::helib::IOError("intSize must be 32 or 64 bit for binary IO");
+        }
+        if (size != context.getPhiM()) {
+          return false;
+        }
+        long prime = context.ithPrime(i);
+        for (long j = 0; j < size; ++j) {
+          long residue = read_raw(stream, int_size);
+          if (residue < 0 || residue >= prime) {
+            return false;
+          }
+        }
+      }
+      // Then its SKHandle (`SKHandle::writeTo`, Ctxt.cpp:49-54): powerOfS,
+      // powerOfX and secretKeyID as raw ints.
+      skip_raw(stream, 2 * RAW_INT_SIZE);
+      if (!public_key.keyExists(read_raw(stream, RAW_INT_SIZE))) {
+        return false;
+      }
+    }
+    read_eye_catcher(stream, CTXT_END);
+    return true;
+  }
+
+  /**
+   * Checks whether a public key written by `pub_key_to_bytes` was generated
+   * for `context`, by the context written in front of it (keys.cpp:888-903, `PubKey::writeTo`).
+   *
+   * @param context The context.
+   * @param bytes The serialized bytes.
+   * @return Whether the public key belongs to the context.
+   */
+  bool pub_key_bytes_for_context(const ::helib::Context& context, rust::Slice<const uint8_t> bytes) {
+    std::istringstream stream(byte_string(bytes));
+    read_header(stream, PUB_KEY_ID);
+    read_eye_catcher(stream, PK_BEGIN);
+    return read_context_is(stream, context);
+  }
+
+  /**
+   * Checks whether a secret key written by `sec_key_to_bytes` was generated
+   * for `context`. It is written in front of its public key (keys.cpp:1736-1743, `SecKey::writeTo`).
+   *
+   * @param context The context.
+   * @param bytes The serialized bytes.
+   * @return Whether the secret key belongs to the context.
+   */
+  bool sec_key_bytes_for_context(const ::helib::Context& context, rust::Slice<const uint8_t> bytes) {
+    std::istringstream stream(byte_string(bytes));
+    read_header(stream, SEC_KEY_ID);
+    read_eye_catcher(stream, SK_BEGIN);
+    read_header(stream, PUB_KEY_ID);
+    read_eye_catcher(stream, PK_BEGIN);
+    return read_context_is(stream, context);
+  }
+
+  /**
+   * Checks whether a public key written by `pub_key_to_json` was generated
+   * for `context`, by its "content.context" member.
+   *
+   * @param context The context.
+   * @param json The JSON document.
+   * @return Whether the public key belongs to the context.
+   */
+  bool pub_key_json_for_context(const ::helib::Context& context, rust::Str json) {
+    return json_context_is(json_member(std::string(json), "content"), context);
+  }
+
+  /**
+   * Checks whether a secret key written by `sec_key_to_json` was generated
+   * for `context`, by its "content.PubKey.content.context" member.
+   *
+   * @param context The context.
+   * @param json The JSON document.
+   * @return Whether the secret key belongs to the context.
+   */
+  bool sec_key_json_for_context(const ::helib::Context& context, rust::Str json) {
+    const std::string public_key = json_member(json_member(std::string(json), "content"), "PubKey");
+    return json_context_is(json_member(public_key, "content"), context);
+  }
+
+  /**
+   * Checks whether a ciphertext written by `ctxt_to_bytes` belongs to the
+   * context of `public_key`, see `ctxt_stream_for_key`.
+   *
+   * @param public_key The public key the ciphertext was encrypted with.
+   * @param bytes The serialized bytes.
+   * @return Whether the ciphertext belongs to the context.
+   */
+  bool ctxt_bytes_for_key(const ::helib::PubKey& public_key, rust::Slice<const uint8_t> bytes) {
+    std::istringstream stream(byte_string(bytes));
+    return ctxt_stream_for_key(stream, public_key);
+  }
+
+  /**
+   * Checks whether a ciphertext written by `ctxt_to_json` belongs to the
+   * context of `public_key`, like `ctxt_bytes_for_key`. `Ctxt::readJSON`
+   * asserts on parts of another context, so they are checked before.
+   *
+   * @param public_key The public key the ciphertext was encrypted with.
+   * @param json The JSON document.
+   * @return Whether the ciphertext belongs to the context.
+   */
+  bool ctxt_json_for_key(const ::helib::PubKey& public_key, rust::Str json) {
+    const ::helib::Context& context = public_key.getContext();
+    const std::string content = json_member(std::string(json), "content");
+    if (!ptxt_space_divides(json_long(json_member(content, "ptxtSpace")), public_key)) {
+      return false;
+    }
+    ::helib::IndexSet prime_set;
+    if (!json_prime_set(json_member(content, "primeSet"), context, prime_set)) {
+      return false;
+    }
+
+    for (const std::string& part : json_elements(json_member(content, "parts"))) {
+      const std::string rows = json_member(part, "DoubleCRT");
+      ::helib::IndexSet part_set;
+      if (!json_prime_set(json_member(rows, "set"), context, part_set) || part_set != prime_set) {
+        return false;
+      }
+      const std::vector<std::string> map = json_elements(json_member(rows, "map"));
+      if (map.size() != static_cast<size_t>(prime_set.card())) {
+        throw ::helib::IOError("Malformed JSON: a part does not hold a row for each prime");
+      }
+      size_t row = 0;
+      for (long i : prime_set) {
+        const std::vector<std::string> residues = json_elements(map[row++]);
+        if (static_cast<long>(residues.size()) != context.getPhiM()) {
+          return false;
+        }
+        long prime = context.ithPrime(i);
+        for (const std::string& residue : residues) {
+          long value = json_long(residue);
+          if (value < 0 || value >= prime) {
+            return false;
+          }
+        }
+      }
+      if (!public_key.keyExists(json_long(json_member(json_member(part, "skHandle"), "secretKeyID")))) {
+        return false;
+      }
+    }
+    return true;
+  }
+
+  /**
+   * Serializes a Context with `writeTo`.
+   *
+   * @param context The context.
+   * @return The serialized bytes.
+   */
+  rust::Vec<uint8_t> context_to_bytes(const ::helib::Context& context) {
+    std::ostringstream stream;
+    context.writeTo(stream);
+    return to_byte_vec(stream);
+  }
+
+  /**
+   * Deserializes a Context written by `context_to_bytes`.
+   *
+   * @param bytes The serialized bytes.
+   * @return A unique pointer to the context.
+   */
+  std::unique_ptr<::helib::Context> context_from_bytes(rust::Slice<const uint8_t> bytes) {
+    std::istringstream stream(byte_string(bytes));
+    return std::unique_ptr<::helib::Context>(::helib::Context::readPtrFrom(stream));
+  }
+
+  /**
+   * Serializes a Context with `writeToJSON`.
+   *
+   * @param context The context.
+   * @return The JSON document.
+   */
+  rust::String context_to_json(const ::helib::Context& context) {
+    std::ostringstream stream;
+    context.writeToJSON(stream);
+    return rust::String(stream.str());
+  }
+
+  /**
+   * Deserializes a Context written by `context_to_json`.
+   *
+   * @param json The JSON document.
+   * @return A unique pointer to the context.
+   */
+  std::unique_ptr<::helib::Context> context_from_json(rust::Str json) {
+    std::istringstream stream{std::string(json)};
+    return std::unique_ptr<::helib::Context>(::helib::Context::readPtrFromJSON(stream));
+  }
+
+  /**
+   * Serializes a SecKey, with its public key and key-switching matrices, with `writeTo`.
+   *
+   * @param secret_key The secret key.
+   * @return The serialized bytes.
+   */
+  rust::Vec<uint8_t> sec_key_to_bytes(const ::helib::SecKey& secret_key) {
+    std::ostringstream stream;
+    secret_key.writeTo(stream);
+    return to_byte_vec(stream);
+  }
+
+  /**
+   * Deserializes a SecKey written by `sec_key_to_bytes`.
+   *
+   * @param context The context the key was generated for.
+   * @param bytes The serialized bytes.
+   * @return A unique pointer to the secret key.
+   */
+  std::unique_ptr<::helib::SecKey> sec_key_from_bytes(const ::helib::Context& context, rust::Slice<const uint8_t> bytes) {
+    std::istringstream stream(byte_string(bytes));
+    auto secret_key = std::make_unique<::helib::SecKey>(::helib::SecKey::readFrom(stream, context));
+    check_stream(stream);
+    return secret_key;
+  }
+
+  /**
+   * Serializes a SecKey, with its public key and key-switching matrices, with `writeToJSON`.
+   *
+   * @param secret_key The secret key.
+   * @return The JSON document.
+   */
+  rust::String sec_key_to_json(const ::helib::SecKey& secret_key) {
+    std::ostringstream stream;
+    secret_key.writeToJSON(stream);
+    return rust::String(stream.str());
+  }
+
+  /**
+   * Deserializes a SecKey written by `sec_key_to_json`.
+   *
+   * @param context The context the key was generated for.
+   * @param json The JSON document.
+   * @return A unique pointer to the secret key.
+   */
+  std::unique_ptr<::helib::SecKey> sec_key_from_json(const ::helib::Context& context, rust::Str json) {
+    std::istringstream stream{std::string(json)};
+    return std::make_unique<::helib::SecKey>(::helib::SecKey::readFromJSON(stream, context));
+  }
+
+  /**
+   * Serializes a PubKey, with its key-switching matrices, with `writeTo`.
+   *
+   * @param public_key The public key.
+   * @return The serialized bytes.
+   */
+  rust::Vec<uint8_t> pub_key_to_bytes(const ::helib::PubKey& public_key) {
+    std::ostringstream stream;
+    public_key.writeTo(stream);
+    return to_byte_vec(stream);
+  }
+
+  /**
+   * Deserializes a PubKey written by `pub_key_to_bytes`.
+   *
+   * @param context The context the key was generated for.
+   * @param bytes The serialized bytes.
+   * @return A unique pointer to the public key.
+   */
+  std::unique_ptr<::helib::PubKey> pub_key_from_bytes(const ::helib::Context& context, rust::Slice<const uint8_t> bytes) {
+    std::istringstream stream(byte_string(bytes));
+    auto public_key = std::make_unique<::helib::PubKey>(::helib::PubKey::readFrom(stream, context));
+    check_stream(stream);
+    return public_key;
+  }
+
+  /**
+   * Serializes a PubKey, with its key-switching matrices, with `writeToJSON`.
+   *
+   * @param public_key The public key.
+   * @return The JSON document.
+   */
+  rust::String pub_key_to_json(const ::helib::PubKey& public_key) {
+    std::ostringstream stream;
+    public_key.writeToJSON(stream);
+    return rust::String(stream.str());
+  }
+
+  /**
+   * Deserializes a PubKey written by `pub_key_to_json`.
+   *
+   * @param context The context the key was generated for.
+   * @param json The JSON document.
+   * @return A unique pointer to the public key.
+   */
+  std::unique_ptr<::helib::PubKey> pub_key_from_json(const ::helib::Context& context, rust::Str json) {
+    std::istringstream stream{std::string(json)};
+    return std::make_unique<::helib::PubKey>(::helib::PubKey::readFromJSON(stream, context));
+  }
+
+  /**
+   * Serializes a Ctxt with `writeTo`.
+   *
+   * @param ctxt The ciphertext.
+   * @return The serialized bytes.
+   */
+  rust::Vec<uint8_t> ctxt_to_bytes(const ::helib::Ctxt& ctxt) {
+    std::ostringstream stream;
+    ctxt.writeTo(stream);
+    return to_byte_vec(stream);
+  }
+
+  /**
+   * Deserializes a Ctxt written by `ctxt_to_bytes`.
+   *
+   * The bytes are checked with `ctxt_stream_for_key` first, as the binary
+   * reader of HElib does not look at the parts. Callers that tell a
+   * ciphertext of another context from malformed input run
+   * `ctxt_bytes_for_key` before.
+   *
+   * @param public_key The public key the ciphertext was encrypted with.
+   * @param bytes The serialized bytes.
+   * @return A unique pointer to the ciphertext.
+   */
+  std::unique_ptr<::helib::Ctxt> ctxt_from_bytes(const ::helib::PubKey& public_key, rust::Slice<const uint8_t> bytes) {
+    std::istringstream stream(byte_string(bytes));
+    if (!ctxt_stream_for_key(stream, public_key)) {
+      throw ::helib::LogicError("The ciphertext does not belong to the context");
+    }
+    stream.seekg(0);
+    auto ctxt = std::make_unique<::helib::Ctxt>(::helib::Ctxt::readFrom(stream, public_key));
+    check_stream(stream);
+    return ctxt;
+  }
+
+  /**
+   * Serializes a Ctxt with `writeToJSON`.
+   *
+   * @param ctxt The ciphertext.
+   * @return The JSON document.
+   */
+  rust::String ctxt_to_json(const ::helib::Ctxt& ctxt) {
+    std::ostringstream stream;
+    ctxt.writeToJSON(stream);
+    return rust::String(stream.str());
+  }
+
+  /**
+   * Deserializes a Ctxt written by `ctxt_to_json`, checked with
+   * `ctxt_json_for_key` first like `ctxt_from_bytes`.
+   *
+   * @param public_key The public key the ciphertext was encrypted with.
+   * @param json The JSON document.
+   * @return A unique pointer to the ciphertext.
+   */
+  std::unique_ptr<::helib::Ctxt> ctxt_from_json(const ::helib::PubKey& public_key, rust::Str json) {
+    if (!ctxt_json_for_key(public_key, json)) {
+      throw ::helib::LogicError("The ciphertext does not belong to the context");
+    }
+    std::istringstream stream{std::string(json)};
+    return std::make_unique<::helib::Ctxt>(::helib::Ctxt::readFromJSON(stream, public_key));
   }
 
 }  // namespace helib
@@ -5,6 +5,7 @@
 
 #include <memory>
 #include <optional>
+#include <sstream>
 #include <vector>
 #include <string>
 
@@ -12,6 +13,8 @@
 #include <helib/Context.h>
 #include <helib/EncryptedArray.h>  // For potential bootstrapping flags or other parameters
 #include <helib/apiAttributes.h>
+#include <helib/binaryArith.h>
+#include <helib/binaryCompare.h>
 #include <helib/version.h>
 
 #include <NTL/Lazy.h>
@@ -23,6 +26,18 @@ namespace helib {
     // Declare the type alias in the helib namespace after including the ContextBuilder definition.
     using BGVContextBuilder = ContextBuilder<BGV>;
 
+    // The CKKS builder is another instantiation of the template. It builds the
+    // same Context class, so keys and ciphertexts are shared with BGV.
+    using CKKSContextBuilder = ContextBuilder<CKKS>;
+
+    // cxx cannot name a class template, so `Ptxt<BGV>` is bridged under this alias.
+    using PtxtBGV = Ptxt<BGV>;
+
+    // A bit-sliced integer: element `i` encrypts bit `i` of a number in every
+    // slot, least significant bit first. The binary circuits see it through
+    // a `CtPtrs_vectorCt`.
+    using CtxtVector = std::vector<Ctxt>;
+
     rust::String version();
 
     // Declare the type alias in the helib namespace after including the Context definition.
@@ -40,18 +55,137 @@ namespace helib {
     std::unique_ptr<::helib::BGVContextBuilder> set_m(std::unique_ptr<::helib::BGVContextBuilder> builder, uint32_t m);
     std::unique_ptr<::helib::BGVContextBuilder> set_p(std::unique_ptr<::helib::BGVContextBuilder> builder, uint32_t p);
     std::unique_ptr<::helib::BGVContextBuilder> set_r(std::unique_ptr<::helib::BGVContextBuilder> builder, uint32_t r);
+    std::unique_ptr<::helib::BGVContextBuilder> set_sk_hwt(std::unique_ptr<::helib::BGVContextBuilder> builder, uint32_t sk_hwt);
+    std::unique_ptr<::helib::BGVContextBuilder> set_resolution(std::unique_ptr<::helib::BGVContextBuilder> builder, uint32_t bits);
+    std::unique_ptr<::helib::BGVContextBuilder> set_bits_in_special_primes(std::unique_ptr<::helib::BGVContextBuilder> builder, uint32_t bits);
 
-    std::unique_ptr<::helib::BGVContextBuilder> set_gens(std::unique_ptr<::helib::BGVContextBuilder> builder, const rust::cxxbridge1::Vec<int32_t>& gens);
-    std::unique_ptr<::helib::BGVContextBuilder> set_ords(std::unique_ptr<::helib::BGVContextBuilder> builder, const rust::cxxbridge1::Vec<int32_t>& ords);
-    std::unique_ptr<::helib::BGVContextBuilder> set_mvec(std::unique_ptr<::helib::BGVContextBuilder> builder, const rust::cxxbridge1::Vec<uint32_t>& mvec);
+    std::unique_ptr<::helib::BGVContextBuilder> set_gens(std::unique_ptr<::helib::BGVContextBuilder> builder, const rust::Vec<int64_t>& gens);
+    std::unique_ptr<::helib::BGVContextBuilder> set_ords(std::unique_ptr<::helib::BGVContextBuilder> builder, const rust::Vec<int64_t>& ords);
+    std::unique_ptr<::helib::BGVContextBuilder> set_mvec(std::unique_ptr<::helib::BGVContextBuilder> builder, const rust::Vec<int64_t>& mvec);
 
     std::unique_ptr<::helib::BGVContextBuilder> is_bootstrappable(std::unique_ptr<::helib::BGVContextBuilder> builder, bool flag);
     std::unique_ptr<::helib::BGVContextBuilder> set_thickboot(std::unique_ptr<::helib::BGVContextBuilder> builder);
     std::unique_ptr<::helib::BGVContextBuilder> set_thinboot(std::unique_ptr<::helib::BGVContextBuilder> builder);
 
-    // Declare the multiplyBy function
-    void multiplyBy(std::unique_ptr<::helib::Ctxt>& ciphertext, std::unique_ptr<::helib::Ctxt>& other);
-
+    // The CKKS builder has no `p` or `r`: `precision` sets the bits of
+    // precision of the slots instead.
+    std::unique_ptr<::helib::CKKSContextBuilder> init_ckks();
+    std::unique_ptr<::helib::Context> build_ckks(std::unique_ptr<::helib::CKKSContextBuilder> builder);
+    std::unique_ptr<::helib::CKKSContextBuilder> ckks_set_m(std::unique_ptr<::helib::CKKSContextBuilder> builder, uint32_t m);
+    std::unique_ptr<::helib::CKKSContextBuilder> ckks_set_bits(std::unique_ptr<::helib::CKKSContextBuilder> builder, uint32_t bits);
+    std::unique_ptr<::helib::CKKSContextBuilder> ckks_set_precision(std::unique_ptr<::helib::CKKSContextBuilder> builder, uint32_t precision);
+    std::unique_ptr<::helib::CKKSContextBuilder> ckks_set_c(std::unique_ptr<::helib::CKKSContextBuilder> builder, uint32_t c);
+
+    // Key generation. SecKey and PubKey keep a reference to the Context they were created for.
+    std::unique_ptr<::helib::SecKey> new_sec_key(const ::helib::Context& context);
+    std::unique_ptr<::helib::PubKey> public_key(const ::helib::SecKey& secret_key);
+    std::unique_ptr<::helib::Ctxt> new_ctxt(const ::helib::PubKey& public_key);
+    std::unique_ptr<::helib::Ctxt> encrypt_constant(const ::helib::PubKey& public_key, int64_t constant);
+    int64_t decrypt_constant(const ::helib::SecKey& secret_key, const ::helib::Ctxt& ctxt);
+
+    // Bootstrapping. The Context must have been built bootstrappable, so that
+    // the modulus chain leaves room for recryption.
+    void enable_bootstrapping(::helib::Context& context, const rust::Vec<int64_t>& mvec, bool build_cache, bool also_thick);
+
+    // Parameters of a built Context that the Context does not return as a
+    // single value.
+    rust::Vec<int64_t> context_gens(const ::helib::Context& context);
+    rust::Vec<int64_t> context_ords(const ::helib::Context& context);
+    rust::Vec<int64_t> context_mvec(const ::helib::Context& context);
+    int64_t context_c(const ::helib::Context& context);
+    int64_t context_bits(const ::helib::Context& context);
+    int64_t context_chain_length(const ::helib::Context& context);
+
+    // Ctxt arithmetic. The overloaded operators and constants are wrapped here,
+    // the remaining Ctxt member functions are bound directly in the bridge.
+    std::unique_ptr<::helib::Ctxt> ctxt_clone(const ::helib::Ctxt& ctxt);
+    void add_assign(::helib::Ctxt& ctxt, const ::helib::Ctxt& other);
+    void sub_assign(::helib::Ctxt& ctxt, const ::helib::Ctxt& other);
+    void mul_assign(::helib::Ctxt& ctxt, const ::helib::Ctxt& other);
+    void mult_by_constant(::helib::Ctxt& ctxt, int64_t constant);
+    void add_constant(::helib::Ctxt& ctxt, int64_t constant);
+
+    // Packed slots. cxx cannot bind `Context::shareEA` (see ISSUES.md), so the
+    // EncryptedArray is reached through a reference instead of a shared_ptr.
+    const ::helib::EncryptedArray& get_ea(const ::helib::Context& context);
+    std::unique_ptr<NTL::ZZX> ea_encode(const ::helib::EncryptedArray& ea, const rust::Vec<int64_t>& slots);
+    rust::Vec<int64_t> ea_decode(const ::helib::EncryptedArray& ea, const NTL::ZZX& poly);
+
+
+    // Rotations and sums over the slots. These need the key-switching matrices
+    // added by `addSome1DMatrices`, and return false, leaving the ciphertext
+    // unchanged, when one is missing.
+    bool ea_rotate(const ::helib::EncryptedArray& ea, ::helib::Ctxt& ctxt, int64_t k);
+    bool ea_shift(const ::helib::EncryptedArray& ea, ::helib::Ctxt& ctxt, int64_t k);
+    bool ea_rotate_1d(const ::helib::EncryptedArray& ea, ::helib::Ctxt& ctxt, int64_t i, int64_t k);
+    bool ea_shift_1d(const ::helib::EncryptedArray& ea, ::helib::Ctxt& ctxt, int64_t i, int64_t k);
+    bool total_sums(const ::helib::EncryptedArray& ea, ::helib::Ctxt& ctxt);
+    bool running_sums(const ::helib::EncryptedArray& ea, ::helib::Ctxt& ctxt);
+
+    std::unique_ptr<::helib::PtxtBGV> new_ptxt(const ::helib::Context& context);
+    std::unique_ptr<::helib::PtxtBGV> ptxt_from_slots(const ::helib::Context& context, const rust::Vec<int64_t>& slots);
+    std::unique_ptr<::helib::PtxtBGV> ptxt_clone(const ::helib::PtxtBGV& ptxt);
+    int64_t ptxt_get(const ::helib::PtxtBGV& ptxt, size_t index);
+    void ptxt_set(::helib::PtxtBGV& ptxt, size_t index, int64_t value);
+    rust::Vec<int64_t> ptxt_to_vec(const ::helib::PtxtBGV& ptxt);
+    std::unique_ptr<::helib::Ctxt> encrypt_ptxt(const ::helib::PubKey& public_key, const ::helib::PtxtBGV& ptxt);
+    std::unique_ptr<::helib::PtxtBGV> decrypt_ptxt(const ::helib::SecKey& secret_key, const ::helib::Ctxt& ctxt);
+
+    // CKKS plaintexts. A PtxtArray holds one real or complex number per slot;
+    // complex numbers cross the bridge as interleaved (re, im) pairs.
+    std::unique_ptr<::helib::PtxtArray> new_ptxt_array(const ::helib::Context& context);
+    std::unique_ptr<::helib::PtxtArray> ptxt_array_clone(const ::helib::PtxtArray& ptxt);
+    void ptxt_array_load_f64(::helib::PtxtArray& ptxt, const rust::Vec<double>& values);
+    void ptxt_array_load_complex(::helib::PtxtArray& ptxt, const rust::Vec<double>& parts);
+    rust::Vec<double> ptxt_array_store_f64(const ::helib::PtxtArray& ptxt);
+    rust::Vec<double> ptxt_array_store_complex(const ::helib::PtxtArray& ptxt);
+    std::unique_ptr<::helib::Ctxt> encrypt_ptxt_array(const ::helib::PubKey& public_key, const ::helib::PtxtArray& ptxt);
+    std::unique_ptr<::helib::PtxtArray> decrypt_ptxt_array(const ::helib::SecKey& secret_key, const ::helib::Ctxt& ctxt);
+
+    // Ctxt arithmetic that CKKS adds: real constants and plaintext arrays.
+    void add_constant_f64(::helib::Ctxt& ctxt, double constant);
+    void mult_by_constant_f64(::helib::Ctxt& ctxt, double constant);
+    void add_ptxt_array(::helib::Ctxt& ctxt, const ::helib::PtxtArray& ptxt);
+    void sub_ptxt_array(::helib::Ctxt& ctxt, const ::helib::PtxtArray& ptxt);
+    void mult_by_ptxt_array(::helib::Ctxt& ctxt, const ::helib::PtxtArray& ptxt);
+
+
+    // Binary arithmetic and comparison on bit-sliced integers, from
+    // binaryArith.h and binaryCompare.h. No bootstrapping is done in between,
+    // so the context needs enough capacity for the whole circuit.
+    std::unique_ptr<::helib::CtxtVector> encrypt_binary_nums(const ::helib::PubKey& public_key, const ::helib::EncryptedArray& ea, const rust::Vec<int64_t>& nums, size_t bit_size);
+    rust::Vec<int64_t> decrypt_binary_nums(const ::helib::CtxtVector& bits, const ::helib::SecKey& secret_key, const ::helib::EncryptedArray& ea, bool twos_complement);
+    std::unique_ptr<::helib::CtxtVector> ctxt_vector_clone(const ::helib::CtxtVector& bits);
+    std::unique_ptr<::helib::CtxtVector> add_two_numbers(const ::helib::CtxtVector& lhs, const ::helib::CtxtVector& rhs, int64_t size_limit);
+    std::unique_ptr<::helib::CtxtVector> subtract_binary(const ::helib::CtxtVector& lhs, const ::helib::CtxtVector& rhs);
+    std::unique_ptr<::helib::CtxtVector> mult_two_numbers(const ::helib::CtxtVector& lhs, const ::helib::CtxtVector& rhs, bool rhs_twos_complement, int64_t size_limit);
+    void compare_two_numbers(::helib::Ctxt& mu, ::helib::Ctxt& ni, const ::helib::CtxtVector& a, const ::helib::CtxtVector& b, bool twos_complement);
+    // Binary (`writeTo`/`readFrom`) and JSON (`writeToJSON`/`readFromJSON`)
+    // serialization. Keys and ciphertexts are read against a context or public
+    // key. The `*_for_context` and `*_for_key` checks return whether they
+    // belong to it, and throw only for malformed input.
+    rust::Vec<uint8_t> context_to_bytes(const ::helib::Context& context);
+    std::unique_ptr<::helib::Context> context_from_bytes(rust::Slice<const uint8_t> bytes);
+    rust::String context_to_json(const ::helib::Context& context);
+    std::unique_ptr<::helib::Context> context_from_json(rust::Str json);
+    rust::Vec<uint8_t> sec_key_to_bytes(const ::helib::SecKey& secret_key);
+    std::unique_ptr<::helib::SecKey> sec_key_from_bytes(const ::helib::Context& context, rust::Slice<const uint8_t> bytes);
+    rust::String sec_key_to_json(const ::helib::SecKey& secret_key);
+    std::unique_ptr<::helib::SecKey> sec_key_from_json(const ::helib::Context& context, rust::Str json);
+    rust::Vec<uint8_t> pub_key_to_bytes(const ::helib::PubKey& public_key);
+    std::unique_ptr<::helib::PubKey> pub_key_from_bytes(const ::helib::Context& context, rust::Slice<const uint8_t> bytes);
+    rust::String pub_key_to_json(const ::helib::PubKey& public_key);
+    std::unique_ptr<::helib::PubKey> pub_key_from_json(const ::helib::Context& context, rust::Str json);
+    rust::Vec<uint8_t> ctxt_to_bytes(const ::helib::Ctxt& ctxt);
+    std::unique_ptr<::helib::Ctxt> ctxt_from_bytes(const ::helib::PubKey& public_key, rust::Slice<const uint8_t> bytes);
+    rust::String ctxt_to_json(const ::helib::Ctxt& ctxt);
+    std::unique_ptr<::helib::Ctxt> ctxt_from_json(const ::helib::PubKey& public_key, rust::Str json);
+    bool sec_key_bytes_for_context(const ::helib::Context& context, rust::Slice<const uint8_t> bytes);
+    bool sec_key_json_for_context(const ::helib::Context& context, rust::Str json);
+    bool pub_key_bytes_for_context(const ::helib::Context& context, rust::Slice<const uint8_t> bytes);
+    bool pub_key_json_for_context(const ::helib::Context& context, rust::Str json);
+    bool ctxt_bytes_for_key(const ::helib::PubKey& public_key, rust::Slice<const uint8_t> bytes);
+    bool ctxt_json_for_key(const ::helib::PubKey& public_key, rust::Str json);
 
     enum class MErrorKind {
         None,
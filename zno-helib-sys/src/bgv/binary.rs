@@ -0,0 +1,115 @@
+use crate::prelude::*;
+use super::*;
+
+/// Define the Rust struct to represent a C++ std::vector<Ctxt> seen through CtPtrs_vectorCt
+///
+/// A `BinaryNumber` is a bit-sliced integer: bit `i` of the number in every
+/// slot is encrypted in ciphertext `i`, least significant bit first. The
+/// circuits of HElib's `binaryArith.h` and `binaryCompare.h` work on it, so
+/// the context needs `p = 2`.
+///
+/// Nothing is bootstrapped between gates, so the context needs enough
+/// capacity for the whole circuit.
+///
+/// # Example
+///
+/// ```no_run
+/// # use zno_helib_sys::prelude::*;
+/// # let context = Builder::new().build().expect("BGV context creation");
+/// # let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+/// # secret_key.gen_sec_key().expect("secret key generation");
+/// # secret_key.add_some_1d_matrices().expect("key-switching matrices");
+/// # let public_key = secret_key.public_key().expect("PubKey");
+/// let ea = context.ea();
+/// let a = BinaryNumber::encrypt(&public_key, &ea, &[3], 4).expect("encryption");
+/// let b = BinaryNumber::encrypt(&public_key, &ea, &[5], 4).expect("encryption");
+/// let sum = a.add(&b, None).expect("addition");
+/// assert_eq!(sum.decrypt(&secret_key, &ea, false).unwrap()[0], 8);
+/// ```
+pub struct BinaryNumber<'a> {
+    // This holds a pointer to the C++ object.
+    pub(crate) inner: cxx::UniquePtr<crate::helib::bgv::ffi::CtxtVector>,
+    public_key: &'a PubKey<'a>,
+}
+
+impl<'a> BinaryNumber<'a> {
+    /// Encrypts one number per slot, keeping its `bit_size` least significant bits.
+    /// Slots past the end of `nums` hold zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::CppException` if there are more numbers than slots.
+    pub fn encrypt(public_key: &'a PubKey<'a>, ea: &EncryptedArray, nums: &[i64], bit_size: usize) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::encrypt_binary_nums(&public_key.inner, ea.inner, &nums.to_vec(), bit_size)?)?;
+        Ok(BinaryNumber { inner, public_key })
+    }
+
+    fn wrap(&self, inner: cxx::UniquePtr<crate::helib::bgv::ffi::CtxtVector>) -> Result<Self, FFIError> {
+        Ok(BinaryNumber { inner: non_null(inner)?, public_key: self.public_key })
+    }
+
+    /// Decrypts the number in every slot of the context.
+    ///
+    /// With `twos_complement`, the numbers are read as signed.
+    pub fn decrypt(&self, secret_key: &SecKey, ea: &EncryptedArray, twos_complement: bool) -> Result<Vec<i64>, FFIError> {
+        Ok(ffi::decrypt_binary_nums(&self.inner, &secret_key.inner, ea.inner, twos_complement)?)
+    }
+
+    /// Returns the number of bits.
+    pub fn len(&self) -> usize {
+        self.inner.size()
+    }
+
+    /// Returns true if there are no bits.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds `other`, with `addTwoNumbers`.
+    ///
+    /// The sum is one bit longer than the longer operand, or `size_limit` bits long.
+    pub fn add(&self, other: &BinaryNumber<'_>, size_limit: Option<usize>) -> Result<Self, FFIError> {
+        let limit = size_limit.unwrap_or(0) as i64;
+        self.wrap(ffi::add_two_numbers(&self.inner, &other.inner, limit)?)
+    }
+
+    /// Subtracts `other`, with `subtractBinary`. Both operands and the difference are in 2's complement.
+    pub fn subtract(&self, other: &BinaryNumber<'_>) -> Result<Self, FFIError> {
+        self.wrap(ffi::subtract_binary(&self.inner, &other.inner)?)
+    }
+
+    /// Multiplies by `other`, with `multTwoNumbers`.
+    ///
+    /// `self` is unsigned; with `other_twos_complement`, `other` is read as
+    /// signed. The product is as long as the two operands together, or
+    /// `size_limit` bits long.
+    pub fn multiply(&self, other: &BinaryNumber<'_>, other_twos_complement: bool, size_limit: Option<usize>) -> Result<Self, FFIError> {
+        let limit = size_limit.unwrap_or(0) as i64;
+        self.wrap(ffi::mult_two_numbers(&self.inner, &other.inner, other_twos_complement, limit)?)
+    }
+
+    /// Compares with `other` slot by slot, with `compareTwoNumbers`.
+    ///
+    /// Returns `(greater, less)`: encryptions of 1 in the slots where `self`
+    /// is greater than, respectively less than, `other`, and 0 elsewhere.
+    pub fn compare(&self, other: &BinaryNumber<'_>, twos_complement: bool) -> Result<(Ctxt<'a>, Ctxt<'a>), FFIError> {
+        let mut greater = Ctxt::new(self.public_key)?;
+        let mut less = Ctxt::new(self.public_key)?;
+        ffi::compare_two_numbers(greater.inner.pin_mut(), less.inner.pin_mut(), &self.inner, &other.inner, twos_complement)?;
+        Ok((greater, less))
+    }
+}
+
+impl Clone for BinaryNumber<'_> {
+    fn clone(&self) -> Self {
+        let inner = ffi::ctxt_vector_clone(&self.inner).expect("BinaryNumber copy");
+        BinaryNumber { inner, public_key: self.public_key }
+    }
+}
+
+// Implement Display for printing, debugging, etc.
+impl core::fmt::Display for BinaryNumber<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "BinaryNumber(bits={})", self.len())
+    }
+}
@@ -0,0 +1,112 @@
+use crate::prelude::*;
+use super::*;
+
+/// Define the Rust struct to represent the C++ ContextBuilder<CKKS> class
+///
+/// Builds a `Context` for the CKKS scheme, which computes approximately on
+/// real or complex numbers. It replaces `p` and `r` of the BGV `Builder` by
+/// `precision`, the bits of precision of the slots; `m` must be a power of two
+/// and gives `m / 4` slots.
+///
+/// The built `Context` is the same type as for BGV, so keys, `Ctxt`,
+/// rotations and capacity queries work unchanged. Use `PtxtArray` for the
+/// plaintexts.
+///
+/// # Example
+///
+/// ```no_run
+/// # use zno_helib_sys::prelude::*;
+/// let context = CKKSBuilder::new()
+///     .set_m(16 * 1024)
+///     .and_then(|builder| builder.set_bits(119))
+///     .and_then(|builder| builder.set_precision(20))
+///     .and_then(|builder| builder.set_c(2))
+///     .and_then(CKKSBuilder::build)
+///     .expect("CKKS context creation");
+/// assert_eq!(context.ea().size(), 4096);
+/// ```
+pub struct CKKSBuilder {
+    // Holds a pointer to the C++ object
+    pub inner: cxx::UniquePtr<crate::helib::bgv::ffi::CKKSContextBuilder>,
+}
+
+impl CKKSBuilder {
+    // Constructs a new CKKSBuilder with HElib's defaults
+    pub fn new() -> Self {
+        Self { inner: ffi::init_ckks() }
+    }
+
+    /// Sets the cyclotomic index `m`, a power of two.
+    pub fn set_m(mut self, m: u32) -> Result<Self, FFIError> {
+        self.inner = ffi::ckks_set_m(self.inner, m)?;
+        Ok(self)
+    }
+
+    /// Sets the number of bits of the modulus chain, which bounds the depth of the circuits.
+    pub fn set_bits(mut self, bits: u32) -> Result<Self, FFIError> {
+        self.inner = ffi::ckks_set_bits(self.inner, bits)?;
+        Ok(self)
+    }
+
+    /// Sets the bits of precision of the slots.
+    pub fn set_precision(mut self, precision: u32) -> Result<Self, FFIError> {
+        self.inner = ffi::ckks_set_precision(self.inner, precision)?;
+        Ok(self)
+    }
+
+    /// Sets the number of columns of the key-switching matrices.
+    pub fn set_c(mut self, c: u32) -> Result<Self, FFIError> {
+        self.inner = ffi::ckks_set_c(self.inner, c)?;
+        Ok(self)
+    }
+
+    /// Builds the `Context`, consuming the builder.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::CppException` if HElib rejects the parameters,
+    /// for example when `m` is not a power of two.
+    pub fn build(self) -> Result<Context, FFIError> {
+        let inner = non_null(ffi::build_ckks(self.inner)?)?;
+        Ok(Context { inner })
+    }
+}
+
+impl Default for CKKSBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Implement Display for printing, debugging, etc.
+impl core::fmt::Display for CKKSBuilder {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CKKSBuilder") // How this type name should appear
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_ckks_context() {
+        let context = CKKSBuilder::new()
+            .set_m(16 * 1024)
+            .and_then(|builder| builder.set_bits(119))
+            .and_then(|builder| builder.set_precision(20))
+            .and_then(|builder| builder.set_c(2))
+            .and_then(CKKSBuilder::build)
+            .expect("CKKS context creation");
+        assert_eq!(context.inner.getM(), 16 * 1024);
+        assert_eq!(context.ea().size(), 4 * 1024);
+    }
+
+    #[test]
+    fn test_m_not_power_of_two() {
+        let result = CKKSBuilder::new()
+            .set_m(4095)
+            .and_then(CKKSBuilder::build);
+        assert!(matches!(result, Err(FFIError::CppException(_))));
+    }
+}
@@ -1,6 +1,3 @@
-use std::convert::TryFrom;
-use std::convert::TryInto;
-
 use crate::prelude::*;
 use super::*;
 
@@ -11,6 +8,98 @@ pub struct Context {
     pub(crate) inner: cxx::UniquePtr<crate::helib::bgv::ffi::Context>,
 }
 
+impl Context {
+    /// Returns the `EncryptedArray` that maps plaintext slots to polynomials for this context.
+    pub fn ea(&self) -> EncryptedArray<'_> {
+        EncryptedArray { inner: ffi::get_ea(&self.inner) }
+    }
+
+    /// Initialises the data that recryption (bootstrapping) needs.
+    ///
+    /// `mvec` factors `m` into coprime factors, e.g. `[7, 5, 9, 13]` for
+    /// `m = 4095`. Thin bootstrapping is always enabled; `also_thick` enables
+    /// thick bootstrapping too. Call this before any keys are generated, then
+    /// `SecKey::gen_recrypt_data`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::CppException` if the context was not built
+    /// bootstrappable, or if the product of `mvec` is not `m`.
+    pub fn enable_bootstrapping(&mut self, mvec: &[i64], also_thick: bool) -> Result<(), FFIError> {
+        ffi::enable_bootstrapping(self.inner.pin_mut(), &mvec.to_vec(), false, also_thick)?;
+        Ok(())
+    }
+
+    /// Returns true if the recryption data has been initialised.
+    pub fn is_bootstrappable(&self) -> bool {
+        self.inner.is_bootstrappable()
+    }
+
+    /// Returns HElib's estimate of the security level, in bits.
+    pub fn security_level(&self) -> f64 {
+        self.inner.security_level()
+    }
+
+    /// Returns the cyclotomic order `m`.
+    pub fn m(&self) -> i64 {
+        self.inner.getM()
+    }
+
+    /// Returns the plaintext prime `p`, or `-1` for a CKKS context.
+    pub fn p(&self) -> i64 {
+        self.inner.get_p()
+    }
+
+    /// Returns the Hensel lifting `r`, so that the plaintext space is `p^r`.
+    /// For a CKKS context, this is the precision in bits.
+    pub fn r(&self) -> i64 {
+        self.inner.get_r()
+    }
+
+    /// Returns `phi(m)`, the degree of the cyclotomic polynomial.
+    pub fn phi_m(&self) -> i64 {
+        self.inner.get_phi_m()
+    }
+
+    /// Returns the number of plaintext slots, `EncryptedArray::size`.
+    pub fn slots(&self) -> i64 {
+        self.inner.get_n_slots()
+    }
+
+    /// Returns the number of columns of the key-switching matrices. It is
+    /// the `c` the builder was given, unless the modulus chain has fewer primes.
+    pub fn c(&self) -> i64 {
+        ffi::context_c(&self.inner)
+    }
+
+    /// Returns the size of the modulus chain in bits, at least the `bits` the builder was given.
+    pub fn bits(&self) -> i64 {
+        ffi::context_bits(&self.inner)
+    }
+
+    /// Returns the number of primes in the modulus chain.
+    pub fn modulus_chain_length(&self) -> i64 {
+        ffi::context_chain_length(&self.inner)
+    }
+
+    /// Returns the generators of `(Z/mZ)^* / (p)`, one per dimension of the hypercube.
+    pub fn gens(&self) -> Vec<i64> {
+        ffi::context_gens(&self.inner)
+    }
+
+    /// Returns the orders of the generators. Unlike the builder, which takes
+    /// a negative order for a "bad" dimension, every order is positive.
+    pub fn ords(&self) -> Vec<i64> {
+        ffi::context_ords(&self.inner)
+    }
+
+    /// Returns the factorization of `m` passed to `enable_bootstrapping`,
+    /// empty until then.
+    pub fn mvec(&self) -> Vec<i64> {
+        ffi::context_mvec(&self.inner)
+    }
+}
+
 // Implement Display for printing, debugging, etc.
 impl core::fmt::Display for Context {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
@@ -18,26 +107,12 @@ impl core::fmt::Display for Context {
     }
 }
 
-// impl Getters for Context {
-//     /// Get the `m` parameter from the `Context`.
-//     ///
-//     /// # Errors
-//     ///
-//     /// Returns an `MError` if `m` is zero or negative or if `m` is larger than `u32::MAX`.
-//     // fn get_m(&self) -> Result<M, MError> {
-//     //     // Call the C++ function through the FFI
-//     //     let m_value = self.inner.getM();
-
-//     //     // Convert the C++ result to Rust M enum
-//     //     M::try_from(m_value)
-//     // }
-
-//     // Similarly, implement other getters...
-// }
+// The typed `Getters` over these accessors live in `zno_fhe::helib::getters`.
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::bgv::testing::{setup_bgv_builder, setup_bgv_context};
 
     #[test]
     fn test_build_with_valid_builder() {
@@ -46,6 +121,47 @@ mod tests {
         assert!(context.is_ok());
     }
 
+    #[test]
+    fn test_enable_bootstrapping_needs_bootstrappable_context() {
+        let mut context = Builder::new().build().expect("BGV context creation");
+        assert!(!context.is_bootstrappable());
+        let m = context.inner.getM();
+        assert!(matches!(context.enable_bootstrapping(&[m], true), Err(FFIError::CppException(_))));
+        assert!(!context.is_bootstrappable());
+    }
+
+    #[test]
+    fn test_modulus_chain_settings() {
+        let context = setup_bgv_builder(4999, 300)
+            .set_sk_hwt(120)
+            .and_then(|builder| builder.set_resolution(3))
+            .and_then(|builder| builder.set_bits_in_special_primes(60))
+            .and_then(Builder::build)
+            .expect("BGV context creation");
+        assert!(context.security_level() > 0.0);
+    }
+
+    #[test]
+    fn test_parameters() {
+        let context = setup_bgv_context(300);
+
+        assert_eq!(context.m(), 4095);
+        assert_eq!(context.p(), 4999);
+        assert_eq!(context.r(), 1);
+        assert_eq!(context.phi_m(), 1728); // 4095 = 3^2 * 5 * 7 * 13
+        assert_eq!(context.slots(), context.ea().size() as i64);
+        assert_eq!(context.c(), 2);
+        assert!(context.bits() >= 300);
+        assert!(context.modulus_chain_length() > 0);
+
+        let gens = context.gens();
+        let ords = context.ords();
+        assert_eq!(gens.len(), context.ea().dimension());
+        assert_eq!(ords.len(), gens.len());
+        assert_eq!(ords.iter().product::<i64>(), context.slots());
+        assert!(context.mvec().is_empty());
+    }
+
     // #[ignore = "Incomplete HELib FFI"]
     // #[test]
     // fn test_bgv_context_new() {
@@ -1,39 +1,396 @@
+use core::marker::PhantomData;
+
+use crate::prelude::*;
+use super::*;
+
+/// Define the Rust struct to represent the C++ Ctxt class
+///
+/// A `Ctxt` is a BGV or CKKS ciphertext. Arithmetic is slot-wise: every slot of the
+/// result is computed from the same slot of the operands.
+///
+/// The methods return `FFIError::CppException` when HElib rejects the
+/// operands, for example when they are encrypted under different keys. The
+/// operator traits call the same methods and panic instead, like integer
+/// overflow in debug builds.
+///
+/// Multiplication (`*=`, `square`, `cube`, `power`) relinearizes its result,
+/// and every operation consumes some of the ciphertext's capacity.
+///
+/// HElib keeps a reference to the `PubKey` a ciphertext is encrypted under,
+/// so the `Ctxt` borrows it.
+pub struct Ctxt<'a> {
+    // This holds a pointer to the C++ object.
+    pub(crate) inner: cxx::UniquePtr<crate::helib::bgv::ffi::Ctxt>,
+    _public_key: PhantomData<&'a PubKey<'a>>,
+}
+
+impl<'a> Ctxt<'a> {
+    /// Creates an empty `Ctxt`, encrypted under `public_key`.
+    pub fn new(public_key: &'a PubKey<'_>) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::new_ctxt(&public_key.inner)?)?;
+        Ok(Ctxt::from_ptr(inner))
+    }
+
+    pub(crate) fn from_ptr(inner: cxx::UniquePtr<crate::helib::bgv::ffi::Ctxt>) -> Self {
+        Ctxt { inner, _public_key: PhantomData }
+    }
+
+    /// Adds `other` to `self`, i.e. `self += other`.
+    pub fn add_ctxt(&mut self, other: &Ctxt<'_>) -> Result<(), FFIError> {
+        ffi::add_assign(self.inner.pin_mut(), &other.inner)?;
+        Ok(())
+    }
+
+    /// Subtracts `other` from `self`, i.e. `self -= other`.
+    pub fn sub_ctxt(&mut self, other: &Ctxt<'_>) -> Result<(), FFIError> {
+        ffi::sub_assign(self.inner.pin_mut(), &other.inner)?;
+        Ok(())
+    }
+
+    /// Multiplies `self` by `other` and relinearizes the result, i.e. `self *= other`.
+    pub fn multiply_by(&mut self, other: &Ctxt<'_>) -> Result<(), FFIError> {
+        ffi::mul_assign(self.inner.pin_mut(), &other.inner)?;
+        Ok(())
+    }
+
+    /// Multiplies every slot by `constant`.
+    pub fn mult_by_constant(&mut self, constant: i64) -> Result<(), FFIError> {
+        ffi::mult_by_constant(self.inner.pin_mut(), constant)?;
+        Ok(())
+    }
+
+    /// Adds `constant` to every slot.
+    pub fn add_constant(&mut self, constant: i64) -> Result<(), FFIError> {
+        ffi::add_constant(self.inner.pin_mut(), constant)?;
+        Ok(())
+    }
+
+    /// Multiplies every slot by the real `constant`, for CKKS.
+    pub fn mult_by_constant_f64(&mut self, constant: f64) -> Result<(), FFIError> {
+        ffi::mult_by_constant_f64(self.inner.pin_mut(), constant)?;
+        Ok(())
+    }
+
+    /// Adds the real `constant` to every slot, for CKKS.
+    pub fn add_constant_f64(&mut self, constant: f64) -> Result<(), FFIError> {
+        ffi::add_constant_f64(self.inner.pin_mut(), constant)?;
+        Ok(())
+    }
+
+    /// Adds `ptxt` slot by slot.
+    pub fn add_ptxt_array(&mut self, ptxt: &PtxtArray<'_>) -> Result<(), FFIError> {
+        ffi::add_ptxt_array(self.inner.pin_mut(), &ptxt.inner)?;
+        Ok(())
+    }
+
+    /// Subtracts `ptxt` slot by slot.
+    pub fn sub_ptxt_array(&mut self, ptxt: &PtxtArray<'_>) -> Result<(), FFIError> {
+        ffi::sub_ptxt_array(self.inner.pin_mut(), &ptxt.inner)?;
+        Ok(())
+    }
+
+    /// Multiplies by `ptxt` slot by slot.
+    pub fn mult_by_ptxt_array(&mut self, ptxt: &PtxtArray<'_>) -> Result<(), FFIError> {
+        ffi::mult_by_ptxt_array(self.inner.pin_mut(), &ptxt.inner)?;
+        Ok(())
+    }
+
+    /// Negates every slot.
+    pub fn negate(&mut self) -> Result<(), FFIError> {
+        self.inner.pin_mut().negate()?;
+        Ok(())
+    }
+
+    /// Squares every slot. Cheaper than multiplying the ciphertext by a copy of itself.
+    pub fn square(&mut self) -> Result<(), FFIError> {
+        self.inner.pin_mut().square()?;
+        Ok(())
+    }
+
+    /// Cubes every slot.
+    pub fn cube(&mut self) -> Result<(), FFIError> {
+        self.inner.pin_mut().cube()?;
+        Ok(())
+    }
+
+    /// Raises every slot to the power `e`, with about `log2(e)` multiplications.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::CppException` if `e` is less than 1.
+    pub fn power(&mut self, e: i64) -> Result<(), FFIError> {
+        self.inner.pin_mut().power(e)?;
+        Ok(())
+    }
+
+    /// Relinearizes the ciphertext back to two parts, with the key-switching matrices for `key_idx`.
+    ///
+    /// The operations above relinearize already; this is only needed after
+    /// lower-level operations that leave extra parts behind.
+    pub fn re_linearize(&mut self, key_idx: i64) -> Result<(), FFIError> {
+        self.inner.pin_mut().re_linearize(key_idx)?;
+        Ok(())
+    }
+
+    /// Returns false if the noise has grown too large for the ciphertext to decrypt correctly.
+    pub fn is_correct(&self) -> bool {
+        self.inner.is_correct()
+    }
+
+    /// Returns the plaintext space, `p^r`, the slots are computed modulo.
+    pub fn ptxt_space(&self) -> i64 {
+        self.inner.get_ptxt_space()
+    }
+
+    /// Returns the capacity, in bits: roughly `log2(q / noise)` for the
+    /// current modulus `q`. Each multiplication spends some of it, and the
+    /// ciphertext no longer decrypts correctly once it reaches zero.
+    pub fn capacity(&self) -> f64 {
+        self.inner.capacity()
+    }
+
+    /// Returns the capacity rounded down to whole bits.
+    pub fn bit_capacity(&self) -> i64 {
+        self.inner.bit_capacity()
+    }
+
+    /// Returns a bound on the absolute error of the slots for CKKS.
+    /// It is always 0 for BGV, where `capacity` tracks the noise instead.
+    pub fn error_bound(&self) -> f64 {
+        self.inner.error_bound()
+    }
+
+    /// Returns the natural logarithm of the current modulus, the product of
+    /// the primes the ciphertext is defined over.
+    pub fn log_of_prime_set(&self) -> f64 {
+        self.inner.log_of_prime_set()
+    }
+}
+
+impl Clone for Ctxt<'_> {
+    fn clone(&self) -> Self {
+        let inner = ffi::ctxt_clone(&self.inner).expect("Ctxt copy");
+        Ctxt::from_ptr(inner)
+    }
+}
+
+// Implement Display for printing, debugging, etc.
+impl core::fmt::Display for Ctxt<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Ctxt") // How this type name should appear
+    }
+}
+
 // Double the ciphertext (using addition)
 // Example 1:
-// ctxt += ctxt;
+// ctxt += ctxt.clone();
 // [0] [1] [1] ... [1] [1] -> [0] [2] [2] ... [2] [2]
 // Example 2:
-// ctxt += &ctxt;
+// ctxt += &ctxt.clone();
 // [0] [1] [1] ... [1] [1] -> [0] [2] [2] ... [2] [2]
 
-impl std::ops::AddAssign<Self> for Ctxt {
+impl std::ops::AddAssign<Self> for Ctxt<'_> {
     fn add_assign(&mut self, other: Self) {
-        // Implementation goes here
+        *self += &other;
+    }
+}
+
+impl std::ops::AddAssign<&Self> for Ctxt<'_> {
+    fn add_assign(&mut self, other: &Self) {
+        self.add_ctxt(other).expect("Ctxt addition");
+    }
+}
+
+impl std::ops::AddAssign<i64> for Ctxt<'_> {
+    fn add_assign(&mut self, constant: i64) {
+        self.add_constant(constant).expect("Ctxt constant addition");
+    }
+}
+
+impl std::ops::Add<Self> for Ctxt<'_> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self += &other;
+        self
+    }
+}
+
+impl<'a> std::ops::Add<&Ctxt<'a>> for &Ctxt<'a> {
+    type Output = Ctxt<'a>;
+
+    fn add(self, other: &Ctxt<'a>) -> Ctxt<'a> {
+        let mut sum = self.clone();
+        sum += other;
+        sum
+    }
+}
+
+impl std::ops::SubAssign<Self> for Ctxt<'_> {
+    fn sub_assign(&mut self, other: Self) {
+        *self -= &other;
+    }
+}
+
+impl std::ops::SubAssign<&Self> for Ctxt<'_> {
+    fn sub_assign(&mut self, other: &Self) {
+        self.sub_ctxt(other).expect("Ctxt subtraction");
+    }
+}
+
+impl std::ops::Sub<Self> for Ctxt<'_> {
+    type Output = Self;
+
+    fn sub(mut self, other: Self) -> Self {
+        self -= &other;
+        self
+    }
+}
+
+impl<'a> std::ops::Sub<&Ctxt<'a>> for &Ctxt<'a> {
+    type Output = Ctxt<'a>;
+
+    fn sub(self, other: &Ctxt<'a>) -> Ctxt<'a> {
+        let mut difference = self.clone();
+        difference -= other;
+        difference
     }
 }
 
 // Square the ciphertext
 // Example 1:
-// ctxt *= &ctxt;
+// ctxt *= &ctxt.clone();
 // [0] [1] [2] [3] [4] ... [nslots-1]
 // -> [0] [1] [4] [9] [16] ... [(nslots-1)*(nslots-1)]
 
-impl std::ops::MulAssign<&Self> for Ctxt {
+impl std::ops::MulAssign<&Self> for Ctxt<'_> {
     fn mul_assign(&mut self, other: &Self) {
-        // Implementation goes here
+        self.multiply_by(other).expect("Ctxt multiplication");
+    }
+}
+
+impl std::ops::MulAssign<Self> for Ctxt<'_> {
+    fn mul_assign(&mut self, other: Self) {
+        *self *= &other;
+    }
+}
+
+impl std::ops::MulAssign<i64> for Ctxt<'_> {
+    fn mul_assign(&mut self, constant: i64) {
+        self.mult_by_constant(constant).expect("Ctxt constant multiplication");
     }
 }
 
 // Square the ciphertext
 // Example 2:
-// let ctxt2 = ctxt * ctxt;
+// let ctxt2 = &ctxt * &ctxt;
 // [0] [1] [2] [3] [4] ... [nslots-1]
 // -> [0] [1] [4] [9] [16] ... [(nslots-1)*(nslots-1)]
 
-impl std::ops::Mul<Self> for Ctxt {
+impl std::ops::Mul<Self> for Ctxt<'_> {
     type Output = Self;
 
-    fn mul(self, other: Self) -> Self {
-        // Implementation goes here
+    fn mul(mut self, other: Self) -> Self {
+        self *= &other;
+        self
+    }
+}
+
+impl<'a> std::ops::Mul<&Ctxt<'a>> for &Ctxt<'a> {
+    type Output = Ctxt<'a>;
+
+    fn mul(self, other: &Ctxt<'a>) -> Ctxt<'a> {
+        let mut product = self.clone();
+        product *= other;
+        product
+    }
+}
+
+impl std::ops::Neg for Ctxt<'_> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self {
+        self.negate().expect("Ctxt negation");
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bgv::testing::setup_bgv_context;
+
+    #[test]
+    fn test_arithmetic() {
+        let context = setup_bgv_context(300);
+        let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+        secret_key.gen_sec_key().unwrap();
+        let public_key = secret_key.public_key().expect("PubKey");
+        let three = public_key.encrypt_constant(3).expect("Ctxt");
+        let four = public_key.encrypt_constant(4).expect("Ctxt");
+
+        assert_eq!(secret_key.decrypt_constant(&(&three + &four)).unwrap(), 7);
+        assert_eq!(secret_key.decrypt_constant(&(&four - &three)).unwrap(), 1);
+        assert_eq!(secret_key.decrypt_constant(&(&three * &four)).unwrap(), 12);
+        assert_eq!(secret_key.decrypt_constant(&-three.clone()).unwrap(), 4999 - 3);
+
+        let mut ctxt = three.clone();
+        ctxt *= 5;
+        ctxt += 1;
+        assert_eq!(secret_key.decrypt_constant(&ctxt).unwrap(), 16);
+    }
+
+    #[test]
+    fn test_square_cube_power() {
+        let context = setup_bgv_context(300);
+        let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+        secret_key.gen_sec_key().unwrap();
+        let public_key = secret_key.public_key().expect("PubKey");
+        let two = public_key.encrypt_constant(2).expect("Ctxt");
+
+        let mut ctxt = two.clone();
+        ctxt.square().unwrap();
+        assert_eq!(secret_key.decrypt_constant(&ctxt).unwrap(), 4);
+
+        let mut ctxt = two.clone();
+        ctxt.cube().unwrap();
+        assert_eq!(secret_key.decrypt_constant(&ctxt).unwrap(), 8);
+
+        let mut ctxt = two;
+        ctxt.power(5).unwrap();
+        ctxt.re_linearize(0).unwrap();
+        assert!(ctxt.is_correct());
+        assert_eq!(secret_key.decrypt_constant(&ctxt).unwrap(), 32);
+    }
+
+    #[test]
+    fn test_new_ctxt_is_empty() {
+        let context = setup_bgv_context(300);
+        let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+        secret_key.gen_sec_key().unwrap();
+        let public_key = secret_key.public_key().expect("PubKey");
+
+        let mut ctxt = Ctxt::new(&public_key).expect("Ctxt creation");
+        ctxt += &public_key.encrypt_constant(9).unwrap();
+        assert_eq!(secret_key.decrypt_constant(&ctxt).unwrap(), 9);
+    }
+
+    #[test]
+    fn test_capacity_shrinks_with_depth() {
+        let context = setup_bgv_context(300);
+        let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+        secret_key.gen_sec_key().unwrap();
+        let public_key = secret_key.public_key().expect("PubKey");
+
+        let mut ctxt = public_key.encrypt_constant(3).expect("Ctxt");
+        let fresh = ctxt.capacity();
+        assert!(fresh > 0.0);
+        assert_eq!(ctxt.bit_capacity(), fresh as i64);
+        assert!(ctxt.log_of_prime_set() / 2f64.ln() > fresh);
+        assert_eq!(ctxt.error_bound(), 0.0);
+
+        ctxt.square().unwrap();
+        assert!(ctxt.capacity() < fresh);
+        assert!(ctxt.is_correct());
     }
 }
@@ -0,0 +1,263 @@
+use crate::prelude::*;
+use super::*;
+
+/// Define the Rust struct to represent the C++ EncryptedArray class
+///
+/// The `EncryptedArray` of a `Context` maps a vector of `size()` slot values
+/// to the plaintext polynomial that holds them, and back. Get it from
+/// `Context::ea`.
+///
+/// The `Context` owns the C++ object, so this only borrows it. HElib shares
+/// it through a `std::shared_ptr<const EncryptedArray>`, which cxx cannot
+/// bind; see ISSUES.md.
+///
+/// # Example
+///
+/// ```no_run
+/// # use zno_helib_sys::prelude::*;
+/// # let context = Builder::new().build().expect("BGV context creation");
+/// let ea = context.ea();
+/// let poly = ea.encode(&[1, 2, 3]).expect("encoding");
+/// let slots = ea.decode(&poly).expect("decoding");
+/// assert_eq!(slots.len(), ea.size());
+/// assert_eq!(slots[..3], [1, 2, 3]);
+/// ```
+pub struct EncryptedArray<'a> {
+    pub(crate) inner: &'a crate::helib::bgv::ffi::EncryptedArray,
+}
+
+impl EncryptedArray<'_> {
+    /// Returns the number of slots.
+    pub fn size(&self) -> usize {
+        self.inner.size() as usize
+    }
+
+    /// Returns the number of dimensions of the hypercube the slots are arranged in.
+    pub fn dimension(&self) -> usize {
+        self.inner.dimension() as usize
+    }
+
+    /// Returns the number of slots along dimension `i`, or `None` if there is no such dimension.
+    pub fn size_of_dimension(&self, i: usize) -> Option<usize> {
+        if i >= self.dimension() {
+            return None;
+        }
+        Some(self.inner.size_of_dimension(i as i64) as usize)
+    }
+
+    /// Rotates the slots of `ctxt` right by `k`, as a linear array.
+    /// Slot `j` moves to slot `(j + k) mod size()`; a negative `k` rotates left.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::MissingKeySwitchMatrix`, leaving `ctxt` unchanged, if the
+    /// key-switching matrices of the public key of `ctxt` do not reach every rotation
+    /// along every dimension, see `SecKey::add_some_1d_matrices`. Rotating by 0 needs
+    /// no matrix.
+    pub fn rotate(&self, ctxt: &mut Ctxt, k: i64) -> Result<(), FFIError> {
+        reached(ffi::ea_rotate(self.inner, ctxt.inner.pin_mut(), k)?, "rotate")
+    }
+
+    /// Shifts the slots of `ctxt` right by `k`, as a linear array, filling with zeros.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::MissingKeySwitchMatrix`, like `rotate`.
+    pub fn shift(&self, ctxt: &mut Ctxt, k: i64) -> Result<(), FFIError> {
+        reached(ffi::ea_shift(self.inner, ctxt.inner.pin_mut(), k)?, "shift")
+    }
+
+    /// Rotates the slots of `ctxt` by `k` along dimension `i` of the hypercube only.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::MissingKeySwitchMatrix`, like `rotate`, and
+    /// `FFIError::CppException` if `i` is not less than `dimension()`.
+    pub fn rotate_1d(&self, ctxt: &mut Ctxt, i: usize, k: i64) -> Result<(), FFIError> {
+        reached(ffi::ea_rotate_1d(self.inner, ctxt.inner.pin_mut(), i as i64, k)?, "rotate_1d")
+    }
+
+    /// Shifts the slots of `ctxt` by `k` along dimension `i` of the hypercube only, filling with zeros.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `rotate_1d`.
+    pub fn shift_1d(&self, ctxt: &mut Ctxt, i: usize, k: i64) -> Result<(), FFIError> {
+        reached(ffi::ea_shift_1d(self.inner, ctxt.inner.pin_mut(), i as i64, k)?, "shift_1d")
+    }
+
+    /// Replaces every slot of `ctxt` by the sum of all slots, with `O(log size())` rotations.
+    ///
+    /// Together with a slot-wise product this is an inner product.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::MissingKeySwitchMatrix`, like `rotate`.
+    pub fn total_sums(&self, ctxt: &mut Ctxt) -> Result<(), FFIError> {
+        reached(ffi::total_sums(self.inner, ctxt.inner.pin_mut())?, "total_sums")
+    }
+
+    /// Replaces slot `j` of `ctxt` by the sum of slots `0..=j`, with `O(log size())` shifts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::MissingKeySwitchMatrix`, like `rotate`.
+    pub fn running_sums(&self, ctxt: &mut Ctxt) -> Result<(), FFIError> {
+        reached(ffi::running_sums(self.inner, ctxt.inner.pin_mut())?, "running_sums")
+    }
+
+    /// Encodes one value per slot into a plaintext polynomial.
+    ///
+    /// Slots past the end of `slots` are set to zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::CppException` if there are more values than slots.
+    pub fn encode(&self, slots: &[i64]) -> Result<Polynomial, FFIError> {
+        let inner = non_null(ffi::ea_encode(self.inner, &slots.to_vec())?)?;
+        Ok(Polynomial { inner })
+    }
+
+    /// Decodes a plaintext polynomial into its `size()` slot values, in `[0, p^r)`.
+    pub fn decode(&self, poly: &Polynomial) -> Result<Vec<i64>, FFIError> {
+        Ok(ffi::ea_decode(self.inner, &poly.inner)?)
+    }
+}
+
+// Implement Display for printing, debugging, etc.
+impl core::fmt::Display for EncryptedArray<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "EncryptedArray(slots={})", self.size())
+    }
+}
+
+/// Define the Rust struct to represent the C++ NTL::ZZX class
+///
+/// A plaintext polynomial, as `EncryptedArray::encode` returns it.
+pub struct Polynomial {
+    pub(crate) inner: cxx::UniquePtr<crate::helib::bgv::ffi::ZZX>,
+}
+
+// Implement Display for printing, debugging, etc.
+impl core::fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Polynomial") // How this type name should appear
+    }
+}
+
+// The shims check the key-switching matrices before touching `ctxt`, and
+// return `false` rather than throw: cxx would only carry the message over.
+fn reached(reached: bool, operation: &str) -> Result<(), FFIError> {
+    if reached {
+        Ok(())
+    } else {
+        Err(FFIError::MissingKeySwitchMatrix(operation.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bgv::testing::setup_bgv_context;
+
+    #[test]
+    fn test_encode_decode() {
+        let context = setup_bgv_context(300);
+        let ea = context.ea();
+        assert!(ea.size() > 1);
+
+        let poly = ea.encode(&[7, -1, 4999 + 2]).expect("encoding");
+        let slots = ea.decode(&poly).expect("decoding");
+        assert_eq!(slots.len(), ea.size());
+        assert_eq!(slots[..3], [7, 4999 - 1, 2]);
+        assert!(slots[3..].iter().all(|&slot| slot == 0));
+    }
+
+    #[test]
+    fn test_dimensions() {
+        let context = setup_bgv_context(300);
+        let ea = context.ea();
+        let sizes: Vec<usize> = (0..ea.dimension()).map(|i| ea.size_of_dimension(i).unwrap()).collect();
+        assert_eq!(sizes.iter().product::<usize>(), ea.size());
+        assert_eq!(ea.size_of_dimension(ea.dimension()), None);
+    }
+
+    #[test]
+    fn test_rotate_and_shift() {
+        let context = setup_bgv_context(300);
+        let ea = context.ea();
+        let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+        secret_key.gen_sec_key().unwrap();
+        secret_key.add_some_1d_matrices().unwrap();
+        let public_key = secret_key.public_key().expect("PubKey");
+
+        let n = ea.size();
+        let slots: Vec<i64> = (1..=n as i64).collect();
+        let ctxt = public_key.encrypt(&Ptxt::from_slots(&context, &slots).unwrap()).expect("Ctxt");
+
+        let mut rotated = ctxt.clone();
+        ea.rotate(&mut rotated, 1).unwrap();
+        let mut expected = slots.clone();
+        expected.rotate_right(1);
+        assert_eq!(secret_key.decrypt(&rotated).unwrap().to_vec(), expected);
+
+        let mut shifted = ctxt.clone();
+        ea.shift(&mut shifted, -1).unwrap();
+        let mut expected = slots[1..].to_vec();
+        expected.push(0);
+        assert_eq!(secret_key.decrypt(&shifted).unwrap().to_vec(), expected);
+
+        let mut rotated = ctxt;
+        ea.rotate_1d(&mut rotated, 0, ea.size_of_dimension(0).unwrap() as i64).unwrap();
+        assert_eq!(secret_key.decrypt(&rotated).unwrap().to_vec(), slots);
+    }
+
+    #[test]
+    fn test_total_and_running_sums() {
+        let context = setup_bgv_context(300);
+        let ea = context.ea();
+        let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+        secret_key.gen_sec_key().unwrap();
+        secret_key.add_some_1d_matrices().unwrap();
+        let public_key = secret_key.public_key().expect("PubKey");
+
+        let slots: Vec<i64> = (1..=ea.size() as i64).collect();
+        let ctxt = public_key.encrypt(&Ptxt::from_slots(&context, &slots).unwrap()).expect("Ctxt");
+
+        // The inner product of the slots with themselves.
+        let mut product = &ctxt * &ctxt;
+        ea.total_sums(&mut product).unwrap();
+        let sum: i64 = slots.iter().map(|slot| slot * slot).sum::<i64>() % 4999;
+        assert_eq!(secret_key.decrypt(&product).unwrap().to_vec(), vec![sum; ea.size()]);
+
+        let mut sums = ctxt;
+        ea.running_sums(&mut sums).unwrap();
+        let expected: Vec<i64> = slots.iter().scan(0, |acc, slot| { *acc += slot; Some(*acc % 4999) }).collect();
+        assert_eq!(secret_key.decrypt(&sums).unwrap().to_vec(), expected);
+    }
+
+    #[test]
+    fn test_rotate_without_matrices() {
+        let context = setup_bgv_context(300);
+        let ea = context.ea();
+        let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+        secret_key.gen_sec_key().unwrap();
+        let public_key = secret_key.public_key().expect("PubKey");
+
+        let mut ctxt = public_key.encrypt_constant(1).expect("Ctxt");
+        assert!(matches!(ea.rotate(&mut ctxt, 1), Err(FFIError::MissingKeySwitchMatrix(_))));
+        // Rotating or shifting by 0 leaves the ciphertext as it is.
+        ea.rotate(&mut ctxt, 0).expect("rotation by 0");
+        ea.shift(&mut ctxt, 0).expect("shift by 0");
+        assert!(matches!(ea.total_sums(&mut ctxt), Err(FFIError::MissingKeySwitchMatrix(_))));
+        assert_eq!(secret_key.decrypt(&ctxt).unwrap().to_vec(), vec![1; ea.size()]);
+    }
+
+    #[test]
+    fn test_encode_too_many_values() {
+        let context = setup_bgv_context(300);
+        let ea = context.ea();
+        let slots = vec![1; ea.size() + 1];
+        assert!(matches!(ea.encode(&slots), Err(FFIError::CppException(_))));
+    }
+}
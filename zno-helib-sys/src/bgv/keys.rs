@@ -0,0 +1,262 @@
+use core::marker::PhantomData;
+
+use crate::prelude::*;
+use super::*;
+
+/// The default bound of `SecKey::add_some_1d_matrices`, HElib's `HELIB_KEYSWITCH_THRESH`.
+pub const KEYSWITCH_THRESH: i64 = 50;
+
+/// Define the Rust struct to represent the C++ SecKey class
+///
+/// A `SecKey` holds the secret key and, since HElib's `SecKey` derives from
+/// `PubKey`, the public key and key-switching matrices too. Set it up in order:
+///
+/// 1. `SecKey::new` for a built `Context`.
+/// 2. `gen_sec_key` to sample the secret key and its public encryption key.
+/// 3. `add_some_1d_matrices` and `add_frb_matrices` for the key-switching
+///    matrices that rotations and Frobenius automorphisms need.
+/// 4. `public_key` to copy the public part, to encrypt with or hand out.
+///
+/// HElib keeps a reference to the `Context`, so the key borrows it.
+///
+/// # Example
+///
+/// ```no_run
+/// # use zno_helib_sys::prelude::*;
+/// # let context = Builder::new().build().expect("BGV context creation");
+/// let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+/// secret_key.gen_sec_key().expect("secret key generation");
+/// secret_key.add_some_1d_matrices().expect("key-switching matrices");
+/// let public_key = secret_key.public_key().expect("PubKey");
+/// let ctxt = public_key.encrypt_constant(3).expect("Ctxt");
+/// ```
+pub struct SecKey<'a> {
+    pub(crate) inner: cxx::UniquePtr<crate::helib::bgv::ffi::SecKey>,
+    _context: PhantomData<&'a Context>,
+}
+
+impl<'a> SecKey<'a> {
+    /// Creates an empty `SecKey` for a built `Context`.
+    pub fn new(context: &'a Context) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::new_sec_key(&context.inner)?)?;
+        Ok(SecKey::from_ptr(inner))
+    }
+
+    pub(crate) fn from_ptr(inner: cxx::UniquePtr<crate::helib::bgv::ffi::SecKey>) -> Self {
+        SecKey { inner, _context: PhantomData }
+    }
+
+    /// Samples a secret key, and the public encryption key for it.
+    ///
+    /// Returns the index of the new key, `0` for the first one.
+    pub fn gen_sec_key(&mut self) -> Result<i64, FFIError> {
+        // The defaults of HElib: the plaintext space of the context, and
+        // key-switching matrices for up to s^3.
+        Ok(self.inner.pin_mut().gen_sec_key(0, 3)?)
+    }
+
+    /// Adds the key-switching matrices for rotations along every dimension
+    /// of the hypercube, `s(X^{g^i}) -> s(X)`.
+    ///
+    /// For a generator of order larger than `KEYSWITCH_THRESH`, only the
+    /// matrices for baby-step/giant-step rotations are generated.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::CppException` if `gen_sec_key` was not called first.
+    pub fn add_some_1d_matrices(&mut self) -> Result<(), FFIError> {
+        ffi::add_some_1d_matrices(self.inner.pin_mut(), KEYSWITCH_THRESH, 0)?;
+        Ok(())
+    }
+
+    /// Adds the key-switching matrices for the Frobenius automorphisms, `s(X^{p^i}) -> s(X)`.
+    pub fn add_frb_matrices(&mut self) -> Result<(), FFIError> {
+        ffi::add_frb_matrices(self.inner.pin_mut(), 0)?;
+        Ok(())
+    }
+
+    /// Generates the bootstrapping key, encrypted under this secret key, and
+    /// the key-switching matrices recryption needs.
+    ///
+    /// Returns the index of the bootstrapping key. Call it before
+    /// `public_key`, so that the copy can recrypt.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::CppException` if `Context::enable_bootstrapping` was not called first.
+    pub fn gen_recrypt_data(&mut self) -> Result<i64, FFIError> {
+        Ok(self.inner.pin_mut().gen_recrypt_data()?)
+    }
+
+    /// Copies the public key, including the key-switching matrices added so far.
+    pub fn public_key(&self) -> Result<PubKey<'a>, FFIError> {
+        let inner = non_null(ffi::public_key(&self.inner)?)?;
+        Ok(PubKey::from_ptr(inner))
+    }
+
+    /// Decrypts a `Ctxt` that holds the same constant in every slot, see `PubKey::encrypt_constant`.
+    ///
+    /// The result is in `[0, p^r)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::CppException` if the noise is too large to decrypt.
+    pub fn decrypt_constant(&self, ctxt: &Ctxt) -> Result<i64, FFIError> {
+        Ok(ffi::decrypt_constant(&self.inner, &ctxt.inner)?)
+    }
+
+    /// Decrypts a `Ctxt` into a `Ptxt`, one value per slot.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::CppException` if the noise is too large to decrypt.
+    pub fn decrypt(&self, ctxt: &Ctxt) -> Result<Ptxt<'a>, FFIError> {
+        let inner = non_null(ffi::decrypt_ptxt(&self.inner, &ctxt.inner)?)?;
+        Ok(Ptxt::from_ptr(inner))
+    }
+
+    /// Decrypts a CKKS `Ctxt` into a `PtxtArray`, one number per slot.
+    pub fn decrypt_array(&self, ctxt: &Ctxt) -> Result<PtxtArray<'a>, FFIError> {
+        let inner = non_null(ffi::decrypt_ptxt_array(&self.inner, &ctxt.inner)?)?;
+        Ok(PtxtArray::from_ptr(inner))
+    }
+}
+
+// Implement Display for printing, debugging, etc.
+impl core::fmt::Display for SecKey<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SecKey") // How this type name should appear
+    }
+}
+
+/// Define the Rust struct to represent the C++ PubKey class
+///
+/// A `PubKey` encrypts, and holds the key-switching matrices the evaluation
+/// of rotations and multiplications needs. Every `Ctxt` borrows the `PubKey`
+/// it was encrypted under.
+pub struct PubKey<'a> {
+    pub(crate) inner: cxx::UniquePtr<crate::helib::bgv::ffi::PubKey>,
+    _context: PhantomData<&'a Context>,
+}
+
+impl<'a> PubKey<'a> {
+    pub(crate) fn from_ptr(inner: cxx::UniquePtr<crate::helib::bgv::ffi::PubKey>) -> Self {
+        PubKey { inner, _context: PhantomData }
+    }
+
+    /// Encrypts `constant` into every slot.
+    pub fn encrypt_constant(&self, constant: i64) -> Result<Ctxt<'_>, FFIError> {
+        let inner = non_null(ffi::encrypt_constant(&self.inner, constant)?)?;
+        Ok(Ctxt::from_ptr(inner))
+    }
+
+    /// Encrypts `ptxt`, one value per slot.
+    pub fn encrypt(&self, ptxt: &Ptxt) -> Result<Ctxt<'_>, FFIError> {
+        let inner = non_null(ffi::encrypt_ptxt(&self.inner, &ptxt.inner)?)?;
+        Ok(Ctxt::from_ptr(inner))
+    }
+
+    /// Encrypts a CKKS `ptxt`, one number per slot.
+    pub fn encrypt_array(&self, ptxt: &PtxtArray) -> Result<Ctxt<'_>, FFIError> {
+        let inner = non_null(ffi::encrypt_ptxt_array(&self.inner, &ptxt.inner)?)?;
+        Ok(Ctxt::from_ptr(inner))
+    }
+
+    /// Returns true if the key-switching matrices of key `key_id` exist.
+    pub fn key_exists(&self, key_id: i64) -> bool {
+        self.inner.key_exists(key_id)
+    }
+
+    /// Returns true if this key holds the bootstrapping key, see `SecKey::gen_recrypt_data`.
+    pub fn is_bootstrappable(&self) -> bool {
+        self.inner.is_bootstrappable()
+    }
+
+    /// Recrypts (thick bootstrapping) `ctxt`, refreshing its capacity so
+    /// that it can go through more multiplications.
+    ///
+    /// Works for any ciphertext, at a higher cost than `thin_re_crypt`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::CppException` if this key has no bootstrapping key,
+    /// or if the context was bootstrapped without `also_thick`.
+    pub fn re_crypt(&self, ctxt: &mut Ctxt) -> Result<(), FFIError> {
+        self.inner.re_crypt(ctxt.inner.pin_mut())?;
+        Ok(())
+    }
+
+    /// Recrypts (thin bootstrapping) `ctxt`, refreshing its capacity.
+    ///
+    /// Only correct for "thin" ciphertexts, whose every slot holds a constant
+    /// in `[0, p^r)`, like those made from a `Ptxt` or `encrypt_constant`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::CppException` if this key has no bootstrapping key.
+    pub fn thin_re_crypt(&self, ctxt: &mut Ctxt) -> Result<(), FFIError> {
+        self.inner.thin_re_crypt(ctxt.inner.pin_mut())?;
+        Ok(())
+    }
+}
+
+// Implement Display for printing, debugging, etc.
+impl core::fmt::Display for PubKey<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "PubKey") // How this type name should appear
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bgv::testing::setup_bgv_context;
+
+    #[test]
+    fn test_key_generation() {
+        let context = setup_bgv_context(300);
+        let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+        assert_eq!(secret_key.gen_sec_key().unwrap(), 0);
+        secret_key.add_some_1d_matrices().unwrap();
+        secret_key.add_frb_matrices().unwrap();
+
+        let public_key = secret_key.public_key().expect("PubKey");
+        assert!(public_key.key_exists(0));
+        assert!(!public_key.key_exists(1));
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_constant() {
+        let context = setup_bgv_context(300);
+        let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+        secret_key.gen_sec_key().unwrap();
+        let public_key = secret_key.public_key().expect("PubKey");
+
+        let ctxt = public_key.encrypt_constant(42).expect("Ctxt");
+        assert!(ctxt.is_correct());
+        assert_eq!(ctxt.ptxt_space(), 4999);
+        assert_eq!(secret_key.decrypt_constant(&ctxt).unwrap(), 42);
+    }
+
+    #[test]
+    fn test_recrypt_needs_bootstrapping_data() {
+        let context = setup_bgv_context(300);
+        let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+        secret_key.gen_sec_key().unwrap();
+        assert!(matches!(secret_key.gen_recrypt_data(), Err(FFIError::CppException(_))));
+
+        let public_key = secret_key.public_key().expect("PubKey");
+        assert!(!public_key.is_bootstrappable());
+        let mut ctxt = public_key.encrypt_constant(5).expect("Ctxt");
+        assert!(matches!(public_key.re_crypt(&mut ctxt), Err(FFIError::CppException(_))));
+        assert!(matches!(public_key.thin_re_crypt(&mut ctxt), Err(FFIError::CppException(_))));
+        assert_eq!(secret_key.decrypt_constant(&ctxt).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_matrices_need_a_secret_key() {
+        let context = setup_bgv_context(300);
+        let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+        assert!(matches!(secret_key.add_some_1d_matrices(), Err(FFIError::CppException(_))));
+    }
+}
@@ -1,6 +1,23 @@
 // Include the modules
+pub mod binary;
+pub mod ckks;
 pub mod context;
+pub mod ctxt;
+pub mod encrypted_array;
+pub mod keys;
+pub mod ptxt;
+pub mod ptxt_array;
+pub mod serialization;
+#[cfg(test)]
+pub(crate) mod testing;
 
 
 // Re-export the types for external use as `crate::bgv::<type>`
+pub use self::binary::*;
+pub use self::ckks::*;
 pub use self::context::*;
+pub use self::ctxt::*;
+pub use self::encrypted_array::*;
+pub use self::keys::*;
+pub use self::ptxt::*;
+pub use self::ptxt_array::*;
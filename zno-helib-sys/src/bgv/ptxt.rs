@@ -0,0 +1,156 @@
+use core::marker::PhantomData;
+
+use crate::prelude::*;
+use super::*;
+
+/// Define the Rust struct to represent the C++ Ptxt<BGV> class
+///
+/// A `Ptxt` is a BGV plaintext of `len()` slots, one per slot of the
+/// context's `EncryptedArray`. Each slot holds a value modulo the plaintext
+/// space `p^r`. It behaves like a fixed-length `Vec<i64>`: read slots with
+/// `get` or `to_vec`, write them with `set`.
+///
+/// Slots are converted on every access, so there is no `Index` impl handing
+/// out references into the C++ object.
+///
+/// HElib keeps a reference to the `Context`, so the plaintext borrows it.
+///
+/// # Example
+///
+/// ```no_run
+/// # use zno_helib_sys::prelude::*;
+/// # let context = Builder::new().build().expect("BGV context creation");
+/// # let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+/// # secret_key.gen_sec_key().expect("secret key generation");
+/// # let public_key = secret_key.public_key().expect("PubKey");
+/// let mut ptxt = Ptxt::from_slots(&context, &[1, 2, 3]).expect("Ptxt creation");
+/// ptxt.set(3, 4).expect("slot 3");
+///
+/// let mut ctxt = public_key.encrypt(&ptxt).expect("Ctxt");
+/// ctxt.square().expect("square");
+/// let squares = secret_key.decrypt(&ctxt).expect("Ptxt");
+/// assert_eq!(squares.to_vec()[..4], [1, 4, 9, 16]);
+/// ```
+pub struct Ptxt<'a> {
+    // This holds a pointer to the C++ object.
+    pub(crate) inner: cxx::UniquePtr<crate::helib::bgv::ffi::PtxtBGV>,
+    _context: PhantomData<&'a Context>,
+}
+
+impl<'a> Ptxt<'a> {
+    /// Creates a `Ptxt` with every slot set to zero.
+    pub fn new(context: &'a Context) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::new_ptxt(&context.inner)?)?;
+        Ok(Ptxt::from_ptr(inner))
+    }
+
+    /// Creates a `Ptxt` from one value per slot. Slots past the end of `slots` are set to zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::CppException` if there are more values than slots.
+    pub fn from_slots(context: &'a Context, slots: &[i64]) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::ptxt_from_slots(&context.inner, &slots.to_vec())?)?;
+        Ok(Ptxt::from_ptr(inner))
+    }
+
+    pub(crate) fn from_ptr(inner: cxx::UniquePtr<crate::helib::bgv::ffi::PtxtBGV>) -> Self {
+        Ptxt { inner, _context: PhantomData }
+    }
+
+    /// Returns the number of slots.
+    pub fn len(&self) -> usize {
+        self.inner.size()
+    }
+
+    /// Returns true if there are no slots, which only happens for a context with `m = 0`.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the value of slot `index`, in `[0, p^r)`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<i64> {
+        if index >= self.len() {
+            return None;
+        }
+        ffi::ptxt_get(&self.inner, index).ok()
+    }
+
+    /// Sets slot `index` to `value`, reduced modulo `p^r`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::CppException` if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: i64) -> Result<(), FFIError> {
+        ffi::ptxt_set(self.inner.pin_mut(), index, value)?;
+        Ok(())
+    }
+
+    /// Copies every slot into a `Vec`, in `[0, p^r)`.
+    pub fn to_vec(&self) -> Vec<i64> {
+        ffi::ptxt_to_vec(&self.inner)
+    }
+}
+
+impl From<&Ptxt<'_>> for Vec<i64> {
+    fn from(ptxt: &Ptxt<'_>) -> Self {
+        ptxt.to_vec()
+    }
+}
+
+impl Clone for Ptxt<'_> {
+    fn clone(&self) -> Self {
+        let inner = ffi::ptxt_clone(&self.inner).expect("Ptxt copy");
+        Ptxt::from_ptr(inner)
+    }
+}
+
+// Implement Display for printing, debugging, etc.
+impl core::fmt::Display for Ptxt<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Ptxt{:?}", self.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bgv::testing::setup_bgv_context;
+
+    #[test]
+    fn test_slot_access() {
+        let context = setup_bgv_context(300);
+        let mut ptxt = Ptxt::new(&context).expect("Ptxt creation");
+        assert_eq!(ptxt.len(), context.ea().size());
+        assert!(ptxt.to_vec().iter().all(|&slot| slot == 0));
+
+        ptxt.set(1, 5).unwrap();
+        ptxt.set(2, -1).unwrap();
+        assert_eq!(ptxt.get(1), Some(5));
+        assert_eq!(ptxt.get(2), Some(4999 - 1));
+        assert_eq!(ptxt.get(ptxt.len()), None);
+        assert!(matches!(ptxt.set(ptxt.len(), 1), Err(FFIError::CppException(_))));
+
+        let copy = ptxt.clone();
+        ptxt.set(1, 6).unwrap();
+        assert_eq!(copy.get(1), Some(5));
+        assert_eq!(Vec::from(&copy)[..3], [0, 5, 4999 - 1]);
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_slots() {
+        let context = setup_bgv_context(300);
+        let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+        secret_key.gen_sec_key().unwrap();
+        let public_key = secret_key.public_key().expect("PubKey");
+
+        let slots: Vec<i64> = (0..context.ea().size() as i64).collect();
+        let ptxt = Ptxt::from_slots(&context, &slots).expect("Ptxt creation");
+        let mut ctxt = public_key.encrypt(&ptxt).expect("Ctxt");
+        ctxt *= &ctxt.clone();
+
+        let squares = secret_key.decrypt(&ctxt).expect("Ptxt");
+        let expected: Vec<i64> = slots.iter().map(|slot| slot * slot % 4999).collect();
+        assert_eq!(squares.to_vec(), expected);
+    }
+}
@@ -0,0 +1,193 @@
+use core::marker::PhantomData;
+
+use crate::prelude::*;
+use super::*;
+
+pub use num_complex::Complex;
+
+/// Define the Rust struct to represent the C++ PtxtArray class
+///
+/// A `PtxtArray` is a CKKS plaintext of `len()` slots, each holding a real or
+/// complex number. Load it from a slice with `load_f64` or `load_complex`,
+/// and read it back with `store_f64` or `store_complex`. CKKS is
+/// approximate: decrypted values match to about the `precision` the context
+/// was built with, less the noise of the operations.
+///
+/// HElib keeps a reference to the `Context`, so the plaintext borrows it.
+///
+/// # Example
+///
+/// ```no_run
+/// # use zno_helib_sys::prelude::*;
+/// # let context = CKKSBuilder::new().set_m(16 * 1024).and_then(CKKSBuilder::build).expect("CKKS context creation");
+/// # let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+/// # secret_key.gen_sec_key().expect("secret key generation");
+/// # let public_key = secret_key.public_key().expect("PubKey");
+/// let ptxt = PtxtArray::from_f64(&context, &[0.5, 1.5]).expect("PtxtArray creation");
+///
+/// let mut ctxt = public_key.encrypt_array(&ptxt).expect("Ctxt");
+/// ctxt.square().expect("square");
+/// let squares = secret_key.decrypt_array(&ctxt).expect("PtxtArray").store_f64().unwrap();
+/// assert!((squares[1] - 2.25).abs() < 1e-3);
+/// ```
+pub struct PtxtArray<'a> {
+    // This holds a pointer to the C++ object.
+    pub(crate) inner: cxx::UniquePtr<crate::helib::bgv::ffi::PtxtArray>,
+    _context: PhantomData<&'a Context>,
+}
+
+impl<'a> PtxtArray<'a> {
+    /// Creates a `PtxtArray` with every slot set to zero.
+    pub fn new(context: &'a Context) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::new_ptxt_array(&context.inner)?)?;
+        Ok(PtxtArray::from_ptr(inner))
+    }
+
+    /// Creates a `PtxtArray` from one real number per slot. Slots past the end of `values` are set to zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::CppException` if there are more values than slots,
+    /// or if the context is not a CKKS context.
+    pub fn from_f64(context: &'a Context, values: &[f64]) -> Result<Self, FFIError> {
+        let mut ptxt = PtxtArray::new(context)?;
+        ptxt.load_f64(values)?;
+        Ok(ptxt)
+    }
+
+    /// Creates a `PtxtArray` from one complex number per slot. Slots past the end of `values` are set to zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `from_f64`.
+    pub fn from_complex(context: &'a Context, values: &[Complex<f64>]) -> Result<Self, FFIError> {
+        let mut ptxt = PtxtArray::new(context)?;
+        ptxt.load_complex(values)?;
+        Ok(ptxt)
+    }
+
+    pub(crate) fn from_ptr(inner: cxx::UniquePtr<crate::helib::bgv::ffi::PtxtArray>) -> Self {
+        PtxtArray { inner, _context: PhantomData }
+    }
+
+    /// Returns the number of slots.
+    pub fn len(&self) -> usize {
+        self.inner.size() as usize
+    }
+
+    /// Returns true if there are no slots.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Replaces the slots by `values`, setting the slots past its end to zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `from_f64`.
+    pub fn load_f64(&mut self, values: &[f64]) -> Result<(), FFIError> {
+        ffi::ptxt_array_load_f64(self.inner.pin_mut(), &values.to_vec())?;
+        Ok(())
+    }
+
+    /// Replaces the slots by the complex `values`, setting the slots past its end to zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `from_f64`.
+    pub fn load_complex(&mut self, values: &[Complex<f64>]) -> Result<(), FFIError> {
+        let parts: Vec<f64> = values.iter().flat_map(|value| [value.re, value.im]).collect();
+        ffi::ptxt_array_load_complex(self.inner.pin_mut(), &parts)?;
+        Ok(())
+    }
+
+    /// Copies every slot into a `Vec`, discarding the imaginary parts.
+    pub fn store_f64(&self) -> Result<Vec<f64>, FFIError> {
+        Ok(ffi::ptxt_array_store_f64(&self.inner)?)
+    }
+
+    /// Copies every slot into a `Vec` of complex numbers.
+    pub fn store_complex(&self) -> Result<Vec<Complex<f64>>, FFIError> {
+        let parts = ffi::ptxt_array_store_complex(&self.inner)?;
+        Ok(parts.chunks_exact(2).map(|part| Complex::new(part[0], part[1])).collect())
+    }
+}
+
+impl Clone for PtxtArray<'_> {
+    fn clone(&self) -> Self {
+        let inner = ffi::ptxt_array_clone(&self.inner).expect("PtxtArray copy");
+        PtxtArray::from_ptr(inner)
+    }
+}
+
+// Implement Display for printing, debugging, etc.
+impl core::fmt::Display for PtxtArray<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "PtxtArray(slots={})", self.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bgv::testing::setup_ckks_context;
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-3, "{} != {}", a, e);
+        }
+    }
+
+    #[test]
+    fn test_load_store() {
+        let context = setup_ckks_context();
+        let mut ptxt = PtxtArray::new(&context).expect("PtxtArray creation");
+        assert_eq!(ptxt.len(), context.ea().size());
+
+        ptxt.load_f64(&[1.5, -2.25]).unwrap();
+        let values = ptxt.store_f64().unwrap();
+        assert_close(&values[..2], &[1.5, -2.25]);
+        assert!(values[2..].iter().all(|&value| value == 0.0));
+
+        let copy = ptxt.clone();
+        ptxt.load_complex(&[Complex::new(0.5, -1.0)]).unwrap();
+        assert_close(&copy.store_f64().unwrap()[..1], &[1.5]);
+        let values = ptxt.store_complex().unwrap();
+        assert_close(&[values[0].re, values[0].im], &[0.5, -1.0]);
+
+        let too_many = vec![1.0; ptxt.len() + 1];
+        assert!(matches!(ptxt.load_f64(&too_many), Err(FFIError::CppException(_))));
+    }
+
+    #[test]
+    fn test_encrypted_arithmetic() {
+        let context = setup_ckks_context();
+        let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+        secret_key.gen_sec_key().unwrap();
+        let public_key = secret_key.public_key().expect("PubKey");
+
+        let n = context.ea().size();
+        let x: Vec<f64> = (0..n).map(|i| i as f64 / n as f64).collect();
+        let y: Vec<f64> = (0..n).map(|i| 1.0 - i as f64 / n as f64).collect();
+        let ptxt_x = PtxtArray::from_f64(&context, &x).unwrap();
+        let ptxt_y = PtxtArray::from_f64(&context, &y).unwrap();
+
+        // (x * y + y) * 0.5 - x + 1
+        let mut ctxt = public_key.encrypt_array(&ptxt_x).expect("Ctxt");
+        let ctxt_y = public_key.encrypt_array(&ptxt_y).expect("Ctxt");
+        ctxt.multiply_by(&ctxt_y).unwrap();
+        ctxt.add_ptxt_array(&ptxt_y).unwrap();
+        ctxt.mult_by_constant_f64(0.5).unwrap();
+        ctxt.sub_ptxt_array(&ptxt_x).unwrap();
+        ctxt.add_constant_f64(1.0).unwrap();
+
+        let expected: Vec<f64> = x.iter().zip(&y).map(|(x, y)| (x * y + y) * 0.5 - x + 1.0).collect();
+        let decrypted = secret_key.decrypt_array(&ctxt).expect("PtxtArray");
+        assert_close(&decrypted.store_f64().unwrap(), &expected);
+
+        ctxt.mult_by_ptxt_array(&ptxt_x).unwrap();
+        let expected: Vec<f64> = expected.iter().zip(&x).map(|(e, x)| e * x).collect();
+        assert_close(&secret_key.decrypt_array(&ctxt).unwrap().store_f64().unwrap(), &expected);
+    }
+}
@@ -0,0 +1,293 @@
+use crate::prelude::*;
+use super::*;
+
+// Anything the readers throw comes from malformed input: whether the input
+// belongs to the context is asked of the `*_for_context` and `*_for_key`
+// checks first.
+fn read_error(err: cxx::Exception) -> FFIError {
+    FFIError::Io(err.what().to_string())
+}
+
+// Maps the result of a check to `FFIError::InvalidData` when the input does
+// not belong to the context.
+fn check(belongs: Result<bool, cxx::Exception>, what: &str) -> Result<(), FFIError> {
+    match belongs.map_err(read_error)? {
+        true => Ok(()),
+        false => Err(FFIError::InvalidData(format!("The {} does not belong to the context", what))),
+    }
+}
+
+/// Serializes a `Context` with HElib's `writeTo` and `writeToJSON`, so keys
+/// and ciphertexts can cross process boundaries.
+///
+/// Keys are read back against a `Context`, and ciphertexts against a
+/// `PubKey`. The readers check that the object belongs to it, so bytes from
+/// an untrusted party can be read.
+///
+/// # Example
+///
+/// ```no_run
+/// # use zno_helib_sys::prelude::*;
+/// # let context = Builder::new().build().expect("BGV context creation");
+/// # let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+/// # secret_key.gen_sec_key().expect("secret key generation");
+/// # let public_key = secret_key.public_key().expect("PubKey");
+/// let bytes = public_key.encrypt_constant(3).unwrap().to_bytes().expect("Ctxt bytes");
+///
+/// let context = Context::from_bytes(&context.to_bytes().unwrap()).expect("Context");
+/// let public_key = PubKey::from_json(&context, &public_key.to_json().unwrap()).expect("PubKey");
+/// let ctxt = Ctxt::from_bytes(&public_key, &bytes).expect("Ctxt");
+/// ```
+impl Context {
+    /// Serializes the context, in HElib's binary format.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, FFIError> {
+        Ok(ffi::context_to_bytes(&self.inner)?)
+    }
+
+    /// Deserializes a context written by `to_bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::Io` if the bytes are malformed.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::context_from_bytes(bytes).map_err(read_error)?)?;
+        Ok(Context { inner })
+    }
+
+    /// Serializes the context as a JSON document.
+    pub fn to_json(&self) -> Result<String, FFIError> {
+        Ok(ffi::context_to_json(&self.inner)?)
+    }
+
+    /// Deserializes a context written by `to_json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::Io` if the document is malformed.
+    pub fn from_json(json: &str) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::context_from_json(json).map_err(read_error)?)?;
+        Ok(Context { inner })
+    }
+}
+
+impl<'a> SecKey<'a> {
+    /// Serializes the secret key, together with the public key and the
+    /// key-switching matrices, in HElib's binary format.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, FFIError> {
+        Ok(ffi::sec_key_to_bytes(&self.inner)?)
+    }
+
+    /// Deserializes a secret key written by `to_bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::Io` if the bytes are malformed, or
+    /// `FFIError::InvalidData` if the key was generated for another context.
+    pub fn from_bytes(context: &'a Context, bytes: &[u8]) -> Result<Self, FFIError> {
+        check(ffi::sec_key_bytes_for_context(&context.inner, bytes), "secret key")?;
+        let inner = non_null(ffi::sec_key_from_bytes(&context.inner, bytes).map_err(read_error)?)?;
+        Ok(SecKey::from_ptr(inner))
+    }
+
+    /// Serializes the secret key, like `to_bytes`, as a JSON document.
+    pub fn to_json(&self) -> Result<String, FFIError> {
+        Ok(ffi::sec_key_to_json(&self.inner)?)
+    }
+
+    /// Deserializes a secret key written by `to_json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `from_bytes`.
+    pub fn from_json(context: &'a Context, json: &str) -> Result<Self, FFIError> {
+        check(ffi::sec_key_json_for_context(&context.inner, json), "secret key")?;
+        let inner = non_null(ffi::sec_key_from_json(&context.inner, json).map_err(read_error)?)?;
+        Ok(SecKey::from_ptr(inner))
+    }
+}
+
+impl<'a> PubKey<'a> {
+    /// Serializes the public key, together with the key-switching matrices, in HElib's binary format.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, FFIError> {
+        Ok(ffi::pub_key_to_bytes(&self.inner)?)
+    }
+
+    /// Deserializes a public key written by `to_bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::Io` if the bytes are malformed, or
+    /// `FFIError::InvalidData` if the key was generated for another context.
+    pub fn from_bytes(context: &'a Context, bytes: &[u8]) -> Result<Self, FFIError> {
+        check(ffi::pub_key_bytes_for_context(&context.inner, bytes), "public key")?;
+        let inner = non_null(ffi::pub_key_from_bytes(&context.inner, bytes).map_err(read_error)?)?;
+        Ok(PubKey::from_ptr(inner))
+    }
+
+    /// Serializes the public key, like `to_bytes`, as a JSON document.
+    pub fn to_json(&self) -> Result<String, FFIError> {
+        Ok(ffi::pub_key_to_json(&self.inner)?)
+    }
+
+    /// Deserializes a public key written by `to_json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `from_bytes`.
+    pub fn from_json(context: &'a Context, json: &str) -> Result<Self, FFIError> {
+        check(ffi::pub_key_json_for_context(&context.inner, json), "public key")?;
+        let inner = non_null(ffi::pub_key_from_json(&context.inner, json).map_err(read_error)?)?;
+        Ok(PubKey::from_ptr(inner))
+    }
+}
+
+impl<'a> Ctxt<'a> {
+    /// Serializes the ciphertext, in HElib's binary format.
+    ///
+    /// Neither the context nor the public key is included; the reader passes them in.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, FFIError> {
+        Ok(ffi::ctxt_to_bytes(&self.inner)?)
+    }
+
+    /// Deserializes a ciphertext written by `to_bytes`, encrypted under `public_key`.
+    ///
+    /// The ciphertext is checked to belong to the context of `public_key`:
+    /// its primes are primes of the context, every part holds `phi(m)`
+    /// coefficients below its prime, its plaintext space divides that of the
+    /// key, and its secret key is one of the key's.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::Io` if the bytes are malformed, or
+    /// `FFIError::InvalidData` if the ciphertext does not belong to the context.
+    pub fn from_bytes(public_key: &'a PubKey<'_>, bytes: &[u8]) -> Result<Self, FFIError> {
+        check(ffi::ctxt_bytes_for_key(&public_key.inner, bytes), "ciphertext")?;
+        let inner = non_null(ffi::ctxt_from_bytes(&public_key.inner, bytes).map_err(read_error)?)?;
+        Ok(Ctxt::from_ptr(inner))
+    }
+
+    /// Serializes the ciphertext, like `to_bytes`, as a JSON document.
+    pub fn to_json(&self) -> Result<String, FFIError> {
+        Ok(ffi::ctxt_to_json(&self.inner)?)
+    }
+
+    /// Deserializes a ciphertext written by `to_json`, with the checks of `from_bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `from_bytes`.
+    pub fn from_json(public_key: &'a PubKey<'_>, json: &str) -> Result<Self, FFIError> {
+        check(ffi::ctxt_json_for_key(&public_key.inner, json), "ciphertext")?;
+        let inner = non_null(ffi::ctxt_from_json(&public_key.inner, json).map_err(read_error)?)?;
+        Ok(Ctxt::from_ptr(inner))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bgv::testing::setup_bgv_context;
+
+    #[test]
+    fn test_context_round_trip() {
+        let context = setup_bgv_context(300);
+
+        let bytes = context.to_bytes().expect("Context bytes");
+        let loaded = Context::from_bytes(&bytes).expect("Context");
+        assert_eq!(loaded.ea().size(), context.ea().size());
+        assert_eq!(loaded.to_bytes().unwrap(), bytes);
+
+        let json = context.to_json().expect("Context JSON");
+        let loaded = Context::from_json(&json).expect("Context");
+        assert_eq!(loaded.security_level(), context.security_level());
+        assert_eq!(loaded.to_json().unwrap(), json);
+    }
+
+    #[test]
+    fn test_keys_round_trip() {
+        let context = setup_bgv_context(300);
+        let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+        secret_key.gen_sec_key().unwrap();
+        secret_key.add_some_1d_matrices().unwrap();
+        let public_key = secret_key.public_key().expect("PubKey");
+
+        let loaded_secret_key = SecKey::from_bytes(&context, &secret_key.to_bytes().unwrap()).expect("SecKey");
+        let loaded_public_key = PubKey::from_bytes(&context, &public_key.to_bytes().unwrap()).expect("PubKey");
+        let ctxt = loaded_public_key.encrypt_constant(7).expect("Ctxt");
+        assert_eq!(loaded_secret_key.decrypt_constant(&ctxt).unwrap(), 7);
+        assert_eq!(secret_key.decrypt_constant(&ctxt).unwrap(), 7);
+
+        // The key-switching matrices come along.
+        let mut rotated = ctxt.clone();
+        context.ea().rotate(&mut rotated, 1).expect("rotation with the loaded matrices");
+
+        let loaded_secret_key = SecKey::from_json(&context, &secret_key.to_json().unwrap()).expect("SecKey");
+        let loaded_public_key = PubKey::from_json(&context, &public_key.to_json().unwrap()).expect("PubKey");
+        let ctxt = loaded_public_key.encrypt_constant(8).expect("Ctxt");
+        assert_eq!(loaded_secret_key.decrypt_constant(&ctxt).unwrap(), 8);
+    }
+
+    #[test]
+    fn test_ctxt_round_trip() {
+        let context = setup_bgv_context(300);
+        let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+        secret_key.gen_sec_key().unwrap();
+        let public_key = secret_key.public_key().expect("PubKey");
+
+        let slots: Vec<i64> = (0..context.ea().size() as i64).collect();
+        let mut ctxt = public_key.encrypt(&Ptxt::from_slots(&context, &slots).unwrap()).expect("Ctxt");
+        ctxt.square().unwrap();
+        let expected: Vec<i64> = slots.iter().map(|slot| slot * slot % 4999).collect();
+
+        let loaded = Ctxt::from_bytes(&public_key, &ctxt.to_bytes().unwrap()).expect("Ctxt");
+        assert_eq!(secret_key.decrypt(&loaded).unwrap().to_vec(), expected);
+        assert_eq!(loaded.to_bytes().unwrap(), ctxt.to_bytes().unwrap());
+
+        let loaded = Ctxt::from_json(&public_key, &ctxt.to_json().unwrap()).expect("Ctxt");
+        assert_eq!(secret_key.decrypt(&loaded).unwrap().to_vec(), expected);
+    }
+
+    #[test]
+    fn test_wrong_context() {
+        let context = setup_bgv_context(300);
+        let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+        secret_key.gen_sec_key().unwrap();
+        let public_key = secret_key.public_key().expect("PubKey");
+        let ctxt = public_key.encrypt_constant(1).expect("Ctxt");
+
+        // Fewer primes than the context the ciphertext was encrypted for.
+        let other_context = setup_bgv_context(100);
+        let mut other_secret_key = SecKey::new(&other_context).expect("SecKey creation");
+        other_secret_key.gen_sec_key().unwrap();
+        let other_public_key = other_secret_key.public_key().expect("PubKey");
+
+        assert!(matches!(Ctxt::from_bytes(&other_public_key, &ctxt.to_bytes().unwrap()), Err(FFIError::InvalidData(_))));
+        assert!(matches!(Ctxt::from_json(&other_public_key, &ctxt.to_json().unwrap()), Err(FFIError::InvalidData(_))));
+        assert!(matches!(PubKey::from_bytes(&other_context, &public_key.to_bytes().unwrap()), Err(FFIError::InvalidData(_))));
+        assert!(matches!(PubKey::from_json(&other_context, &public_key.to_json().unwrap()), Err(FFIError::InvalidData(_))));
+        assert!(matches!(SecKey::from_bytes(&other_context, &secret_key.to_bytes().unwrap()), Err(FFIError::InvalidData(_))));
+        assert!(matches!(SecKey::from_json(&other_context, &secret_key.to_json().unwrap()), Err(FFIError::InvalidData(_))));
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        let context = setup_bgv_context(300);
+        let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+        secret_key.gen_sec_key().unwrap();
+        let public_key = secret_key.public_key().expect("PubKey");
+
+        assert!(matches!(Context::from_bytes(&[0u8; 4]), Err(FFIError::Io(_))));
+        assert!(matches!(Context::from_json("{"), Err(FFIError::Io(_))));
+        let bytes = public_key.encrypt_constant(1).unwrap().to_bytes().unwrap();
+        assert!(matches!(Ctxt::from_bytes(&public_key, &bytes[..bytes.len() / 2]), Err(FFIError::Io(_))));
+        // The header's begin eye catcher, then its version.
+        for i in [0, 6] {
+            let mut corrupted = bytes.clone();
+            corrupted[i] ^= 0xff;
+            assert!(matches!(Ctxt::from_bytes(&public_key, &corrupted), Err(FFIError::Io(_))));
+        }
+        assert!(matches!(Ctxt::from_json(&public_key, "{"), Err(FFIError::Io(_))));
+        assert!(matches!(PubKey::from_bytes(&context, &[0u8; 4]), Err(FFIError::Io(_))));
+        assert!(matches!(SecKey::from_json(&context, "{\"content\": 1}"), Err(FFIError::Io(_))));
+    }
+}
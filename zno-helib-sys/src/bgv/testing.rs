@@ -0,0 +1,37 @@
+//! The contexts the tests run against.
+//!
+//! Only compiled for this crate's tests. The integration tests include this
+//! file as well, so it names the crate by its path, `zno_helib_sys`.
+
+use zno_helib_sys::prelude::*;
+
+/// Returns the builder of a small BGV context: m = 4095 and c = 2, for the
+/// plaintext modulus `p` and a modulus chain of `bits` bits.
+pub fn setup_bgv_builder(p: u32, bits: u32) -> Builder {
+    let builder = ffi::set_m(ffi::init(), 4095).unwrap();
+    let builder = ffi::set_p(builder, p).unwrap();
+    let builder = ffi::set_bits(builder, bits).unwrap();
+    let builder = ffi::set_c(builder, 2).unwrap();
+    Builder { inner: builder }
+}
+
+/// Returns the BGV context built by `setup_bgv_builder`.
+pub fn setup_bgv_context_with(p: u32, bits: u32) -> Context {
+    setup_bgv_builder(p, bits).build().expect("BGV context creation")
+}
+
+/// Returns the BGV context of `setup_bgv_context_with` for p = 4999, which
+/// most tests run against.
+pub fn setup_bgv_context(bits: u32) -> Context {
+    setup_bgv_context_with(4999, bits)
+}
+
+/// Returns the CKKS context of HElib's tutorial: m = 16384, so 4096 slots,
+/// 119 bits of modulus chain, 20 bits of precision and c = 2.
+pub fn setup_ckks_context() -> Context {
+    let builder = ffi::ckks_set_m(ffi::init_ckks(), 16 * 1024).unwrap();
+    let builder = ffi::ckks_set_bits(builder, 119).unwrap();
+    let builder = ffi::ckks_set_precision(builder, 20).unwrap();
+    let builder = ffi::ckks_set_c(builder, 2).unwrap();
+    CKKSBuilder { inner: builder }.build().expect("CKKS context creation")
+}
@@ -1,17 +1,8 @@
-use core::pin::Pin;
 use core::fmt;
-use core::convert::TryFrom;
-use core::convert::TryInto;
-use std::ptr;
 use std::{fmt::{Display, Formatter}, error::Error};
 
-use cxx::CxxString;
-use cxx::CxxVector;
-use cxx::UniquePtr;
-
 // Import the BGV struct and its fields
 use crate::bgv::*;
-use crate::prelude::*;
 
 #[cxx::bridge(namespace="helib")]
 pub mod ffi {
@@ -26,28 +17,196 @@ pub mod ffi {
 
         fn init() -> UniquePtr<BGVContextBuilder>;
 
-        fn build(builder: UniquePtr<BGVContextBuilder>) -> UniquePtr<Context>;
+        fn build(builder: UniquePtr<BGVContextBuilder>) -> Result<UniquePtr<Context>>;
 
         // fn build_ptr(builder: UniquePtr<BGVContextBuilder>) -> UniquePtr<Context>;
 
 
         fn to_std_vector(rust_vec: &Vec<i64>) -> UniquePtr<CxxVector<i64>>;
 
-        fn set_bits(builder: UniquePtr<BGVContextBuilder>, bits: u32) -> UniquePtr<BGVContextBuilder>;
-        fn set_c(builder: UniquePtr<BGVContextBuilder>, c: u32) -> UniquePtr<BGVContextBuilder>;
-        fn set_m(builder: UniquePtr<BGVContextBuilder>, m: u32) -> UniquePtr<BGVContextBuilder>;
-        fn set_p(builder: UniquePtr<BGVContextBuilder>, p: u32) -> UniquePtr<BGVContextBuilder>;
-        fn set_r(builder: UniquePtr<BGVContextBuilder>, r: u32) -> UniquePtr<BGVContextBuilder>;
+        fn set_bits(builder: UniquePtr<BGVContextBuilder>, bits: u32) -> Result<UniquePtr<BGVContextBuilder>>;
+        fn set_c(builder: UniquePtr<BGVContextBuilder>, c: u32) -> Result<UniquePtr<BGVContextBuilder>>;
+        fn set_m(builder: UniquePtr<BGVContextBuilder>, m: u32) -> Result<UniquePtr<BGVContextBuilder>>;
+        fn set_p(builder: UniquePtr<BGVContextBuilder>, p: u32) -> Result<UniquePtr<BGVContextBuilder>>;
+        fn set_r(builder: UniquePtr<BGVContextBuilder>, r: u32) -> Result<UniquePtr<BGVContextBuilder>>;
+        fn set_sk_hwt(builder: UniquePtr<BGVContextBuilder>, sk_hwt: u32) -> Result<UniquePtr<BGVContextBuilder>>;
+        fn set_resolution(builder: UniquePtr<BGVContextBuilder>, bits: u32) -> Result<UniquePtr<BGVContextBuilder>>;
+        fn set_bits_in_special_primes(builder: UniquePtr<BGVContextBuilder>, bits: u32) -> Result<UniquePtr<BGVContextBuilder>>;
+
+        fn is_bootstrappable(builder: UniquePtr<BGVContextBuilder>, flag: bool) -> Result<UniquePtr<BGVContextBuilder>>;
+        fn set_thickboot(builder: UniquePtr<BGVContextBuilder>) -> Result<UniquePtr<BGVContextBuilder>>;
+        fn set_thinboot(builder: UniquePtr<BGVContextBuilder>) -> Result<UniquePtr<BGVContextBuilder>>;
+
+        type CKKSContextBuilder;
 
-        fn is_bootstrappable(builder: UniquePtr<BGVContextBuilder>, flag: bool) -> UniquePtr<BGVContextBuilder>;
-        fn set_thickboot(builder: UniquePtr<BGVContextBuilder>) -> UniquePtr<BGVContextBuilder>;
-        fn set_thinboot(builder: UniquePtr<BGVContextBuilder>) -> UniquePtr<BGVContextBuilder>;
+        fn init_ckks() -> UniquePtr<CKKSContextBuilder>;
+        fn build_ckks(builder: UniquePtr<CKKSContextBuilder>) -> Result<UniquePtr<Context>>;
+        fn ckks_set_m(builder: UniquePtr<CKKSContextBuilder>, m: u32) -> Result<UniquePtr<CKKSContextBuilder>>;
+        fn ckks_set_bits(builder: UniquePtr<CKKSContextBuilder>, bits: u32) -> Result<UniquePtr<CKKSContextBuilder>>;
+        fn ckks_set_precision(builder: UniquePtr<CKKSContextBuilder>, precision: u32) -> Result<UniquePtr<CKKSContextBuilder>>;
+        fn ckks_set_c(builder: UniquePtr<CKKSContextBuilder>, c: u32) -> Result<UniquePtr<CKKSContextBuilder>>;
 
-        // fn set_gens(builder: UniquePtr<BGVContextBuilder>, gens: &CxxVector<i64>);
-        // fn set_mvec(builder: UniquePtr<BGVContextBuilder>, mvec: &CxxVector<i64>);
-        // fn set_ords(builder: UniquePtr<BGVContextBuilder>, ords: &CxxVector<i64>);
+        fn set_gens(builder: UniquePtr<BGVContextBuilder>, gens: &Vec<i64>) -> Result<UniquePtr<BGVContextBuilder>>;
+        fn set_mvec(builder: UniquePtr<BGVContextBuilder>, mvec: &Vec<i64>) -> Result<UniquePtr<BGVContextBuilder>>;
+        fn set_ords(builder: UniquePtr<BGVContextBuilder>, ords: &Vec<i64>) -> Result<UniquePtr<BGVContextBuilder>>;
 
         fn getM(self: &Context) -> i64;
+        #[rust_name = "security_level"]
+        fn securityLevel(self: &Context) -> f64;
+        fn enable_bootstrapping(context: Pin<&mut Context>, mvec: &Vec<i64>, build_cache: bool, also_thick: bool) -> Result<()>;
+        #[rust_name = "is_bootstrappable"]
+        fn isBootstrappable(self: &Context) -> bool;
+        #[rust_name = "get_p"]
+        fn getP(self: &Context) -> i64;
+        #[rust_name = "get_r"]
+        fn getR(self: &Context) -> i64;
+        #[rust_name = "get_phi_m"]
+        fn getPhiM(self: &Context) -> i64;
+        #[rust_name = "get_n_slots"]
+        fn getNSlots(self: &Context) -> i64;
+        fn context_gens(context: &Context) -> Vec<i64>;
+        fn context_ords(context: &Context) -> Vec<i64>;
+        fn context_mvec(context: &Context) -> Vec<i64>;
+        fn context_c(context: &Context) -> i64;
+        fn context_bits(context: &Context) -> i64;
+        fn context_chain_length(context: &Context) -> i64;
+
+        type SecKey;
+        type PubKey;
+
+        fn new_sec_key(context: &Context) -> Result<UniquePtr<SecKey>>;
+        #[rust_name = "gen_sec_key"]
+        fn GenSecKey(self: Pin<&mut SecKey>, ptxt_space: i64, max_deg_kswitch: i64) -> Result<i64>;
+        #[rust_name = "add_some_1d_matrices"]
+        fn addSome1DMatrices(secret_key: Pin<&mut SecKey>, bound: i64, key_id: i64) -> Result<()>;
+        #[rust_name = "add_frb_matrices"]
+        fn addFrbMatrices(secret_key: Pin<&mut SecKey>, key_id: i64) -> Result<()>;
+        fn public_key(secret_key: &SecKey) -> Result<UniquePtr<PubKey>>;
+        #[rust_name = "key_exists"]
+        fn keyExists(self: &PubKey, key_id: i64) -> bool;
+        #[rust_name = "gen_recrypt_data"]
+        fn genRecryptData(self: Pin<&mut SecKey>) -> Result<i64>;
+        #[rust_name = "is_bootstrappable"]
+        fn isBootstrappable(self: &PubKey) -> bool;
+        #[rust_name = "re_crypt"]
+        fn reCrypt(self: &PubKey, ctxt: Pin<&mut Ctxt>) -> Result<()>;
+        #[rust_name = "thin_re_crypt"]
+        fn thinReCrypt(self: &PubKey, ctxt: Pin<&mut Ctxt>) -> Result<()>;
+
+        fn new_ctxt(public_key: &PubKey) -> Result<UniquePtr<Ctxt>>;
+        fn encrypt_constant(public_key: &PubKey, constant: i64) -> Result<UniquePtr<Ctxt>>;
+        fn decrypt_constant(secret_key: &SecKey, ctxt: &Ctxt) -> Result<i64>;
+
+        type Ctxt;
+
+        fn ctxt_clone(ctxt: &Ctxt) -> Result<UniquePtr<Ctxt>>;
+        fn add_assign(ctxt: Pin<&mut Ctxt>, other: &Ctxt) -> Result<()>;
+        fn sub_assign(ctxt: Pin<&mut Ctxt>, other: &Ctxt) -> Result<()>;
+        fn mul_assign(ctxt: Pin<&mut Ctxt>, other: &Ctxt) -> Result<()>;
+        fn mult_by_constant(ctxt: Pin<&mut Ctxt>, constant: i64) -> Result<()>;
+        fn add_constant(ctxt: Pin<&mut Ctxt>, constant: i64) -> Result<()>;
+        fn add_constant_f64(ctxt: Pin<&mut Ctxt>, constant: f64) -> Result<()>;
+        fn mult_by_constant_f64(ctxt: Pin<&mut Ctxt>, constant: f64) -> Result<()>;
+
+        fn negate(self: Pin<&mut Ctxt>) -> Result<()>;
+        fn square(self: Pin<&mut Ctxt>) -> Result<()>;
+        fn cube(self: Pin<&mut Ctxt>) -> Result<()>;
+        fn power(self: Pin<&mut Ctxt>, e: i64) -> Result<()>;
+        #[rust_name = "re_linearize"]
+        fn reLinearize(self: Pin<&mut Ctxt>, key_idx: i64) -> Result<()>;
+        #[rust_name = "is_correct"]
+        fn isCorrect(self: &Ctxt) -> bool;
+        #[rust_name = "get_ptxt_space"]
+        fn getPtxtSpace(self: &Ctxt) -> i64;
+        fn capacity(self: &Ctxt) -> f64;
+        #[rust_name = "bit_capacity"]
+        fn bitCapacity(self: &Ctxt) -> i64;
+        #[rust_name = "error_bound"]
+        fn errorBound(self: &Ctxt) -> f64;
+        #[rust_name = "log_of_prime_set"]
+        fn logOfPrimeSet(self: &Ctxt) -> f64;
+
+        type EncryptedArray;
+        #[namespace = "NTL"]
+        type ZZX;
+        // `Ptxt<BGV>`, see the alias in ffi_wrapper.h.
+        type PtxtBGV;
+
+        fn get_ea(context: &Context) -> &EncryptedArray;
+        fn size(self: &EncryptedArray) -> i64;
+        fn ea_encode(ea: &EncryptedArray, slots: &Vec<i64>) -> Result<UniquePtr<ZZX>>;
+        fn ea_decode(ea: &EncryptedArray, poly: &ZZX) -> Result<Vec<i64>>;
+        fn dimension(self: &EncryptedArray) -> i64;
+        #[rust_name = "size_of_dimension"]
+        fn sizeOfDimension(self: &EncryptedArray, i: i64) -> i64;
+
+        fn ea_rotate(ea: &EncryptedArray, ctxt: Pin<&mut Ctxt>, k: i64) -> Result<bool>;
+        fn ea_shift(ea: &EncryptedArray, ctxt: Pin<&mut Ctxt>, k: i64) -> Result<bool>;
+        fn ea_rotate_1d(ea: &EncryptedArray, ctxt: Pin<&mut Ctxt>, i: i64, k: i64) -> Result<bool>;
+        fn ea_shift_1d(ea: &EncryptedArray, ctxt: Pin<&mut Ctxt>, i: i64, k: i64) -> Result<bool>;
+        fn total_sums(ea: &EncryptedArray, ctxt: Pin<&mut Ctxt>) -> Result<bool>;
+        fn running_sums(ea: &EncryptedArray, ctxt: Pin<&mut Ctxt>) -> Result<bool>;
+
+        fn new_ptxt(context: &Context) -> Result<UniquePtr<PtxtBGV>>;
+        fn ptxt_from_slots(context: &Context, slots: &Vec<i64>) -> Result<UniquePtr<PtxtBGV>>;
+        fn ptxt_clone(ptxt: &PtxtBGV) -> Result<UniquePtr<PtxtBGV>>;
+        fn size(self: &PtxtBGV) -> usize;
+        fn ptxt_get(ptxt: &PtxtBGV, index: usize) -> Result<i64>;
+        fn ptxt_set(ptxt: Pin<&mut PtxtBGV>, index: usize, value: i64) -> Result<()>;
+        fn ptxt_to_vec(ptxt: &PtxtBGV) -> Vec<i64>;
+        fn encrypt_ptxt(public_key: &PubKey, ptxt: &PtxtBGV) -> Result<UniquePtr<Ctxt>>;
+        fn decrypt_ptxt(secret_key: &SecKey, ctxt: &Ctxt) -> Result<UniquePtr<PtxtBGV>>;
+
+        type PtxtArray;
+
+        fn size(self: &PtxtArray) -> i64;
+        fn new_ptxt_array(context: &Context) -> Result<UniquePtr<PtxtArray>>;
+        fn ptxt_array_clone(ptxt: &PtxtArray) -> Result<UniquePtr<PtxtArray>>;
+        fn ptxt_array_load_f64(ptxt: Pin<&mut PtxtArray>, values: &Vec<f64>) -> Result<()>;
+        fn ptxt_array_load_complex(ptxt: Pin<&mut PtxtArray>, parts: &Vec<f64>) -> Result<()>;
+        fn ptxt_array_store_f64(ptxt: &PtxtArray) -> Result<Vec<f64>>;
+        fn ptxt_array_store_complex(ptxt: &PtxtArray) -> Result<Vec<f64>>;
+        fn encrypt_ptxt_array(public_key: &PubKey, ptxt: &PtxtArray) -> Result<UniquePtr<Ctxt>>;
+        fn decrypt_ptxt_array(secret_key: &SecKey, ctxt: &Ctxt) -> Result<UniquePtr<PtxtArray>>;
+        fn add_ptxt_array(ctxt: Pin<&mut Ctxt>, ptxt: &PtxtArray) -> Result<()>;
+        fn sub_ptxt_array(ctxt: Pin<&mut Ctxt>, ptxt: &PtxtArray) -> Result<()>;
+        fn mult_by_ptxt_array(ctxt: Pin<&mut Ctxt>, ptxt: &PtxtArray) -> Result<()>;
+
+        // `std::vector<Ctxt>`, see the alias in ffi_wrapper.h.
+        type CtxtVector;
+
+        fn encrypt_binary_nums(public_key: &PubKey, ea: &EncryptedArray, nums: &Vec<i64>, bit_size: usize) -> Result<UniquePtr<CtxtVector>>;
+        fn decrypt_binary_nums(bits: &CtxtVector, secret_key: &SecKey, ea: &EncryptedArray, twos_complement: bool) -> Result<Vec<i64>>;
+        fn ctxt_vector_clone(bits: &CtxtVector) -> Result<UniquePtr<CtxtVector>>;
+        fn size(self: &CtxtVector) -> usize;
+        fn add_two_numbers(lhs: &CtxtVector, rhs: &CtxtVector, size_limit: i64) -> Result<UniquePtr<CtxtVector>>;
+        fn subtract_binary(lhs: &CtxtVector, rhs: &CtxtVector) -> Result<UniquePtr<CtxtVector>>;
+        fn mult_two_numbers(lhs: &CtxtVector, rhs: &CtxtVector, rhs_twos_complement: bool, size_limit: i64) -> Result<UniquePtr<CtxtVector>>;
+        fn compare_two_numbers(mu: Pin<&mut Ctxt>, ni: Pin<&mut Ctxt>, a: &CtxtVector, b: &CtxtVector, twos_complement: bool) -> Result<()>;
+
+        // Binary and JSON serialization, see serialization.rs.
+        fn context_to_bytes(context: &Context) -> Result<Vec<u8>>;
+        fn context_from_bytes(bytes: &[u8]) -> Result<UniquePtr<Context>>;
+        fn context_to_json(context: &Context) -> Result<String>;
+        fn context_from_json(json: &str) -> Result<UniquePtr<Context>>;
+        fn sec_key_to_bytes(secret_key: &SecKey) -> Result<Vec<u8>>;
+        fn sec_key_from_bytes(context: &Context, bytes: &[u8]) -> Result<UniquePtr<SecKey>>;
+        fn sec_key_to_json(secret_key: &SecKey) -> Result<String>;
+        fn sec_key_from_json(context: &Context, json: &str) -> Result<UniquePtr<SecKey>>;
+        fn pub_key_to_bytes(public_key: &PubKey) -> Result<Vec<u8>>;
+        fn pub_key_from_bytes(context: &Context, bytes: &[u8]) -> Result<UniquePtr<PubKey>>;
+        fn pub_key_to_json(public_key: &PubKey) -> Result<String>;
+        fn pub_key_from_json(context: &Context, json: &str) -> Result<UniquePtr<PubKey>>;
+        fn ctxt_to_bytes(ctxt: &Ctxt) -> Result<Vec<u8>>;
+        fn ctxt_from_bytes(public_key: &PubKey, bytes: &[u8]) -> Result<UniquePtr<Ctxt>>;
+        fn ctxt_to_json(ctxt: &Ctxt) -> Result<String>;
+        fn ctxt_from_json(public_key: &PubKey, json: &str) -> Result<UniquePtr<Ctxt>>;
+        fn sec_key_bytes_for_context(context: &Context, bytes: &[u8]) -> Result<bool>;
+        fn sec_key_json_for_context(context: &Context, json: &str) -> Result<bool>;
+        fn pub_key_bytes_for_context(context: &Context, bytes: &[u8]) -> Result<bool>;
+        fn pub_key_json_for_context(context: &Context, json: &str) -> Result<bool>;
+        fn ctxt_bytes_for_key(public_key: &PubKey, bytes: &[u8]) -> Result<bool>;
+        fn ctxt_json_for_key(public_key: &PubKey, json: &str) -> Result<bool>;
     }
 }
 
@@ -66,7 +225,7 @@ impl Builder {
     //       That is you won't be able to use `cb` afterward.
     pub fn build(self) -> Result<Context, FFIError> {
         // This is safe because it transfers ownership of the Context
-        let context_ptr = ffi::build(self.inner);
+        let context_ptr = ffi::build(self.inner)?;
         // Check if the pointer is null
         if context_ptr.is_null() {
             return Err(FFIError::NullPointer(NullPointerError));
@@ -74,21 +233,30 @@ impl Builder {
         Ok(Context { inner: context_ptr })
     }
 
-    fn metric_set(self, metric: Metric) -> Result<Builder, BGVError> {
-        // Delegate to the implementation for each field.
-        match metric {
-            Metric::Bits(value) => self.set_bits(value),
-            // Metric::Bootstrap(value) => self.set_bootstrap(value),
-            // Metric::Bootstrappable(value) => self.set_bootstrappable(value),
-            Metric::C(value) => self.set_c(value),
-            // Metric::Gens(value) => self.set_gens(value),
-            Metric::M(value) => self.set_m(value),
-            // Metric::Mvec(value) => self.set_mvec(value),
-            // Metric::Ords(value) => self.set_ords(value),
-            Metric::P(value) => self.set_p(value),
-            Metric::R(value) => self.set_r(value),
-            _ => todo!()
-        }
+    /// Sets the Hamming weight of the secret key. 0 keeps HElib's default,
+    /// which depends on whether the context is bootstrappable.
+    pub fn set_sk_hwt(mut self, sk_hwt: u32) -> Result<Self, FFIError> {
+        self.inner = ffi::set_sk_hwt(self.inner, sk_hwt)?;
+        Ok(self)
+    }
+
+    /// Sets the bit resolution HElib uses to choose the primes of the modulus chain.
+    pub fn set_resolution(mut self, bits: u32) -> Result<Self, FFIError> {
+        self.inner = ffi::set_resolution(self.inner, bits)?;
+        Ok(self)
+    }
+
+    /// Sets the size in bits of the special primes used for key switching.
+    /// 0 lets HElib derive it from `bits` and `c`.
+    pub fn set_bits_in_special_primes(mut self, bits: u32) -> Result<Self, FFIError> {
+        self.inner = ffi::set_bits_in_special_primes(self.inner, bits)?;
+        Ok(self)
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -120,6 +288,9 @@ pub enum FFIError {
     NullPointer(NullPointerError),
     // Other FFI-related errors can be added here
     CppException(String), // This can represent an exception thrown by C++
+    MissingKeySwitchMatrix(String), // A rotation needs a key-switching matrix that was not generated
+    Io(String), // Reading or writing a serialized object failed
+    InvalidData(String), // A deserialized object does not belong to the context
     // ...
 }
 
@@ -130,165 +301,43 @@ impl Display for FFIError {
         match self {
             FFIError::NullPointer(err) => write!(f, "Null pointer error: {}", err),
             FFIError::CppException(err) => write!(f, "C++ exception: {}", err),
+            FFIError::MissingKeySwitchMatrix(err) => write!(f, "Missing key-switching matrix: {}", err),
+            FFIError::Io(err) => write!(f, "I/O error: {}", err),
+            FFIError::InvalidData(err) => write!(f, "Invalid data: {}", err),
             // other cases as needed
         }
     }
 }
 
-// Logic specific to the HElib implementation belongs here.
-// #[derive(Debug)]
-pub struct Builder {
-    // Holds a pointer to the C++ object
-    pub inner: cxx::UniquePtr<super::ffi::BGVContextBuilder>,
-}
-
-impl Setters for Builder {
-    fn set_bits<T, E>(mut self, value: T) -> Result<Self, BGVError>
-    where
-        Self: Sized,
-        T: ToU32<E>,
-        E: Into<SetError>,
-    {
-        let u32_value = value.to_u32().map_err(Into::<SetError>::into).map_err(Into::<BGVError>::into)?;
-        // Assuming `ffi::set_bits` returns Result<(), BitsError>
-        self.inner = ffi::set_bits(self.inner, u32_value);
-        Ok(self)
-    }
-
-    fn set_c<T, E>(mut self, value: T) -> Result<Self, BGVError>
-    where
-        Self: Sized,
-        T: ToU32<E>,
-        E: Into<SetError>,
-    {
-        let u32_value = value.to_u32().map_err(Into::<SetError>::into).map_err(Into::<BGVError>::into)?;
-        // Assuming `ffi::set_c` returns Result<(), CError>
-        self.inner = ffi::set_c(self.inner, u32_value);
-        Ok(self)
-    }
-
-    fn set_m<T, E>(mut self, value: T) -> Result<Self, BGVError>
-    where
-        Self: Sized,
-        T: ToU32<E>,
-        E: Into<SetError>,
-    {
-        let u32_value = value.to_u32().map_err(Into::<SetError>::into).map_err(Into::<BGVError>::into)?;
-        // Assuming `ffi::set_m` returns Result<(), MError>
-        self.inner = ffi::set_m(self.inner, u32_value);
-        Ok(self)
-    }
-
-    fn set_p<T, E>(mut self, value: T) -> Result<Self, BGVError>
-    where
-        Self: Sized,
-        T: ToU32<E>,
-        E: Into<SetError>,
-    {
-        let u32_value = value.to_u32().map_err(Into::<SetError>::into).map_err(Into::<BGVError>::into)?;
-        // Assuming `ffi::set_p` returns Result<(), PError>
-        self.inner = ffi::set_p(self.inner, u32_value);
-        Ok(self)
-    }
-
-    fn set_r<T, E>(mut self, value: T) -> Result<Self, BGVError>
-    where
-        Self: Sized,
-        T: ToU32<E>,
-        E: Into<SetError>,
-    {
-        let u32_value = value.to_u32().map_err(Into::<SetError>::into).map_err(Into::<BGVError>::into)?;
-        // Assuming `ffi::set_r` returns Result<(), RError>
-        self.inner = ffi::set_r(self.inner, u32_value);
-        Ok(self)
-    }
-
-    fn set(self, value: Metric) -> Result<Self, BGVError>
-    {
-
-        // Convert `value` into `Metric`, since `Into` is infallible
-        let metric: Metric = value.into();
-
-        self.metric_set(metric)
-    }
-
-    fn try_set<T: TryInto<Metric, Error=BGVError>>(self, value: T) -> Result<Self, BGVError>
-    where
-        Self: Sized,
-    {
-        // Convert `value` into `Metric`, since `TryInto` is fallible
-        let metric = value.try_into()?;
-
-        self.metric_set(metric)
+// HElib signals invalid parameters by throwing, e.g. `helib::InvalidArgument`
+// or `helib::LogicError`. The bridge functions return `Result<_, cxx::Exception>`,
+// so the exception message is caught and carried over here instead of aborting.
+// cxx keeps only the message, so the rotation shims report a missing
+// key-switching matrix through their return value instead.
+impl From<cxx::Exception> for FFIError {
+    fn from(err: cxx::Exception) -> Self {
+        FFIError::CppException(err.what().to_string())
     }
 }
 
-
-// Primarily test success scenarios as the `try_from(...)` implementations
-// handle the error scenarios.
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use cxx::CxxVector;
-
-    // Helper function to create a Context with default or dummy data.
-    fn setup_bgv_context(parameters: Parameters) -> Context {
-        Context::new(parameters).expect("BGV context creation")
-    }
-
-    // Test-specific Error types
-    #[derive(Debug, PartialEq)]
-    struct TestError(String);
-
-    // Assuming BGVError can encapsulate or be constructed from TestError
-    impl From<TestError> for BGVError {
-        fn from(e: TestError) -> Self {
-            match e {
-                TestError(s) => BGVError::GenericError(GenericError { kind: GenericErrorKind::Unexpected(s) })
-            }
-        }
-    }
-
-    #[test]
-    fn test_set_bits_success() {
-        let builder = Builder::new();
-        let result = builder.set(Bits::try_from(500).unwrap().into());
-        assert!(result.is_ok());
-    }
-
-    #[test]
-    fn test_set_c_success() {
-        let builder = Builder::new();
-        let result = builder.set(C::try_from(2).unwrap().into());
-        assert!(result.is_ok());
-    }
-
-    #[test]
-    fn test_set_m_success() {
-        let builder = Builder::new();
-        let result = builder.set(M::try_from(42).unwrap().into());
-        assert!(result.is_ok());
-    }
-
-    #[test]
-    fn test_set_p_success() {
-        let builder = Builder::new();
-        let result = builder.set(P::try_from(2).unwrap().into());
-        assert!(result.is_ok());
-    }
-
-    #[test]
-    fn test_set_r_success() {
-        let builder = Builder::new();
-        let result = builder.set(R::try_from(1).unwrap().into());
-        assert!(result.is_ok());
-    }
-
-    #[ignore = "Incomplete HELib FFI"]
-    #[test]
-    fn test_context_with_defaults() {
-        let context = Context::new(Parameters::default());
-        assert!(context.is_ok());
+/// Checks a `UniquePtr` received from C++ and returns it unchanged when it is not null.
+///
+/// # Errors
+///
+/// Returns `FFIError::NullPointer` when the C++ side returned a null pointer.
+pub(crate) fn non_null<T>(ptr: cxx::UniquePtr<T>) -> Result<cxx::UniquePtr<T>, FFIError>
+where
+    T: cxx::memory::UniquePtrTarget,
+{
+    if ptr.is_null() {
+        return Err(FFIError::NullPointer(NullPointerError));
     }
+    Ok(ptr)
+}
 
+// Logic specific to the HElib implementation belongs here.
+// #[derive(Debug)]
+pub struct Builder {
+    // Holds a pointer to the C++ object
+    pub inner: cxx::UniquePtr<ffi::BGVContextBuilder>,
 }
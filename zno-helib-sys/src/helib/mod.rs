@@ -1,4 +1,5 @@
 pub mod bgv;
+pub mod schema;
 pub mod version;
 
 // pub use self::bgv::*;
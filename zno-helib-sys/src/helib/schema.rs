@@ -0,0 +1,40 @@
+/// The homomorphic encryption schemes, as numbered by `zno_fhe::Schema`.
+///
+/// HElib implements two of them: `Bgv`, built with `Builder`, and `Ckks`,
+/// built with `CKKSBuilder`. Both build the same `Context`. HElib has no
+/// BFV, so `Bfv` has no builder.
+#[repr(u8)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Schema {
+    None = 0x0,
+    Bfv = 0x1,
+    Ckks = 0x2,
+    Bgv = 0x3,
+}
+
+impl Schema {
+    /// Returns true if HElib implements the scheme.
+    pub fn is_supported(&self) -> bool {
+        matches!(self, Schema::Bgv | Schema::Ckks)
+    }
+}
+
+/// Returns the default value for `Schema`, which is `Schema::Bgv`.
+impl Default for Schema {
+    fn default() -> Self {
+        Schema::Bgv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_supported_schemes() {
+        assert_eq!(Schema::default(), Schema::Bgv);
+        assert!(Schema::Ckks.is_supported());
+        assert!(!Schema::Bfv.is_supported());
+        assert!(!Schema::None.is_supported());
+    }
+}
@@ -1,10 +1,12 @@
+// The test fixtures name the crate by its path, see `bgv::testing`.
+#[cfg(test)]
+extern crate self as zno_helib_sys;
+
 pub mod bgv;
 #[cfg(feature = "helib")]
 pub mod helib;
-#[cfg(feature = "openfhe")]
-pub mod openfhe;
-#[cfg(feature = "seal")]
-pub mod seal;
+#[cfg(feature = "helib")]
+pub use crate::helib::schema::Schema;
 
 // Make the prelude module available at the top level of the crate.
 pub mod prelude;
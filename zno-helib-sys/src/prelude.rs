@@ -1,6 +1,14 @@
+pub use crate::helib::schema::*;
 pub use crate::helib::version::*;
 
+pub use crate::bgv::binary::*;
+pub use crate::bgv::ckks::*;
 pub use crate::bgv::context::*;
+pub use crate::bgv::ctxt::*;
+pub use crate::bgv::encrypted_array::*;
+pub use crate::bgv::keys::*;
+pub use crate::bgv::ptxt::*;
+pub use crate::bgv::ptxt_array::*;
 pub use crate::helib::bgv::*;
 
 // // Re-export all public items from each submodule
@@ -1,5 +1,3 @@
-use zno_helib_sys::prelude::*;
-
 #[cfg(test)]
 mod tests {
     #[test]
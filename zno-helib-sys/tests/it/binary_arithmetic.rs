@@ -0,0 +1,96 @@
+// HElib's BGV binary arithmetic example (BGV_binary_arithmetic.cpp), without
+// bootstrapping: small numbers keep the circuits within the capacity of the context.
+use zno_helib_sys::prelude::*;
+
+use crate::testing::setup_bgv_context_with;
+
+const BIT_SIZE: usize = 4;
+
+// The parameters of the example, p = 2 for bits.
+fn setup_binary_context() -> Context {
+    setup_bgv_context_with(2, 500)
+}
+
+// A different number in every slot, below 2^BIT_SIZE.
+fn numbers(nslots: usize, seed: i64) -> Vec<i64> {
+    (0..nslots as i64).map(|i| (i * seed + 3) % (1 << BIT_SIZE)).collect()
+}
+
+#[test]
+fn test_binary_arithmetic() {
+    let context = setup_binary_context();
+    let ea = context.ea();
+    let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+    secret_key.gen_sec_key().unwrap();
+    secret_key.add_some_1d_matrices().unwrap();
+    let public_key = secret_key.public_key().expect("PubKey");
+
+    let a = numbers(ea.size(), 7);
+    let b = numbers(ea.size(), 5);
+    let c = numbers(ea.size(), 3);
+    let encrypted_a = BinaryNumber::encrypt(&public_key, &ea, &a, BIT_SIZE).expect("encryption");
+    let encrypted_b = BinaryNumber::encrypt(&public_key, &ea, &b, BIT_SIZE).expect("encryption");
+    let encrypted_c = BinaryNumber::encrypt(&public_key, &ea, &c, BIT_SIZE).expect("encryption");
+    assert_eq!(encrypted_a.len(), BIT_SIZE);
+    assert_eq!(encrypted_a.decrypt(&secret_key, &ea, false).unwrap(), a);
+
+    // a*b+c
+    let product = encrypted_a.multiply(&encrypted_b, false, None).expect("multiplication");
+    let mul_add = product.add(&encrypted_c, None).expect("addition");
+    let expected: Vec<i64> = (0..ea.size()).map(|i| a[i] * b[i] + c[i]).collect();
+    assert_eq!(mul_add.decrypt(&secret_key, &ea, false).unwrap(), expected);
+
+    // a+b+c
+    let sum = encrypted_a.add(&encrypted_b, None).and_then(|sum| sum.add(&encrypted_c, None)).expect("addition");
+    let expected: Vec<i64> = (0..ea.size()).map(|i| a[i] + b[i] + c[i]).collect();
+    assert_eq!(sum.decrypt(&secret_key, &ea, false).unwrap(), expected);
+
+    // The sum limited to BIT_SIZE bits wraps around.
+    let wrapped = encrypted_a.add(&encrypted_b, Some(BIT_SIZE)).expect("addition");
+    assert_eq!(wrapped.len(), BIT_SIZE);
+    let expected: Vec<i64> = (0..ea.size()).map(|i| (a[i] + b[i]) % (1 << BIT_SIZE)).collect();
+    assert_eq!(wrapped.decrypt(&secret_key, &ea, false).unwrap(), expected);
+}
+
+#[test]
+fn test_binary_subtraction() {
+    let context = setup_binary_context();
+    let ea = context.ea();
+    let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+    secret_key.gen_sec_key().unwrap();
+    secret_key.add_some_1d_matrices().unwrap();
+    let public_key = secret_key.public_key().expect("PubKey");
+
+    // One bit more than the numbers, for the sign.
+    let a = numbers(ea.size(), 7);
+    let b = numbers(ea.size(), 5);
+    let encrypted_a = BinaryNumber::encrypt(&public_key, &ea, &a, BIT_SIZE + 1).expect("encryption");
+    let encrypted_b = BinaryNumber::encrypt(&public_key, &ea, &b, BIT_SIZE + 1).expect("encryption");
+
+    let difference = encrypted_a.subtract(&encrypted_b).expect("subtraction");
+    let expected: Vec<i64> = (0..ea.size()).map(|i| a[i] - b[i]).collect();
+    assert_eq!(difference.decrypt(&secret_key, &ea, true).unwrap(), expected);
+}
+
+#[test]
+fn test_binary_comparison() {
+    let context = setup_binary_context();
+    let ea = context.ea();
+    let mut secret_key = SecKey::new(&context).expect("SecKey creation");
+    secret_key.gen_sec_key().unwrap();
+    secret_key.add_some_1d_matrices().unwrap();
+    let public_key = secret_key.public_key().expect("PubKey");
+
+    let a = numbers(ea.size(), 7);
+    let b = numbers(ea.size(), 5);
+    let encrypted_a = BinaryNumber::encrypt(&public_key, &ea, &a, BIT_SIZE).expect("encryption");
+    let encrypted_b = BinaryNumber::encrypt(&public_key, &ea, &b, BIT_SIZE).expect("encryption");
+
+    let (greater, less) = encrypted_a.compare(&encrypted_b, false).expect("comparison");
+    let greater = secret_key.decrypt(&greater).unwrap().to_vec();
+    let less = secret_key.decrypt(&less).unwrap().to_vec();
+    for i in 0..ea.size() {
+        assert_eq!(greater[i], (a[i] > b[i]) as i64, "a > b in slot {}", i);
+        assert_eq!(less[i], (a[i] < b[i]) as i64, "a < b in slot {}", i);
+    }
+}
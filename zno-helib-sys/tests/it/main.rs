@@ -1,2 +1,9 @@
 // For a library with a public API which is published to crates.io, I avoid unit tests. Instead, I use a single integration tests, called `it` (integration test).
-// Integration tests use the library as an external crate. This forces the usage of the same public API that consumers use, resulting in a better design feedback.
\ No newline at end of file
+// Integration tests use the library as an external crate. This forces the usage of the same public API that consumers use, resulting in a better design feedback.
+
+// The crate's own test contexts, which are only compiled for its unit tests.
+#[allow(dead_code)]
+#[path = "../../src/bgv/testing.rs"]
+mod testing;
+
+mod binary_arithmetic;
@@ -60,7 +60,9 @@ fn main() -> miette::Result<()> {
     // that includes the generated Rust bindings for C++ code.
     // Used to link Rust code with the upstream C++ code.
     let path: PathBuf = cpp_source.to_string_lossy().into_owned().into();
-    let mut cc_build = cxx_build::bridge("src/bgv/mod.rs");
+    // `testing.rs` holds a second bridge for the seeded PRNG. Its C++ side is
+    // always built; the Rust side only exists for tests and `test-rng`.
+    let mut cc_build = cxx_build::bridges(["src/bgv/mod.rs", "src/bgv/testing.rs"]);
     // Include the directory where cxx generates the cxxbridge sources.
     // This directory will contain the rust/cxx.h header.
     println!("cargo:include={}", ffi_dir.display());
@@ -1,4 +1,5 @@
 #include "ffi_wrapper.h"
+#include "zno-seal-sys/src/bgv/mod.rs.h"
 
 /**
  * This file contains the implementation of various functions related to the Foreign Function Interface (FFI) for the seal library.
@@ -55,30 +56,40 @@ namespace seal {
     return enc_params;
   }
 
-  uint8_t get_scheme(const std::unique_ptr<seal::EncryptionParameters>& enc_params) {
+  uint8_t get_scheme(const std::unique_ptr<seal::EncryptionParameters> enc_params) {
     // Assuming scheme() returns a scheme_type
     return static_cast<uint8_t>(enc_params->scheme());
   }
 
-  void set_scheme(std::unique_ptr<seal::EncryptionParameters>& enc_params, uint8_t scheme) {
-    // There's no set_scheme method, so we can't change the scheme of an existing EncryptionParameters object.
-    // We need to create a new object with the desired scheme.
-    enc_params = std::make_unique<seal::EncryptionParameters>(scheme);
-  }
-
   /**
    * Creates a new BGVContextBuilder object.
    *
    * @return A unique pointer to the newly created ContextBuilder<BGV> object.
    */
-  std::unique_ptr<BGVContextBuilder> init(std::unique_ptr<u_int8_t> schema) {
-      EncryptionParameters parms(*schema);
+  std::unique_ptr<BGVContextBuilder> init(u_int8_t schema) {
+      EncryptionParameters parms(schema);
       return std::make_unique<BGVContextBuilder>(parms);
   }
 
-  // std::unique_ptr<::seal::Context> build(std::unique_ptr<::seal::BGVContextBuilder> builder) {
-  //   return std::unique_ptr<seal::Context>(builder->buildPtr());
-  // }
+  /**
+   * Returns the scheme of the BGVContextBuilder object.
+   *
+   * @param builder The BGVContextBuilder object.
+   * @return The scheme as a SchemeType shared with Rust.
+   */
+  ::seal::SchemeType scheme_type(const ::seal::BGVContextBuilder& builder) {
+    return static_cast<::seal::SchemeType>(builder.scheme());
+  }
+
+  /**
+   * Builds a Context object from a BGVContextBuilder object.
+   *
+   * @param builder A unique pointer to the BGVContextBuilder object.
+   * @return A unique pointer to the newly created Context object.
+   */
+  std::unique_ptr<::seal::Context> build(std::unique_ptr<::seal::BGVContextBuilder> builder) {
+    return std::make_unique<::seal::Context>(*builder);
+  }
 
   /**
    * Sets the value of `m` in the BGVContextBuilder object.
@@ -92,6 +103,1702 @@ namespace seal {
     return builder; // Return the unique_ptr.
   }
 
+  /**
+   * Sets the degree of the polynomial modulus in the BGVContextBuilder object.
+   *
+   * @param builder The BGVContextBuilder object.
+   * @param degree The degree of the polynomial modulus.
+   * @return A unique_ptr to the modified BGVContextBuilder object.
+   */
+  std::unique_ptr<::seal::BGVContextBuilder> set_poly_modulus_degree(std::unique_ptr<::seal::BGVContextBuilder> builder, uint64_t degree) {
+    builder->set_poly_modulus_degree(degree);
+    return builder;
+  }
+
+  /**
+   * Sets the coefficient modulus in the BGVContextBuilder object.
+   *
+   * @param builder The BGVContextBuilder object.
+   * @param moduli The primes of the coefficient modulus.
+   * @return A unique_ptr to the modified BGVContextBuilder object.
+   */
+  std::unique_ptr<::seal::BGVContextBuilder> set_coeff_modulus(std::unique_ptr<::seal::BGVContextBuilder> builder, rust::Slice<const uint64_t> moduli) {
+    std::vector<::seal::Modulus> coeff_modulus;
+    coeff_modulus.reserve(moduli.size());
+    for (auto modulus : moduli) {
+      coeff_modulus.emplace_back(modulus);
+    }
+    builder->set_coeff_modulus(coeff_modulus);
+    return builder;
+  }
+
+  /**
+   * Sets the plaintext modulus in the BGVContextBuilder object.
+   *
+   * @param builder The BGVContextBuilder object.
+   * @param modulus The plaintext modulus.
+   * @return A unique_ptr to the modified BGVContextBuilder object.
+   */
+  std::unique_ptr<::seal::BGVContextBuilder> set_plain_modulus(std::unique_ptr<::seal::BGVContextBuilder> builder, uint64_t modulus) {
+    builder->set_plain_modulus(modulus);
+    return builder;
+  }
+
+  /**
+   * Installs a Blake2xb PRNG factory with a fixed seed. For tests only.
+   *
+   * @param builder The BGVContextBuilder object to modify.
+   * @param seed The 512-bit seed as 8 64-bit words.
+   * @return A unique_ptr to the modified BGVContextBuilder object.
+   */
+  std::unique_ptr<::seal::BGVContextBuilder> set_random_generator_seed(std::unique_ptr<::seal::BGVContextBuilder> builder, rust::Slice<const uint64_t> seed) {
+    if (seed.size() != ::seal::prng_seed_uint64_count) {
+      throw std::invalid_argument("seed must be 8 64-bit words");
+    }
+    ::seal::prng_seed_type prng_seed;
+    std::copy(seed.begin(), seed.end(), prng_seed.begin());
+    builder->set_random_generator(std::make_shared<::seal::Blake2xbPRNGFactory>(prng_seed));
+    return builder;
+  }
+
+  /**
+   * Copies the values of SEAL Modulus objects into a Rust vector.
+   *
+   * @param moduli The Modulus objects.
+   * @return The values of the moduli.
+   */
+  rust::Vec<uint64_t> to_rust_vec(const std::vector<::seal::Modulus>& moduli) {
+    rust::Vec<uint64_t> values;
+    values.reserve(moduli.size());
+    for (const auto& modulus : moduli) {
+      values.push_back(modulus.value());
+    }
+    return values;
+  }
+
+  /**
+   * Returns the primes of the default coefficient modulus for 128-bit security.
+   *
+   * @param poly_modulus_degree The degree of the polynomial modulus.
+   * @return The primes of the coefficient modulus.
+   */
+  rust::Vec<uint64_t> coeff_modulus_bfv_default(uint64_t poly_modulus_degree) {
+    return to_rust_vec(::seal::CoeffModulus::BFVDefault(poly_modulus_degree));
+  }
+
+  /**
+   * Returns primes suitable for a coefficient modulus, one per bit size.
+   *
+   * @param poly_modulus_degree The degree of the polynomial modulus.
+   * @param bit_sizes The bit size of each prime.
+   * @return The primes of the coefficient modulus.
+   */
+  rust::Vec<uint64_t> coeff_modulus_create(uint64_t poly_modulus_degree, rust::Slice<const int32_t> bit_sizes) {
+    std::vector<int> sizes(bit_sizes.begin(), bit_sizes.end());
+    return to_rust_vec(::seal::CoeffModulus::Create(poly_modulus_degree, sizes));
+  }
+
+  /**
+   * Returns a prime plaintext modulus that supports batching.
+   *
+   * @param poly_modulus_degree The degree of the polynomial modulus.
+   * @param bit_size The bit size of the prime.
+   * @return The plaintext modulus.
+   */
+  uint64_t plain_modulus_batching(uint64_t poly_modulus_degree, int32_t bit_size) {
+    return ::seal::PlainModulus::Batching(poly_modulus_degree, bit_size).value();
+  }
+
+  /**
+   * Creates a new KeyGenerator object for the given Context.
+   *
+   * @param context The Context the keys are generated for.
+   * @return A unique pointer to the newly created KeyGenerator object.
+   */
+  std::unique_ptr<::seal::KeyGenerator> new_key_generator(const ::seal::Context& context) {
+    return std::make_unique<::seal::KeyGenerator>(context);
+  }
+
+  /**
+   * Copies the secret key out of the KeyGenerator object.
+   *
+   * @param keygen The KeyGenerator object.
+   * @return A unique pointer to the copied SecretKey object.
+   */
+  std::unique_ptr<::seal::SecretKey> get_secret_key(const ::seal::KeyGenerator& keygen) {
+    return std::make_unique<::seal::SecretKey>(keygen.secret_key());
+  }
+
+  /**
+   * Creates a PublicKey object from the secret key held by the KeyGenerator.
+   *
+   * @param keygen The KeyGenerator object.
+   * @return A unique pointer to the newly created PublicKey object.
+   */
+  std::unique_ptr<::seal::PublicKey> create_public_key(const ::seal::KeyGenerator& keygen) {
+    auto public_key = std::make_unique<::seal::PublicKey>();
+    keygen.create_public_key(*public_key);
+    return public_key;
+  }
+
+  /**
+   * Creates a RelinKeys object from the secret key held by the KeyGenerator.
+   *
+   * @param keygen The KeyGenerator object.
+   * @return A unique pointer to the newly created RelinKeys object.
+   */
+  std::unique_ptr<::seal::RelinKeys> create_relin_keys(::seal::KeyGenerator& keygen) {
+    auto relin_keys = std::make_unique<::seal::RelinKeys>();
+    keygen.create_relin_keys(*relin_keys);
+    return relin_keys;
+  }
+
+  /**
+   * Creates a GaloisKeys object covering every rotation step.
+   *
+   * @param keygen The KeyGenerator object.
+   * @return A unique pointer to the newly created GaloisKeys object.
+   */
+  std::unique_ptr<::seal::GaloisKeys> create_galois_keys(::seal::KeyGenerator& keygen) {
+    auto galois_keys = std::make_unique<::seal::GaloisKeys>();
+    keygen.create_galois_keys(*galois_keys);
+    return galois_keys;
+  }
+
+  /**
+   * Creates a GaloisKeys object covering the given rotation steps.
+   *
+   * @param keygen The KeyGenerator object.
+   * @param steps The rotation steps keys are generated for.
+   * @return A unique pointer to the newly created GaloisKeys object.
+   */
+  std::unique_ptr<::seal::GaloisKeys> create_galois_keys_with_steps(::seal::KeyGenerator& keygen, rust::Slice<const int32_t> steps) {
+    std::vector<int> steps_vec(steps.begin(), steps.end());
+    auto galois_keys = std::make_unique<::seal::GaloisKeys>();
+    keygen.create_galois_keys(steps_vec, *galois_keys);
+    return galois_keys;
+  }
+
+  /**
+   * Creates a new, empty Plaintext object.
+   *
+   * @return A unique pointer to the newly created Plaintext object.
+   */
+  std::unique_ptr<::seal::Plaintext> new_plaintext() {
+    return std::make_unique<::seal::Plaintext>();
+  }
+
+  /**
+   * Creates a new Plaintext object from a polynomial in hexadecimal form.
+   *
+   * @param hex_poly The polynomial in hexadecimal form.
+   * @return A unique pointer to the newly created Plaintext object.
+   */
+  std::unique_ptr<::seal::Plaintext> new_plaintext_from_hex(rust::Str hex_poly) {
+    return std::make_unique<::seal::Plaintext>(std::string(hex_poly));
+  }
+
+  /**
+   * Returns the polynomial of the Plaintext object in hexadecimal form.
+   *
+   * @param plain The Plaintext object.
+   * @return The polynomial in hexadecimal form, or a placeholder for an NTT form (CKKS) plaintext.
+   */
+  rust::String plaintext_to_string(const ::seal::Plaintext& plain) {
+    if (plain.is_ntt_form()) {
+      return rust::String("<NTT form>");
+    }
+    return rust::String(plain.to_string());
+  }
+
+  /**
+   * Creates a new, empty Ciphertext object.
+   *
+   * @return A unique pointer to the newly created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> new_ciphertext() {
+    return std::make_unique<::seal::Ciphertext>();
+  }
+
+  /**
+   * Creates a new Encryptor object holding a public key.
+   *
+   * @param context The Context to encrypt for.
+   * @param public_key The PublicKey used to encrypt.
+   * @return A unique pointer to the newly created Encryptor object.
+   */
+  std::unique_ptr<::seal::Encryptor> new_encryptor(const ::seal::Context& context, const ::seal::PublicKey& public_key) {
+    return std::make_unique<::seal::Encryptor>(context, public_key);
+  }
+
+  /**
+   * Creates a new Encryptor object holding a secret key.
+   *
+   * @param context The Context to encrypt for.
+   * @param secret_key The SecretKey used to encrypt.
+   * @return A unique pointer to the newly created Encryptor object.
+   */
+  std::unique_ptr<::seal::Encryptor> new_symmetric_encryptor(const ::seal::Context& context, const ::seal::SecretKey& secret_key) {
+    return std::make_unique<::seal::Encryptor>(context, secret_key);
+  }
+
+  /**
+   * Encrypts a Plaintext object with the public key.
+   *
+   * @param encryptor The Encryptor object.
+   * @param plain The Plaintext object to encrypt.
+   * @param pool The MemoryPoolHandle to allocate from.
+   * @return A unique pointer to the newly created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> encrypt(const ::seal::Encryptor& encryptor, const ::seal::Plaintext& plain, const ::seal::MemoryPoolHandle& pool) {
+    auto encrypted = std::make_unique<::seal::Ciphertext>(pool);
+    encryptor.encrypt(plain, *encrypted, pool);
+    return encrypted;
+  }
+
+  /**
+   * Encrypts a zero plaintext with the public key.
+   *
+   * @param encryptor The Encryptor object.
+   * @param pool The MemoryPoolHandle to allocate from.
+   * @return A unique pointer to the newly created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> encrypt_zero(const ::seal::Encryptor& encryptor, const ::seal::MemoryPoolHandle& pool) {
+    auto encrypted = std::make_unique<::seal::Ciphertext>(pool);
+    encryptor.encrypt_zero(*encrypted, pool);
+    return encrypted;
+  }
+
+  /**
+   * Encrypts a Plaintext object with the secret key.
+   *
+   * @param encryptor The Encryptor object.
+   * @param plain The Plaintext object to encrypt.
+   * @param pool The MemoryPoolHandle to allocate from.
+   * @return A unique pointer to the newly created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> encrypt_symmetric(const ::seal::Encryptor& encryptor, const ::seal::Plaintext& plain, const ::seal::MemoryPoolHandle& pool) {
+    auto encrypted = std::make_unique<::seal::Ciphertext>(pool);
+    encryptor.encrypt_symmetric(plain, *encrypted, pool);
+    return encrypted;
+  }
+
+  /**
+   * Encrypts a zero plaintext with the secret key.
+   *
+   * @param encryptor The Encryptor object.
+   * @param pool The MemoryPoolHandle to allocate from.
+   * @return A unique pointer to the newly created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> encrypt_zero_symmetric(const ::seal::Encryptor& encryptor, const ::seal::MemoryPoolHandle& pool) {
+    auto encrypted = std::make_unique<::seal::Ciphertext>(pool);
+    encryptor.encrypt_zero_symmetric(*encrypted, pool);
+    return encrypted;
+  }
+
+  /**
+   * Creates a new Decryptor object.
+   *
+   * @param context The Context to decrypt for.
+   * @param secret_key The SecretKey used to decrypt.
+   * @return A unique pointer to the newly created Decryptor object.
+   */
+  std::unique_ptr<::seal::Decryptor> new_decryptor(const ::seal::Context& context, const ::seal::SecretKey& secret_key) {
+    return std::make_unique<::seal::Decryptor>(context, secret_key);
+  }
+
+  /**
+   * Decrypts a Ciphertext object.
+   *
+   * @param decryptor The Decryptor object.
+   * @param encrypted The Ciphertext object to decrypt.
+   * @return A unique pointer to the newly created Plaintext object.
+   */
+  std::unique_ptr<::seal::Plaintext> decrypt(::seal::Decryptor& decryptor, const ::seal::Ciphertext& encrypted) {
+    auto plain = std::make_unique<::seal::Plaintext>();
+    decryptor.decrypt(encrypted, *plain);
+    return plain;
+  }
+
+  /**
+   * Converts a ParmsId shared with Rust into a SEAL parms_id_type.
+   *
+   * @param parms_id The ParmsId to convert.
+   * @return The equivalent parms_id_type.
+   */
+  ::seal::parms_id_type to_parms_id_type(const ::seal::ParmsId& parms_id) {
+    return parms_id.value;
+  }
+
+  /**
+   * Converts a SEAL parms_id_type into a ParmsId shared with Rust.
+   *
+   * @param parms_id The parms_id_type to convert.
+   * @return The equivalent ParmsId.
+   */
+  ::seal::ParmsId from_parms_id_type(const ::seal::parms_id_type& parms_id) {
+    return ::seal::ParmsId{parms_id};
+  }
+
+  /**
+   * Returns the name of the error that invalidated the encryption parameters.
+   *
+   * @param context The Context object.
+   * @return The name of the error, "success" when the parameters are valid.
+   */
+  rust::String parameter_error_name(const ::seal::Context& context) {
+    return rust::String(context.parameter_error_name());
+  }
+
+  /**
+   * Returns a description of the error that invalidated the encryption parameters.
+   *
+   * @param context The Context object.
+   * @return The description of the error.
+   */
+  rust::String parameter_error_message(const ::seal::Context& context) {
+    return rust::String(context.parameter_error_message());
+  }
+
+  /**
+   * Returns the ContextData for the key level.
+   *
+   * SEAL hands out `shared_ptr<const ContextData>`. cxx has no const shared pointers,
+   * so constness is cast away here. Only const member functions are bound on the Rust side.
+   *
+   * @param context The Context object.
+   * @return The ContextData, or null.
+   */
+  std::shared_ptr<::seal::ContextData> key_context_data(const ::seal::Context& context) {
+    return std::const_pointer_cast<::seal::ContextData>(context.key_context_data());
+  }
+
+  /**
+   * Returns the ContextData for the first data level.
+   *
+   * @param context The Context object.
+   * @return The ContextData, or null.
+   */
+  std::shared_ptr<::seal::ContextData> first_context_data(const ::seal::Context& context) {
+    return std::const_pointer_cast<::seal::ContextData>(context.first_context_data());
+  }
+
+  /**
+   * Returns the ContextData for the last level.
+   *
+   * @param context The Context object.
+   * @return The ContextData, or null.
+   */
+  std::shared_ptr<::seal::ContextData> last_context_data(const ::seal::Context& context) {
+    return std::const_pointer_cast<::seal::ContextData>(context.last_context_data());
+  }
+
+  /**
+   * Returns the ContextData for the given parms_id.
+   *
+   * @param context The Context object.
+   * @param parms_id The parms_id of the level.
+   * @return The ContextData, or null.
+   */
+  std::shared_ptr<::seal::ContextData> get_context_data(const ::seal::Context& context, const ::seal::ParmsId& parms_id) {
+    return std::const_pointer_cast<::seal::ContextData>(context.get_context_data(to_parms_id_type(parms_id)));
+  }
+
+  /**
+   * Returns the parms_id of the key level.
+   *
+   * @param context The Context object.
+   * @return The parms_id of the key level.
+   */
+  ::seal::ParmsId key_parms_id(const ::seal::Context& context) {
+    return from_parms_id_type(context.key_parms_id());
+  }
+
+  /**
+   * Returns the parms_id of the first data level.
+   *
+   * @param context The Context object.
+   * @return The parms_id of the first data level.
+   */
+  ::seal::ParmsId first_parms_id(const ::seal::Context& context) {
+    return from_parms_id_type(context.first_parms_id());
+  }
+
+  /**
+   * Returns the parms_id of the last level.
+   *
+   * @param context The Context object.
+   * @return The parms_id of the last level.
+   */
+  ::seal::ParmsId last_parms_id(const ::seal::Context& context) {
+    return from_parms_id_type(context.last_parms_id());
+  }
+
+  /**
+   * Returns the ContextData of the next level down the chain.
+   *
+   * @param context_data The ContextData object.
+   * @return The next ContextData, or null.
+   */
+  std::shared_ptr<::seal::ContextData> next_context_data(const ::seal::ContextData& context_data) {
+    return std::const_pointer_cast<::seal::ContextData>(context_data.next_context_data());
+  }
+
+  /**
+   * Returns the ContextData of the previous level up the chain.
+   *
+   * @param context_data The ContextData object.
+   * @return The previous ContextData, or null.
+   */
+  std::shared_ptr<::seal::ContextData> prev_context_data(const ::seal::ContextData& context_data) {
+    return std::const_pointer_cast<::seal::ContextData>(context_data.prev_context_data());
+  }
+
+  /**
+   * Returns the parms_id of the ContextData.
+   *
+   * @param context_data The ContextData object.
+   * @return The parms_id of the level.
+   */
+  ::seal::ParmsId context_data_parms_id(const ::seal::ContextData& context_data) {
+    return from_parms_id_type(context_data.parms_id());
+  }
+
+  /**
+   * Returns the primes of the coefficient modulus of the ContextData.
+   *
+   * @param context_data The ContextData object.
+   * @return The primes of the coefficient modulus.
+   */
+  rust::Vec<uint64_t> context_data_coeff_modulus(const ::seal::ContextData& context_data) {
+    return to_rust_vec(context_data.parms().coeff_modulus());
+  }
+
+  /**
+   * Returns the degree of the polynomial modulus of the ContextData.
+   *
+   * @param context_data The ContextData object.
+   * @return The polynomial modulus degree.
+   */
+  uint64_t context_data_poly_modulus_degree(const ::seal::ContextData& context_data) {
+    return static_cast<uint64_t>(context_data.parms().poly_modulus_degree());
+  }
+
+  /**
+   * Returns the plaintext modulus of the ContextData.
+   *
+   * @param context_data The ContextData object.
+   * @return The plaintext modulus.
+   */
+  uint64_t context_data_plain_modulus(const ::seal::ContextData& context_data) {
+    return context_data.parms().plain_modulus().value();
+  }
+
+  /**
+   * Returns the scheme of the ContextData.
+   *
+   * @param context_data The ContextData object.
+   * @return The scheme as a SchemeType shared with Rust.
+   */
+  ::seal::SchemeType context_data_scheme(const ::seal::ContextData& context_data) {
+    return static_cast<::seal::SchemeType>(context_data.parms().scheme());
+  }
+
+  /**
+   * Returns the qualifiers of the encryption parameters of the ContextData.
+   *
+   * @param context_data The ContextData object.
+   * @return The qualifiers of the encryption parameters.
+   */
+  ::seal::ParameterQualifiers context_data_qualifiers(const ::seal::ContextData& context_data) {
+    auto qualifiers = context_data.qualifiers();
+    return ::seal::ParameterQualifiers{
+      qualifiers.parameters_set(),
+      qualifiers.using_fft,
+      qualifiers.using_ntt,
+      qualifiers.using_batching,
+      qualifiers.using_fast_plain_lift,
+      qualifiers.using_descending_modulus_chain,
+      static_cast<int32_t>(qualifiers.sec_level),
+    };
+  }
+
+  /**
+   * Returns the parms_id of the Ciphertext object.
+   *
+   * @param encrypted The Ciphertext object.
+   * @return The parms_id of the Ciphertext.
+   */
+  ::seal::ParmsId ciphertext_parms_id(const ::seal::Ciphertext& encrypted) {
+    return from_parms_id_type(encrypted.parms_id());
+  }
+
+  /**
+   * Returns the scale of the Ciphertext object.
+   *
+   * @param encrypted The Ciphertext object.
+   * @return The scale of the Ciphertext.
+   */
+  double ciphertext_scale(const ::seal::Ciphertext& encrypted) {
+    return encrypted.scale();
+  }
+
+  /**
+   * Sets the scale of the Ciphertext object.
+   *
+   * @param encrypted The Ciphertext object to modify.
+   * @param scale The new scale.
+   */
+  void set_ciphertext_scale(::seal::Ciphertext& encrypted, double scale) {
+    encrypted.scale() = scale;
+  }
+
+  /**
+   * Returns the scale of the Plaintext object.
+   *
+   * @param plain The Plaintext object.
+   * @return The scale of the Plaintext.
+   */
+  double plaintext_scale(const ::seal::Plaintext& plain) {
+    return plain.scale();
+  }
+
+  /**
+   * Creates a new Evaluator object.
+   *
+   * @param context The Context to evaluate in.
+   * @return A unique pointer to the newly created Evaluator object.
+   */
+  std::unique_ptr<::seal::Evaluator> new_evaluator(const ::seal::Context& context) {
+    return std::make_unique<::seal::Evaluator>(context);
+  }
+
+  /**
+   * Negates a ciphertext.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @return A unique pointer to the newly created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> negate(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted) {
+    auto destination = std::make_unique<::seal::Ciphertext>();
+    evaluator.negate(encrypted, *destination);
+    return destination;
+  }
+
+  void negate_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted) {
+    evaluator.negate_inplace(encrypted);
+  }
+
+  /**
+   * Adds two ciphertexts.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted1 The Ciphertext operand.
+   * @param encrypted2 The Ciphertext operand.
+   * @return A unique pointer to the newly created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> add(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted1, const ::seal::Ciphertext& encrypted2) {
+    auto destination = std::make_unique<::seal::Ciphertext>();
+    evaluator.add(encrypted1, encrypted2, *destination);
+    return destination;
+  }
+
+  void add_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted1, const ::seal::Ciphertext& encrypted2) {
+    evaluator.add_inplace(encrypted1, encrypted2);
+  }
+
+  /**
+   * Subtracts the second ciphertext from the first.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted1 The Ciphertext operand.
+   * @param encrypted2 The Ciphertext operand.
+   * @return A unique pointer to the newly created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> sub(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted1, const ::seal::Ciphertext& encrypted2) {
+    auto destination = std::make_unique<::seal::Ciphertext>();
+    evaluator.sub(encrypted1, encrypted2, *destination);
+    return destination;
+  }
+
+  void sub_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted1, const ::seal::Ciphertext& encrypted2) {
+    evaluator.sub_inplace(encrypted1, encrypted2);
+  }
+
+  /**
+   * Multiplies two ciphertexts.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted1 The Ciphertext operand.
+   * @param encrypted2 The Ciphertext operand.
+   * @param pool The MemoryPoolHandle to allocate from.
+   * @return A unique pointer to the newly created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> multiply(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted1, const ::seal::Ciphertext& encrypted2, const ::seal::MemoryPoolHandle& pool) {
+    auto destination = std::make_unique<::seal::Ciphertext>(pool);
+    evaluator.multiply(encrypted1, encrypted2, *destination, pool);
+    return destination;
+  }
+
+  void multiply_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted1, const ::seal::Ciphertext& encrypted2, const ::seal::MemoryPoolHandle& pool) {
+    evaluator.multiply_inplace(encrypted1, encrypted2, pool);
+  }
+
+  /**
+   * Squares a ciphertext.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param pool The MemoryPoolHandle to allocate from.
+   * @return A unique pointer to the newly created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> square(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, const ::seal::MemoryPoolHandle& pool) {
+    auto destination = std::make_unique<::seal::Ciphertext>(pool);
+    evaluator.square(encrypted, *destination, pool);
+    return destination;
+  }
+
+  void square_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, const ::seal::MemoryPoolHandle& pool) {
+    evaluator.square_inplace(encrypted, pool);
+  }
+
+  /**
+   * Adds a ciphertext and a plaintext.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param plain The Plaintext operand.
+   * @param pool The MemoryPoolHandle to allocate from.
+   * @return A unique pointer to the newly created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> add_plain(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, const ::seal::Plaintext& plain, const ::seal::MemoryPoolHandle& pool) {
+    auto destination = std::make_unique<::seal::Ciphertext>(pool);
+    evaluator.add_plain(encrypted, plain, *destination, pool);
+    return destination;
+  }
+
+  void add_plain_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, const ::seal::Plaintext& plain, const ::seal::MemoryPoolHandle& pool) {
+    evaluator.add_plain_inplace(encrypted, plain, pool);
+  }
+
+  /**
+   * Subtracts a plaintext from a ciphertext.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param plain The Plaintext operand.
+   * @param pool The MemoryPoolHandle to allocate from.
+   * @return A unique pointer to the newly created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> sub_plain(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, const ::seal::Plaintext& plain, const ::seal::MemoryPoolHandle& pool) {
+    auto destination = std::make_unique<::seal::Ciphertext>(pool);
+    evaluator.sub_plain(encrypted, plain, *destination, pool);
+    return destination;
+  }
+
+  void sub_plain_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, const ::seal::Plaintext& plain, const ::seal::MemoryPoolHandle& pool) {
+    evaluator.sub_plain_inplace(encrypted, plain, pool);
+  }
+
+  /**
+   * Multiplies a ciphertext with a plaintext.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param plain The Plaintext operand.
+   * @param pool The MemoryPoolHandle to allocate from.
+   * @return A unique pointer to the newly created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> multiply_plain(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, const ::seal::Plaintext& plain, const ::seal::MemoryPoolHandle& pool) {
+    auto destination = std::make_unique<::seal::Ciphertext>(pool);
+    evaluator.multiply_plain(encrypted, plain, *destination, pool);
+    return destination;
+  }
+
+  void multiply_plain_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, const ::seal::Plaintext& plain, const ::seal::MemoryPoolHandle& pool) {
+    evaluator.multiply_plain_inplace(encrypted, plain, pool);
+  }
+
+  /**
+   * Relinearizes a ciphertext, reducing its size to 2.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param relin_keys The RelinKeys used for key switching.
+   * @param pool The MemoryPoolHandle to allocate from.
+   * @return A unique pointer to the newly created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> relinearize(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, const ::seal::RelinKeys& relin_keys, const ::seal::MemoryPoolHandle& pool) {
+    auto destination = std::make_unique<::seal::Ciphertext>(pool);
+    evaluator.relinearize(encrypted, relin_keys, *destination, pool);
+    return destination;
+  }
+
+  void relinearize_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, const ::seal::RelinKeys& relin_keys, const ::seal::MemoryPoolHandle& pool) {
+    evaluator.relinearize_inplace(encrypted, relin_keys, pool);
+  }
+
+  /**
+   * Switches a ciphertext down to the next modulus in the modulus switching chain.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param pool The MemoryPoolHandle to allocate from.
+   * @return A unique pointer to the newly created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> mod_switch_to_next(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, const ::seal::MemoryPoolHandle& pool) {
+    auto destination = std::make_unique<::seal::Ciphertext>(pool);
+    evaluator.mod_switch_to_next(encrypted, *destination, pool);
+    return destination;
+  }
+
+  void mod_switch_to_next_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, const ::seal::MemoryPoolHandle& pool) {
+    evaluator.mod_switch_to_next_inplace(encrypted, pool);
+  }
+
+  /**
+   * Switches a ciphertext down to the modulus given by parms_id.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param parms_id The value of `parms_id`.
+   * @param pool The MemoryPoolHandle to allocate from.
+   * @return A unique pointer to the newly created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> mod_switch_to(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, const ::seal::ParmsId& parms_id, const ::seal::MemoryPoolHandle& pool) {
+    auto destination = std::make_unique<::seal::Ciphertext>(pool);
+    evaluator.mod_switch_to(encrypted, to_parms_id_type(parms_id), *destination, pool);
+    return destination;
+  }
+
+  void mod_switch_to_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, const ::seal::ParmsId& parms_id, const ::seal::MemoryPoolHandle& pool) {
+    evaluator.mod_switch_to_inplace(encrypted, to_parms_id_type(parms_id), pool);
+  }
+
+  /**
+   * Switches a CKKS ciphertext down to the next modulus and rescales it.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param pool The MemoryPoolHandle to allocate from.
+   * @return A unique pointer to the newly created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> rescale_to_next(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, const ::seal::MemoryPoolHandle& pool) {
+    auto destination = std::make_unique<::seal::Ciphertext>(pool);
+    evaluator.rescale_to_next(encrypted, *destination, pool);
+    return destination;
+  }
+
+  void rescale_to_next_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, const ::seal::MemoryPoolHandle& pool) {
+    evaluator.rescale_to_next_inplace(encrypted, pool);
+  }
+
+  /**
+   * Cyclically rotates the rows of a batched ciphertext by the given number of steps.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param steps The value of `steps`.
+   * @param galois_keys The GaloisKeys used for key switching.
+   * @param pool The MemoryPoolHandle to allocate from.
+   * @return A unique pointer to the newly created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> rotate_rows(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, int steps, const ::seal::GaloisKeys& galois_keys, const ::seal::MemoryPoolHandle& pool) {
+    auto destination = std::make_unique<::seal::Ciphertext>(pool);
+    evaluator.rotate_rows(encrypted, steps, galois_keys, *destination, pool);
+    return destination;
+  }
+
+  void rotate_rows_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, int steps, const ::seal::GaloisKeys& galois_keys, const ::seal::MemoryPoolHandle& pool) {
+    evaluator.rotate_rows_inplace(encrypted, steps, galois_keys, pool);
+  }
+
+  /**
+   * Swaps the two rows (rotates the columns) of a batched ciphertext.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param galois_keys The GaloisKeys used for key switching.
+   * @param pool The MemoryPoolHandle to allocate from.
+   * @return A unique pointer to the newly created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> rotate_columns(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, const ::seal::GaloisKeys& galois_keys, const ::seal::MemoryPoolHandle& pool) {
+    auto destination = std::make_unique<::seal::Ciphertext>(pool);
+    evaluator.rotate_columns(encrypted, galois_keys, *destination, pool);
+    return destination;
+  }
+
+  void rotate_columns_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, const ::seal::GaloisKeys& galois_keys, const ::seal::MemoryPoolHandle& pool) {
+    evaluator.rotate_columns_inplace(encrypted, galois_keys, pool);
+  }
+
+  /**
+   * Applies the Galois automorphism given by galois_elt to a ciphertext.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param galois_elt The value of `galois_elt`.
+   * @param galois_keys The GaloisKeys used for key switching.
+   * @param pool The MemoryPoolHandle to allocate from.
+   * @return A unique pointer to the newly created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> apply_galois(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, uint32_t galois_elt, const ::seal::GaloisKeys& galois_keys, const ::seal::MemoryPoolHandle& pool) {
+    auto destination = std::make_unique<::seal::Ciphertext>(pool);
+    evaluator.apply_galois(encrypted, galois_elt, galois_keys, *destination, pool);
+    return destination;
+  }
+
+  void apply_galois_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, uint32_t galois_elt, const ::seal::GaloisKeys& galois_keys, const ::seal::MemoryPoolHandle& pool) {
+    evaluator.apply_galois_inplace(encrypted, galois_elt, galois_keys, pool);
+  }
+
+  /**
+   * Raises a ciphertext to the given power, relinearizing after every multiplication.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param exponent The value of `exponent`.
+   * @param relin_keys The RelinKeys used for key switching.
+   * @param pool The MemoryPoolHandle to allocate from.
+   * @return A unique pointer to the newly created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> exponentiate(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, uint64_t exponent, const ::seal::RelinKeys& relin_keys, const ::seal::MemoryPoolHandle& pool) {
+    auto destination = std::make_unique<::seal::Ciphertext>(pool);
+    evaluator.exponentiate(encrypted, exponent, relin_keys, *destination, pool);
+    return destination;
+  }
+
+  void exponentiate_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, uint64_t exponent, const ::seal::RelinKeys& relin_keys, const ::seal::MemoryPoolHandle& pool) {
+    evaluator.exponentiate_inplace(encrypted, exponent, relin_keys, pool);
+  }
+
+  /**
+   * Creates a new BatchEncoder object.
+   *
+   * @param context The Context to encode for.
+   * @return A unique pointer to the newly created BatchEncoder object.
+   */
+  std::unique_ptr<::seal::BatchEncoder> new_batch_encoder(const ::seal::Context& context) {
+    return std::make_unique<::seal::BatchEncoder>(context);
+  }
+
+  /**
+   * Encodes unsigned integers into the slots of a new Plaintext object.
+   *
+   * @param encoder The BatchEncoder object.
+   * @param values The values to encode.
+   * @return A unique pointer to the encoded Plaintext object.
+   */
+  std::unique_ptr<::seal::Plaintext> encode_u64(const ::seal::BatchEncoder& encoder, rust::Slice<const uint64_t> values) {
+    auto plain = std::make_unique<::seal::Plaintext>();
+    std::vector<uint64_t> matrix(values.begin(), values.end());
+    encoder.encode(matrix, *plain);
+    return plain;
+  }
+
+  /**
+   * Encodes signed integers into the slots of a new Plaintext object.
+   *
+   * @param encoder The BatchEncoder object.
+   * @param values The values to encode.
+   * @return A unique pointer to the encoded Plaintext object.
+   */
+  std::unique_ptr<::seal::Plaintext> encode_i64(const ::seal::BatchEncoder& encoder, rust::Slice<const int64_t> values) {
+    auto plain = std::make_unique<::seal::Plaintext>();
+    std::vector<int64_t> matrix(values.begin(), values.end());
+    encoder.encode(matrix, *plain);
+    return plain;
+  }
+
+  /**
+   * Decodes the slots of a Plaintext object into unsigned integers.
+   *
+   * @param encoder The BatchEncoder object.
+   * @param plain The Plaintext to decode.
+   * @return The decoded values.
+   */
+  rust::Vec<uint64_t> decode_u64(const ::seal::BatchEncoder& encoder, const ::seal::Plaintext& plain) {
+    std::vector<uint64_t> matrix;
+    encoder.decode(plain, matrix);
+    rust::Vec<uint64_t> values;
+    values.reserve(matrix.size());
+    for (auto value : matrix) {
+      values.push_back(value);
+    }
+    return values;
+  }
+
+  /**
+   * Decodes the slots of a Plaintext object into signed integers.
+   *
+   * @param encoder The BatchEncoder object.
+   * @param plain The Plaintext to decode.
+   * @return The decoded values.
+   */
+  rust::Vec<int64_t> decode_i64(const ::seal::BatchEncoder& encoder, const ::seal::Plaintext& plain) {
+    std::vector<int64_t> matrix;
+    encoder.decode(plain, matrix);
+    rust::Vec<int64_t> values;
+    values.reserve(matrix.size());
+    for (auto value : matrix) {
+      values.push_back(value);
+    }
+    return values;
+  }
+
+  /**
+   * Creates a new CKKSEncoder object.
+   *
+   * @param context The Context to encode for.
+   * @return A unique pointer to the newly created CKKSEncoder object.
+   */
+  std::unique_ptr<::seal::CKKSEncoder> new_ckks_encoder(const ::seal::Context& context) {
+    return std::make_unique<::seal::CKKSEncoder>(context);
+  }
+
+  /**
+   * Encodes real numbers into the slots of a new Plaintext object.
+   *
+   * @param encoder The CKKSEncoder object.
+   * @param values The values to encode.
+   * @param parms_id The level to encode at.
+   * @param scale The scale to encode at.
+   * @return A unique pointer to the encoded Plaintext object.
+   */
+  std::unique_ptr<::seal::Plaintext> ckks_encode_f64(const ::seal::CKKSEncoder& encoder, rust::Slice<const double> values, const ::seal::ParmsId& parms_id, double scale) {
+    auto plain = std::make_unique<::seal::Plaintext>();
+    std::vector<double> slots(values.begin(), values.end());
+    encoder.encode(slots, to_parms_id_type(parms_id), scale, *plain);
+    return plain;
+  }
+
+  /**
+   * Encodes complex numbers into the slots of a new Plaintext object.
+   *
+   * @param encoder The CKKSEncoder object.
+   * @param values The values to encode, as interleaved real and imaginary parts.
+   * @param parms_id The level to encode at.
+   * @param scale The scale to encode at.
+   * @return A unique pointer to the encoded Plaintext object.
+   */
+  std::unique_ptr<::seal::Plaintext> ckks_encode_complex(const ::seal::CKKSEncoder& encoder, rust::Slice<const double> values, const ::seal::ParmsId& parms_id, double scale) {
+    if (values.size() % 2 != 0) {
+      throw std::invalid_argument("values must hold pairs of real and imaginary parts");
+    }
+    std::vector<std::complex<double>> slots;
+    slots.reserve(values.size() / 2);
+    for (std::size_t i = 0; i < values.size(); i += 2) {
+      slots.emplace_back(values[i], values[i + 1]);
+    }
+    auto plain = std::make_unique<::seal::Plaintext>();
+    encoder.encode(slots, to_parms_id_type(parms_id), scale, *plain);
+    return plain;
+  }
+
+  /**
+   * Decodes the slots of a Plaintext object into real numbers.
+   *
+   * @param encoder The CKKSEncoder object.
+   * @param plain The Plaintext to decode.
+   * @return The decoded values.
+   */
+  rust::Vec<double> ckks_decode_f64(const ::seal::CKKSEncoder& encoder, const ::seal::Plaintext& plain) {
+    std::vector<double> slots;
+    encoder.decode(plain, slots);
+    rust::Vec<double> values;
+    values.reserve(slots.size());
+    for (auto value : slots) {
+      values.push_back(value);
+    }
+    return values;
+  }
+
+  /**
+   * Decodes the slots of a Plaintext object into complex numbers.
+   *
+   * @param encoder The CKKSEncoder object.
+   * @param plain The Plaintext to decode.
+   * @return The decoded values, as interleaved real and imaginary parts.
+   */
+  rust::Vec<double> ckks_decode_complex(const ::seal::CKKSEncoder& encoder, const ::seal::Plaintext& plain) {
+    std::vector<std::complex<double>> slots;
+    encoder.decode(plain, slots);
+    rust::Vec<double> values;
+    values.reserve(2 * slots.size());
+    for (const auto& value : slots) {
+      values.push_back(value.real());
+      values.push_back(value.imag());
+    }
+    return values;
+  }
+
+  /**
+   * Converts a ComprMode shared with Rust into a SEAL compr_mode_type.
+   *
+   * @param compr_mode The ComprMode to convert.
+   * @return The equivalent compr_mode_type.
+   */
+  ::seal::compr_mode_type to_compr_mode_type(::seal::ComprMode compr_mode) {
+    return static_cast<::seal::compr_mode_type>(compr_mode);
+  }
+
+  /**
+   * Serializes a SEAL object into a Rust vector of bytes.
+   *
+   * @param obj The object to serialize, any type with SEAL's save member functions.
+   * @param compr_mode The compression mode.
+   * @return The serialized bytes.
+   */
+  template <typename T>
+  rust::Vec<uint8_t> save_to_vec(const T& obj, ::seal::ComprMode compr_mode) {
+    auto mode = to_compr_mode_type(compr_mode);
+    std::vector<::seal::seal_byte> buffer(static_cast<std::size_t>(obj.save_size(mode)));
+    auto size = obj.save(buffer.data(), buffer.size(), mode);
+    rust::Vec<uint8_t> bytes;
+    bytes.reserve(static_cast<std::size_t>(size));
+    for (std::size_t i = 0; i < static_cast<std::size_t>(size); i++) {
+      bytes.push_back(static_cast<uint8_t>(buffer[i]));
+    }
+    return bytes;
+  }
+
+  /**
+   * Returns an upper bound on the size of the serialized Ciphertext object.
+   *
+   * @param obj The Ciphertext object.
+   * @param compr_mode The compression mode.
+   * @return The upper bound, in bytes.
+   */
+  int64_t save_size_ciphertext(const ::seal::Ciphertext& obj, ::seal::ComprMode compr_mode) {
+    return obj.save_size(to_compr_mode_type(compr_mode));
+  }
+
+  /**
+   * Serializes the Ciphertext object.
+   *
+   * @param obj The Ciphertext object.
+   * @param compr_mode The compression mode.
+   * @return The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_ciphertext(const ::seal::Ciphertext& obj, ::seal::ComprMode compr_mode) {
+    return save_to_vec(obj, compr_mode);
+  }
+
+  /**
+   * Deserializes a Ciphertext object without checking its data is valid for the Context.
+   *
+   * @param context The Context the Ciphertext belongs to.
+   * @param bytes The serialized bytes.
+   * @return A unique pointer to the loaded Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> load_ciphertext(const ::seal::Context& context, rust::Slice<const uint8_t> bytes) {
+    auto obj = std::make_unique<::seal::Ciphertext>();
+    obj->unsafe_load(context, reinterpret_cast<const ::seal::seal_byte *>(bytes.data()), bytes.size());
+    return obj;
+  }
+
+  /**
+   * Returns an upper bound on the size of the serialized Plaintext object.
+   *
+   * @param obj The Plaintext object.
+   * @param compr_mode The compression mode.
+   * @return The upper bound, in bytes.
+   */
+  int64_t save_size_plaintext(const ::seal::Plaintext& obj, ::seal::ComprMode compr_mode) {
+    return obj.save_size(to_compr_mode_type(compr_mode));
+  }
+
+  /**
+   * Serializes the Plaintext object.
+   *
+   * @param obj The Plaintext object.
+   * @param compr_mode The compression mode.
+   * @return The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_plaintext(const ::seal::Plaintext& obj, ::seal::ComprMode compr_mode) {
+    return save_to_vec(obj, compr_mode);
+  }
+
+  /**
+   * Deserializes a Plaintext object without checking its data is valid for the Context.
+   *
+   * @param context The Context the Plaintext belongs to.
+   * @param bytes The serialized bytes.
+   * @return A unique pointer to the loaded Plaintext object.
+   */
+  std::unique_ptr<::seal::Plaintext> load_plaintext(const ::seal::Context& context, rust::Slice<const uint8_t> bytes) {
+    auto obj = std::make_unique<::seal::Plaintext>();
+    obj->unsafe_load(context, reinterpret_cast<const ::seal::seal_byte *>(bytes.data()), bytes.size());
+    return obj;
+  }
+
+  /**
+   * Returns an upper bound on the size of the serialized SecretKey object.
+   *
+   * @param obj The SecretKey object.
+   * @param compr_mode The compression mode.
+   * @return The upper bound, in bytes.
+   */
+  int64_t save_size_secret_key(const ::seal::SecretKey& obj, ::seal::ComprMode compr_mode) {
+    return obj.save_size(to_compr_mode_type(compr_mode));
+  }
+
+  /**
+   * Serializes the SecretKey object.
+   *
+   * @param obj The SecretKey object.
+   * @param compr_mode The compression mode.
+   * @return The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_secret_key(const ::seal::SecretKey& obj, ::seal::ComprMode compr_mode) {
+    return save_to_vec(obj, compr_mode);
+  }
+
+  /**
+   * Deserializes a SecretKey object without checking its data is valid for the Context.
+   *
+   * @param context The Context the SecretKey belongs to.
+   * @param bytes The serialized bytes.
+   * @return A unique pointer to the loaded SecretKey object.
+   */
+  std::unique_ptr<::seal::SecretKey> load_secret_key(const ::seal::Context& context, rust::Slice<const uint8_t> bytes) {
+    auto obj = std::make_unique<::seal::SecretKey>();
+    obj->unsafe_load(context, reinterpret_cast<const ::seal::seal_byte *>(bytes.data()), bytes.size());
+    return obj;
+  }
+
+  /**
+   * Returns an upper bound on the size of the serialized PublicKey object.
+   *
+   * @param obj The PublicKey object.
+   * @param compr_mode The compression mode.
+   * @return The upper bound, in bytes.
+   */
+  int64_t save_size_public_key(const ::seal::PublicKey& obj, ::seal::ComprMode compr_mode) {
+    return obj.save_size(to_compr_mode_type(compr_mode));
+  }
+
+  /**
+   * Serializes the PublicKey object.
+   *
+   * @param obj The PublicKey object.
+   * @param compr_mode The compression mode.
+   * @return The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_public_key(const ::seal::PublicKey& obj, ::seal::ComprMode compr_mode) {
+    return save_to_vec(obj, compr_mode);
+  }
+
+  /**
+   * Deserializes a PublicKey object without checking its data is valid for the Context.
+   *
+   * @param context The Context the PublicKey belongs to.
+   * @param bytes The serialized bytes.
+   * @return A unique pointer to the loaded PublicKey object.
+   */
+  std::unique_ptr<::seal::PublicKey> load_public_key(const ::seal::Context& context, rust::Slice<const uint8_t> bytes) {
+    auto obj = std::make_unique<::seal::PublicKey>();
+    obj->unsafe_load(context, reinterpret_cast<const ::seal::seal_byte *>(bytes.data()), bytes.size());
+    return obj;
+  }
+
+  /**
+   * Returns an upper bound on the size of the serialized RelinKeys object.
+   *
+   * @param obj The RelinKeys object.
+   * @param compr_mode The compression mode.
+   * @return The upper bound, in bytes.
+   */
+  int64_t save_size_relin_keys(const ::seal::RelinKeys& obj, ::seal::ComprMode compr_mode) {
+    return obj.save_size(to_compr_mode_type(compr_mode));
+  }
+
+  /**
+   * Serializes the RelinKeys object.
+   *
+   * @param obj The RelinKeys object.
+   * @param compr_mode The compression mode.
+   * @return The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_relin_keys(const ::seal::RelinKeys& obj, ::seal::ComprMode compr_mode) {
+    return save_to_vec(obj, compr_mode);
+  }
+
+  /**
+   * Deserializes a RelinKeys object without checking its data is valid for the Context.
+   *
+   * @param context The Context the RelinKeys belongs to.
+   * @param bytes The serialized bytes.
+   * @return A unique pointer to the loaded RelinKeys object.
+   */
+  std::unique_ptr<::seal::RelinKeys> load_relin_keys(const ::seal::Context& context, rust::Slice<const uint8_t> bytes) {
+    auto obj = std::make_unique<::seal::RelinKeys>();
+    obj->unsafe_load(context, reinterpret_cast<const ::seal::seal_byte *>(bytes.data()), bytes.size());
+    return obj;
+  }
+
+  /**
+   * Returns an upper bound on the size of the serialized GaloisKeys object.
+   *
+   * @param obj The GaloisKeys object.
+   * @param compr_mode The compression mode.
+   * @return The upper bound, in bytes.
+   */
+  int64_t save_size_galois_keys(const ::seal::GaloisKeys& obj, ::seal::ComprMode compr_mode) {
+    return obj.save_size(to_compr_mode_type(compr_mode));
+  }
+
+  /**
+   * Serializes the GaloisKeys object.
+   *
+   * @param obj The GaloisKeys object.
+   * @param compr_mode The compression mode.
+   * @return The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_galois_keys(const ::seal::GaloisKeys& obj, ::seal::ComprMode compr_mode) {
+    return save_to_vec(obj, compr_mode);
+  }
+
+  /**
+   * Deserializes a GaloisKeys object without checking its data is valid for the Context.
+   *
+   * @param context The Context the GaloisKeys belongs to.
+   * @param bytes The serialized bytes.
+   * @return A unique pointer to the loaded GaloisKeys object.
+   */
+  std::unique_ptr<::seal::GaloisKeys> load_galois_keys(const ::seal::Context& context, rust::Slice<const uint8_t> bytes) {
+    auto obj = std::make_unique<::seal::GaloisKeys>();
+    obj->unsafe_load(context, reinterpret_cast<const ::seal::seal_byte *>(bytes.data()), bytes.size());
+    return obj;
+  }
+
+  /**
+   * Returns an upper bound on the size of the serialized EncryptionParameters object.
+   *
+   * @param obj The EncryptionParameters object.
+   * @param compr_mode The compression mode.
+   * @return The upper bound, in bytes.
+   */
+  int64_t save_size_encryption_parameters(const ::seal::BGVContextBuilder& obj, ::seal::ComprMode compr_mode) {
+    return obj.save_size(to_compr_mode_type(compr_mode));
+  }
+
+  /**
+   * Serializes the EncryptionParameters object.
+   *
+   * @param obj The EncryptionParameters object.
+   * @param compr_mode The compression mode.
+   * @return The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_encryption_parameters(const ::seal::BGVContextBuilder& obj, ::seal::ComprMode compr_mode) {
+    return save_to_vec(obj, compr_mode);
+  }
+
+  /**
+   * Deserializes an EncryptionParameters object.
+   *
+   * @param bytes The serialized bytes.
+   * @return A unique pointer to the loaded EncryptionParameters object.
+   */
+  std::unique_ptr<::seal::BGVContextBuilder> load_encryption_parameters(rust::Slice<const uint8_t> bytes) {
+    auto obj = std::make_unique<::seal::BGVContextBuilder>();
+    obj->load(reinterpret_cast<const ::seal::seal_byte *>(bytes.data()), bytes.size());
+    return obj;
+  }
+
+  /**
+   * Checks whether serialized bytes hold an object whose parms_id the Context does not know.
+   *
+   * @param context The Context the object is loaded into.
+   * @param bytes The serialized bytes.
+   * @return True if the parms_id was read and is not in the Context.
+   */
+  bool is_foreign_parms_id(const ::seal::Context& context, rust::Slice<const uint8_t> bytes) {
+    ::seal::parms_id_type parms_id = ::seal::parms_id_zero;
+    bool read = false;
+    try {
+      ::seal::Serialization::Load(
+          [&](std::istream& stream, ::seal::SEALVersion) {
+            stream.read(reinterpret_cast<char *>(&parms_id), sizeof(::seal::parms_id_type));
+            read = true;
+          },
+          reinterpret_cast<const ::seal::seal_byte *>(bytes.data()), bytes.size(), false);
+    } catch (const std::exception&) {
+      // Uncompressed bytes fail the size check once the rest is left unread, after parms_id is read.
+    }
+    return read && !context.get_context_data(parms_id);
+  }
+
+  /**
+   * Creates a new Serializable<PublicKey> object.
+   *
+   * @param keygen The KeyGenerator object.
+   * @return A unique pointer to the Serializable<PublicKey> object.
+   */
+  std::unique_ptr<::seal::SerializablePublicKey> create_serializable_public_key(const ::seal::KeyGenerator& keygen) {
+    return std::make_unique<::seal::SerializablePublicKey>(keygen.create_public_key());
+  }
+
+  /**
+   * Creates a new Serializable<RelinKeys> object.
+   *
+   * @param keygen The KeyGenerator object.
+   * @return A unique pointer to the Serializable<RelinKeys> object.
+   */
+  std::unique_ptr<::seal::SerializableRelinKeys> create_serializable_relin_keys(::seal::KeyGenerator& keygen) {
+    return std::make_unique<::seal::SerializableRelinKeys>(keygen.create_relin_keys());
+  }
+
+  /**
+   * Creates a new Serializable<GaloisKeys> object.
+   *
+   * @param keygen The KeyGenerator object.
+   * @return A unique pointer to the Serializable<GaloisKeys> object.
+   */
+  std::unique_ptr<::seal::SerializableGaloisKeys> create_serializable_galois_keys(::seal::KeyGenerator& keygen) {
+    return std::make_unique<::seal::SerializableGaloisKeys>(keygen.create_galois_keys());
+  }
+
+  /**
+   * Encrypts a Plaintext object with the secret key into a Serializable<Ciphertext> object.
+   *
+   * @param encryptor The Encryptor object.
+   * @param plain The Plaintext to encrypt.
+   * @return A unique pointer to the Serializable<Ciphertext> object.
+   */
+  std::unique_ptr<::seal::SerializableCiphertext> encrypt_symmetric_serializable(const ::seal::Encryptor& encryptor, const ::seal::Plaintext& plain) {
+    return std::make_unique<::seal::SerializableCiphertext>(encryptor.encrypt_symmetric(plain));
+  }
+
+  /**
+   * Encrypts a zero plaintext with the secret key into a Serializable<Ciphertext> object.
+   *
+   * @param encryptor The Encryptor object.
+   * @return A unique pointer to the Serializable<Ciphertext> object.
+   */
+  std::unique_ptr<::seal::SerializableCiphertext> encrypt_zero_symmetric_serializable(const ::seal::Encryptor& encryptor) {
+    return std::make_unique<::seal::SerializableCiphertext>(encryptor.encrypt_zero_symmetric());
+  }
+
+  /**
+   * Returns an upper bound on the size of the serialized Serializable<PublicKey> object.
+   *
+   * @param obj The Serializable<PublicKey> object.
+   * @param compr_mode The compression mode.
+   * @return The upper bound, in bytes.
+   */
+  int64_t save_size_serializable_public_key(const ::seal::SerializablePublicKey& obj, ::seal::ComprMode compr_mode) {
+    return obj.save_size(to_compr_mode_type(compr_mode));
+  }
+
+  /**
+   * Serializes the Serializable<PublicKey> object.
+   *
+   * @param obj The Serializable<PublicKey> object.
+   * @param compr_mode The compression mode.
+   * @return The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_serializable_public_key(const ::seal::SerializablePublicKey& obj, ::seal::ComprMode compr_mode) {
+    return save_to_vec(obj, compr_mode);
+  }
+
+  /**
+   * Returns an upper bound on the size of the serialized Serializable<RelinKeys> object.
+   *
+   * @param obj The Serializable<RelinKeys> object.
+   * @param compr_mode The compression mode.
+   * @return The upper bound, in bytes.
+   */
+  int64_t save_size_serializable_relin_keys(const ::seal::SerializableRelinKeys& obj, ::seal::ComprMode compr_mode) {
+    return obj.save_size(to_compr_mode_type(compr_mode));
+  }
+
+  /**
+   * Serializes the Serializable<RelinKeys> object.
+   *
+   * @param obj The Serializable<RelinKeys> object.
+   * @param compr_mode The compression mode.
+   * @return The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_serializable_relin_keys(const ::seal::SerializableRelinKeys& obj, ::seal::ComprMode compr_mode) {
+    return save_to_vec(obj, compr_mode);
+  }
+
+  /**
+   * Returns an upper bound on the size of the serialized Serializable<GaloisKeys> object.
+   *
+   * @param obj The Serializable<GaloisKeys> object.
+   * @param compr_mode The compression mode.
+   * @return The upper bound, in bytes.
+   */
+  int64_t save_size_serializable_galois_keys(const ::seal::SerializableGaloisKeys& obj, ::seal::ComprMode compr_mode) {
+    return obj.save_size(to_compr_mode_type(compr_mode));
+  }
+
+  /**
+   * Serializes the Serializable<GaloisKeys> object.
+   *
+   * @param obj The Serializable<GaloisKeys> object.
+   * @param compr_mode The compression mode.
+   * @return The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_serializable_galois_keys(const ::seal::SerializableGaloisKeys& obj, ::seal::ComprMode compr_mode) {
+    return save_to_vec(obj, compr_mode);
+  }
+
+  /**
+   * Returns an upper bound on the size of the serialized Serializable<Ciphertext> object.
+   *
+   * @param obj The Serializable<Ciphertext> object.
+   * @param compr_mode The compression mode.
+   * @return The upper bound, in bytes.
+   */
+  int64_t save_size_serializable_ciphertext(const ::seal::SerializableCiphertext& obj, ::seal::ComprMode compr_mode) {
+    return obj.save_size(to_compr_mode_type(compr_mode));
+  }
+
+  /**
+   * Serializes the Serializable<Ciphertext> object.
+   *
+   * @param obj The Serializable<Ciphertext> object.
+   * @param compr_mode The compression mode.
+   * @return The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_serializable_ciphertext(const ::seal::SerializableCiphertext& obj, ::seal::ComprMode compr_mode) {
+    return save_to_vec(obj, compr_mode);
+  }
+
+  /**
+   * Returns a handle to the global memory pool.
+   *
+   * @return A unique pointer to the MemoryPoolHandle object.
+   */
+  std::unique_ptr<::seal::MemoryPoolHandle> memory_pool_global() {
+    return std::make_unique<::seal::MemoryPoolHandle>(::seal::MemoryPoolHandle::Global());
+  }
+
+  /**
+   * Returns a handle to the thread-local memory pool.
+   *
+   * @return A unique pointer to the MemoryPoolHandle object.
+   */
+  std::unique_ptr<::seal::MemoryPoolHandle> memory_pool_thread_local() {
+    return std::make_unique<::seal::MemoryPoolHandle>(::seal::MemoryPoolHandle::ThreadLocal());
+  }
+
+  /**
+   * Creates a new memory pool.
+   *
+   * @param clear_on_destruction Whether to zero the memory before it is freed.
+   * @return A unique pointer to the MemoryPoolHandle object.
+   */
+  std::unique_ptr<::seal::MemoryPoolHandle> memory_pool_new(bool clear_on_destruction) {
+    return std::make_unique<::seal::MemoryPoolHandle>(::seal::MemoryPoolHandle::New(clear_on_destruction));
+  }
+
+  /**
+   * Copies a MemoryPoolHandle; the copy points to the same pool.
+   *
+   * @param pool The MemoryPoolHandle to copy.
+   * @return A unique pointer to the MemoryPoolHandle object.
+   */
+  std::unique_ptr<::seal::MemoryPoolHandle> memory_pool_clone(const ::seal::MemoryPoolHandle& pool) {
+    return std::make_unique<::seal::MemoryPoolHandle>(pool);
+  }
+
+  /**
+   * Checks that a Ciphertext is valid for the Context.
+   *
+   * @param obj The Ciphertext object.
+   * @param context The Context to check against.
+   * @return True if the Ciphertext is valid.
+   */
+  bool is_valid_for_ciphertext(const ::seal::Ciphertext& obj, const ::seal::Context& context) {
+    return ::seal::is_valid_for(obj, context);
+  }
+
+  /**
+   * Checks that the metadata of a Ciphertext matches the Context.
+   *
+   * @param obj The Ciphertext object.
+   * @param context The Context to check against.
+   * @return True if the metadata is valid.
+   */
+  bool is_metadata_valid_for_ciphertext(const ::seal::Ciphertext& obj, const ::seal::Context& context) {
+    return ::seal::is_metadata_valid_for(obj, context);
+  }
+
+  /**
+   * Checks that the buffer of a Ciphertext has the size its metadata claims.
+   *
+   * @param obj The Ciphertext object.
+   * @return True if the buffer size is consistent.
+   */
+  bool is_buffer_valid_ciphertext(const ::seal::Ciphertext& obj) {
+    return ::seal::is_buffer_valid(obj);
+  }
+
+  /**
+   * Checks that a Plaintext is valid for the Context.
+   *
+   * @param obj The Plaintext object.
+   * @param context The Context to check against.
+   * @return True if the Plaintext is valid.
+   */
+  bool is_valid_for_plaintext(const ::seal::Plaintext& obj, const ::seal::Context& context) {
+    return ::seal::is_valid_for(obj, context);
+  }
+
+  /**
+   * Checks that the metadata of a Plaintext matches the Context.
+   *
+   * @param obj The Plaintext object.
+   * @param context The Context to check against.
+   * @return True if the metadata is valid.
+   */
+  bool is_metadata_valid_for_plaintext(const ::seal::Plaintext& obj, const ::seal::Context& context) {
+    return ::seal::is_metadata_valid_for(obj, context);
+  }
+
+  /**
+   * Checks that the buffer of a Plaintext has the size its metadata claims.
+   *
+   * @param obj The Plaintext object.
+   * @return True if the buffer size is consistent.
+   */
+  bool is_buffer_valid_plaintext(const ::seal::Plaintext& obj) {
+    return ::seal::is_buffer_valid(obj);
+  }
+
+  /**
+   * Checks that a SecretKey is valid for the Context.
+   *
+   * @param obj The SecretKey object.
+   * @param context The Context to check against.
+   * @return True if the SecretKey is valid.
+   */
+  bool is_valid_for_secret_key(const ::seal::SecretKey& obj, const ::seal::Context& context) {
+    return ::seal::is_valid_for(obj, context);
+  }
+
+  /**
+   * Checks that the metadata of a SecretKey matches the Context.
+   *
+   * @param obj The SecretKey object.
+   * @param context The Context to check against.
+   * @return True if the metadata is valid.
+   */
+  bool is_metadata_valid_for_secret_key(const ::seal::SecretKey& obj, const ::seal::Context& context) {
+    return ::seal::is_metadata_valid_for(obj, context);
+  }
+
+  /**
+   * Checks that the buffer of a SecretKey has the size its metadata claims.
+   *
+   * @param obj The SecretKey object.
+   * @return True if the buffer size is consistent.
+   */
+  bool is_buffer_valid_secret_key(const ::seal::SecretKey& obj) {
+    return ::seal::is_buffer_valid(obj);
+  }
+
+  /**
+   * Checks that a PublicKey is valid for the Context.
+   *
+   * @param obj The PublicKey object.
+   * @param context The Context to check against.
+   * @return True if the PublicKey is valid.
+   */
+  bool is_valid_for_public_key(const ::seal::PublicKey& obj, const ::seal::Context& context) {
+    return ::seal::is_valid_for(obj, context);
+  }
+
+  /**
+   * Checks that the metadata of a PublicKey matches the Context.
+   *
+   * @param obj The PublicKey object.
+   * @param context The Context to check against.
+   * @return True if the metadata is valid.
+   */
+  bool is_metadata_valid_for_public_key(const ::seal::PublicKey& obj, const ::seal::Context& context) {
+    return ::seal::is_metadata_valid_for(obj, context);
+  }
+
+  /**
+   * Checks that the buffer of a PublicKey has the size its metadata claims.
+   *
+   * @param obj The PublicKey object.
+   * @return True if the buffer size is consistent.
+   */
+  bool is_buffer_valid_public_key(const ::seal::PublicKey& obj) {
+    return ::seal::is_buffer_valid(obj);
+  }
+
+  /**
+   * Checks that a RelinKeys is valid for the Context.
+   *
+   * @param obj The RelinKeys object.
+   * @param context The Context to check against.
+   * @return True if the RelinKeys is valid.
+   */
+  bool is_valid_for_relin_keys(const ::seal::RelinKeys& obj, const ::seal::Context& context) {
+    return ::seal::is_valid_for(obj, context);
+  }
+
+  /**
+   * Checks that the metadata of a RelinKeys matches the Context.
+   *
+   * @param obj The RelinKeys object.
+   * @param context The Context to check against.
+   * @return True if the metadata is valid.
+   */
+  bool is_metadata_valid_for_relin_keys(const ::seal::RelinKeys& obj, const ::seal::Context& context) {
+    return ::seal::is_metadata_valid_for(obj, context);
+  }
+
+  /**
+   * Checks that the buffer of a RelinKeys has the size its metadata claims.
+   *
+   * @param obj The RelinKeys object.
+   * @return True if the buffer size is consistent.
+   */
+  bool is_buffer_valid_relin_keys(const ::seal::RelinKeys& obj) {
+    return ::seal::is_buffer_valid(obj);
+  }
+
+  /**
+   * Checks that a GaloisKeys is valid for the Context.
+   *
+   * @param obj The GaloisKeys object.
+   * @param context The Context to check against.
+   * @return True if the GaloisKeys is valid.
+   */
+  bool is_valid_for_galois_keys(const ::seal::GaloisKeys& obj, const ::seal::Context& context) {
+    return ::seal::is_valid_for(obj, context);
+  }
+
+  /**
+   * Checks that the metadata of a GaloisKeys matches the Context.
+   *
+   * @param obj The GaloisKeys object.
+   * @param context The Context to check against.
+   * @return True if the metadata is valid.
+   */
+  bool is_metadata_valid_for_galois_keys(const ::seal::GaloisKeys& obj, const ::seal::Context& context) {
+    return ::seal::is_metadata_valid_for(obj, context);
+  }
+
+  /**
+   * Checks that the buffer of a GaloisKeys has the size its metadata claims.
+   *
+   * @param obj The GaloisKeys object.
+   * @return True if the buffer size is consistent.
+   */
+  bool is_buffer_valid_galois_keys(const ::seal::GaloisKeys& obj) {
+    return ::seal::is_buffer_valid(obj);
+  }
+
   // /**
   //  * Sets the value of `m` in the BGVContextBuilder object.
   //  *
@@ -21,6 +21,20 @@ namespace seal {
 
     using SecurityLevel = sec_level_type;
 
+    using ContextData = SEALContext::ContextData;
+
+    // Serializable<T> is a template, bind each instantiation used by name.
+    using SerializablePublicKey = Serializable<PublicKey>;
+    using SerializableRelinKeys = Serializable<RelinKeys>;
+    using SerializableGaloisKeys = Serializable<GaloisKeys>;
+    using SerializableCiphertext = Serializable<Ciphertext>;
+
+    // Shared with Rust, defined by the cxx generated header.
+    struct ParmsId;
+    struct ParameterQualifiers;
+    enum class ComprMode : uint8_t;
+    enum class SchemeType : uint8_t;
+
   /**
    * Returns the version string of the SEAL library.
    *
@@ -58,20 +72,23 @@ namespace seal {
    */
   uint8_t get_scheme(const std::unique_ptr<seal::EncryptionParameters> enc_params);
   /**
-   * @brief Sets the encryption scheme in the given EncryptionParameters object.
+   * Creates a new BGVContextBuilder object.
    *
-   * @note This function will replace the existing EncryptionParameters object with a new one that uses the given scheme.
+   * @note Despite the name, the builder serves every scheme SEAL supports.
+   * @note SEAL fixes the scheme when the EncryptionParameters are constructed, so it is chosen here and cannot be changed later.
    *
-   * @param enc_params A unique pointer to the EncryptionParameters object. This object will be replaced.
-   * @param scheme The new encryption scheme to be used. This should be a value from the seal::scheme_type enum.
+   * @param schema A seal::scheme_type value: 0x1 for BFV, 0x2 for CKKS, 0x3 for BGV. Throws std::invalid_argument otherwise.
+   * @return A unique pointer to the newly created ContextBuilder<BGV> object.
    */
-  void set_scheme(std::unique_ptr<seal::EncryptionParameters> enc_params, uint8_t scheme);
+  std::unique_ptr<BGVContextBuilder> init(u_int8_t schema);
+
   /**
-   * Creates a new BGVContextBuilder object.
+   * @brief Returns the scheme of the BGVContextBuilder.
    *
-   * @return A unique pointer to the newly created ContextBuilder<BGV> object.
+   * @param builder The BGVContextBuilder object.
+   * @return SchemeType The scheme the builder was initialized with.
    */
-  std::unique_ptr<BGVContextBuilder> init(u_int8_t schema);
+  SchemeType scheme_type(const ::seal::BGVContextBuilder& builder);
 
   std::unique_ptr<::seal::Context> build(std::unique_ptr<::seal::BGVContextBuilder> builder);
 
@@ -86,6 +103,1260 @@ namespace seal {
   //   - set_plain_modulus
   std::unique_ptr<::seal::BGVContextBuilder> set_m(std::unique_ptr<::seal::BGVContextBuilder> builder, uint32_t m);
 
+  /**
+   * @brief Sets the degree of the polynomial modulus, a power of two such as 4096 or 8192.
+   *
+   * @param builder A unique pointer to the BGVContextBuilder object.
+   * @param degree The degree of the polynomial modulus.
+   * @return std::unique_ptr<seal::BGVContextBuilder> The modified BGVContextBuilder object.
+   */
+  std::unique_ptr<::seal::BGVContextBuilder> set_poly_modulus_degree(std::unique_ptr<::seal::BGVContextBuilder> builder, uint64_t degree);
+
+  /**
+   * @brief Sets the coefficient modulus from the values of its primes.
+   *
+   * @param builder A unique pointer to the BGVContextBuilder object.
+   * @param moduli The primes of the coefficient modulus, at most 60 bits each.
+   * @return std::unique_ptr<seal::BGVContextBuilder> The modified BGVContextBuilder object.
+   */
+  std::unique_ptr<::seal::BGVContextBuilder> set_coeff_modulus(std::unique_ptr<::seal::BGVContextBuilder> builder, rust::Slice<const uint64_t> moduli);
+
+  /**
+   * @brief Sets the plaintext modulus.
+   *
+   * @param builder A unique pointer to the BGVContextBuilder object.
+   * @param modulus The plaintext modulus.
+   * @return std::unique_ptr<seal::BGVContextBuilder> The modified BGVContextBuilder object.
+   */
+  std::unique_ptr<::seal::BGVContextBuilder> set_plain_modulus(std::unique_ptr<::seal::BGVContextBuilder> builder, uint64_t modulus);
+
+  /**
+   * @brief Installs a Blake2xb PRNG factory seeded with a fixed 512-bit seed.
+   *
+   * @note FOR TESTS ONLY. Every key and ciphertext created from the resulting
+   * parameters is reproducible from the seed. Production code must leave the
+   * default factory in place, which draws its seeds from system entropy.
+   *
+   * @param builder A unique pointer to the BGVContextBuilder object.
+   * @param seed The seed, exactly 8 64-bit words.
+   * @return std::unique_ptr<seal::BGVContextBuilder> The modified BGVContextBuilder object.
+   */
+  std::unique_ptr<::seal::BGVContextBuilder> set_random_generator_seed(std::unique_ptr<::seal::BGVContextBuilder> builder, rust::Slice<const uint64_t> seed);
+
+  /**
+   * @brief Returns the primes of SEAL's default coefficient modulus for 128-bit security.
+   *
+   * @note Wraps CoeffModulus::BFVDefault, which also serves BGV. Supported degrees are 1024 to 32768.
+   *
+   * @param poly_modulus_degree The degree of the polynomial modulus.
+   * @return rust::Vec<uint64_t> The primes of the coefficient modulus.
+   */
+  rust::Vec<uint64_t> coeff_modulus_bfv_default(uint64_t poly_modulus_degree);
+
+  /**
+   * @brief Returns primes suitable for a coefficient modulus, one per requested bit size.
+   *
+   * @note Wraps CoeffModulus::Create. Each prime is congruent to 1 modulo 2 * poly_modulus_degree.
+   *
+   * @param poly_modulus_degree The degree of the polynomial modulus.
+   * @param bit_sizes The bit size of each prime, at most 60.
+   * @return rust::Vec<uint64_t> The primes of the coefficient modulus.
+   */
+  rust::Vec<uint64_t> coeff_modulus_create(uint64_t poly_modulus_degree, rust::Slice<const int32_t> bit_sizes);
+
+  /**
+   * @brief Returns a prime plaintext modulus that supports batching.
+   *
+   * @note Wraps PlainModulus::Batching. The prime is congruent to 1 modulo 2 * poly_modulus_degree.
+   *
+   * @param poly_modulus_degree The degree of the polynomial modulus.
+   * @param bit_size The bit size of the prime.
+   * @return uint64_t The plaintext modulus.
+   */
+  uint64_t plain_modulus_batching(uint64_t poly_modulus_degree, int32_t bit_size);
+
+  /**
+   * @brief Creates a new KeyGenerator for the given Context.
+   *
+   * @note A fresh secret key is sampled. The KeyGenerator holds a copy of the Context, which shares the underlying context data, so the Context may be dropped first.
+   *
+   * @param context The Context the keys are generated for.
+   * @return std::unique_ptr<seal::KeyGenerator> A unique pointer to the created KeyGenerator object.
+   */
+  std::unique_ptr<::seal::KeyGenerator> new_key_generator(const ::seal::Context& context);
+
+  /**
+   * @brief Returns a copy of the secret key held by the KeyGenerator.
+   *
+   * @param keygen The KeyGenerator object.
+   * @return std::unique_ptr<seal::SecretKey> A unique pointer to the copied SecretKey object.
+   */
+  std::unique_ptr<::seal::SecretKey> get_secret_key(const ::seal::KeyGenerator& keygen);
+
+  /**
+   * @brief Creates a public key from the secret key held by the KeyGenerator.
+   *
+   * @param keygen The KeyGenerator object.
+   * @return std::unique_ptr<seal::PublicKey> A unique pointer to the created PublicKey object.
+   */
+  std::unique_ptr<::seal::PublicKey> create_public_key(const ::seal::KeyGenerator& keygen);
+
+  /**
+   * @brief Creates relinearization keys from the secret key held by the KeyGenerator.
+   *
+   * @param keygen The KeyGenerator object.
+   * @return std::unique_ptr<seal::RelinKeys> A unique pointer to the created RelinKeys object.
+   */
+  std::unique_ptr<::seal::RelinKeys> create_relin_keys(::seal::KeyGenerator& keygen);
+
+  /**
+   * @brief Creates Galois keys for every rotation step supported by the Context.
+   *
+   * @param keygen The KeyGenerator object.
+   * @return std::unique_ptr<seal::GaloisKeys> A unique pointer to the created GaloisKeys object.
+   */
+  std::unique_ptr<::seal::GaloisKeys> create_galois_keys(::seal::KeyGenerator& keygen);
+
+  /**
+   * @brief Creates Galois keys for the chosen rotation steps only.
+   *
+   * @note Positive steps rotate left, negative steps rotate right. A step of zero selects the column swap.
+   *
+   * @param keygen The KeyGenerator object.
+   * @param steps The rotation steps keys are generated for.
+   * @return std::unique_ptr<seal::GaloisKeys> A unique pointer to the created GaloisKeys object.
+   */
+  std::unique_ptr<::seal::GaloisKeys> create_galois_keys_with_steps(::seal::KeyGenerator& keygen, rust::Slice<const int32_t> steps);
+
+  /**
+   * @brief Creates a new, empty Plaintext object.
+   *
+   * @return std::unique_ptr<seal::Plaintext> A unique pointer to the created Plaintext object.
+   */
+  std::unique_ptr<::seal::Plaintext> new_plaintext();
+
+  /**
+   * @brief Creates a new Plaintext object from a polynomial in hexadecimal form.
+   *
+   * @note The string has the form "7FFx^3 + 1x^1 + 3", coefficients written in hexadecimal.
+   *
+   * @param hex_poly The polynomial in hexadecimal form.
+   * @return std::unique_ptr<seal::Plaintext> A unique pointer to the created Plaintext object.
+   */
+  std::unique_ptr<::seal::Plaintext> new_plaintext_from_hex(rust::Str hex_poly);
+
+  /**
+   * @brief Returns the polynomial of the Plaintext in hexadecimal form.
+   *
+   * @note A CKKS plaintext is stored in NTT form and has no such representation; it yields "<NTT form>".
+   *
+   * @param plain The Plaintext object.
+   * @return rust::String The polynomial in hexadecimal form.
+   */
+  rust::String plaintext_to_string(const ::seal::Plaintext& plain);
+
+  /**
+   * @brief Creates a new, empty Ciphertext object.
+   *
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> new_ciphertext();
+
+  /**
+   * @brief Creates a new Encryptor for public-key encryption.
+   *
+   * @param context The Context to encrypt for.
+   * @param public_key The PublicKey used to encrypt.
+   * @return std::unique_ptr<seal::Encryptor> A unique pointer to the created Encryptor object.
+   */
+  std::unique_ptr<::seal::Encryptor> new_encryptor(const ::seal::Context& context, const ::seal::PublicKey& public_key);
+
+  /**
+   * @brief Creates a new Encryptor for secret-key (symmetric) encryption.
+   *
+   * @param context The Context to encrypt for.
+   * @param secret_key The SecretKey used to encrypt.
+   * @return std::unique_ptr<seal::Encryptor> A unique pointer to the created Encryptor object.
+   */
+  std::unique_ptr<::seal::Encryptor> new_symmetric_encryptor(const ::seal::Context& context, const ::seal::SecretKey& secret_key);
+
+  /**
+   * @brief Encrypts a Plaintext with the public key.
+   *
+   * @param encryptor The Encryptor object, holding a public key.
+   * @param plain The Plaintext to encrypt.
+   * @param pool The MemoryPoolHandle to allocate temporaries and the result from.
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> encrypt(const ::seal::Encryptor& encryptor, const ::seal::Plaintext& plain, const ::seal::MemoryPoolHandle& pool);
+
+  /**
+   * @brief Encrypts a zero plaintext with the public key.
+   *
+   * @param encryptor The Encryptor object, holding a public key.
+   * @param pool The MemoryPoolHandle to allocate temporaries and the result from.
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> encrypt_zero(const ::seal::Encryptor& encryptor, const ::seal::MemoryPoolHandle& pool);
+
+  /**
+   * @brief Encrypts a Plaintext with the secret key.
+   *
+   * @param encryptor The Encryptor object, holding a secret key.
+   * @param plain The Plaintext to encrypt.
+   * @param pool The MemoryPoolHandle to allocate temporaries and the result from.
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> encrypt_symmetric(const ::seal::Encryptor& encryptor, const ::seal::Plaintext& plain, const ::seal::MemoryPoolHandle& pool);
+
+  /**
+   * @brief Encrypts a zero plaintext with the secret key.
+   *
+   * @param encryptor The Encryptor object, holding a secret key.
+   * @param pool The MemoryPoolHandle to allocate temporaries and the result from.
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the created Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> encrypt_zero_symmetric(const ::seal::Encryptor& encryptor, const ::seal::MemoryPoolHandle& pool);
+
+  /**
+   * @brief Creates a new Decryptor for the given Context and SecretKey.
+   *
+   * @param context The Context to decrypt for.
+   * @param secret_key The SecretKey used to decrypt.
+   * @return std::unique_ptr<seal::Decryptor> A unique pointer to the created Decryptor object.
+   */
+  std::unique_ptr<::seal::Decryptor> new_decryptor(const ::seal::Context& context, const ::seal::SecretKey& secret_key);
+
+  /**
+   * @brief Decrypts a Ciphertext.
+   *
+   * @param decryptor The Decryptor object.
+   * @param encrypted The Ciphertext to decrypt.
+   * @return std::unique_ptr<seal::Plaintext> A unique pointer to the created Plaintext object.
+   */
+  std::unique_ptr<::seal::Plaintext> decrypt(::seal::Decryptor& decryptor, const ::seal::Ciphertext& encrypted);
+
+  /**
+   * @brief Returns the name of the error that invalidated the encryption parameters, or "success".
+   *
+   * @param context The Context object.
+   * @return rust::String The name of the error.
+   */
+  rust::String parameter_error_name(const ::seal::Context& context);
+
+  /**
+   * @brief Returns a description of the error that invalidated the encryption parameters.
+   *
+   * @param context The Context object.
+   * @return rust::String The description of the error.
+   */
+  rust::String parameter_error_message(const ::seal::Context& context);
+
+  /**
+   * @brief Returns the ContextData for the key level, the top of the modulus switching chain.
+   *
+   * @param context The Context object.
+   * @return std::shared_ptr<seal::ContextData> The ContextData of the level.
+   */
+  std::shared_ptr<::seal::ContextData> key_context_data(const ::seal::Context& context);
+
+  /**
+   * @brief Returns the ContextData for the first data level, one below the key level when key switching is used.
+   *
+   * @param context The Context object.
+   * @return std::shared_ptr<seal::ContextData> The ContextData of the level.
+   */
+  std::shared_ptr<::seal::ContextData> first_context_data(const ::seal::Context& context);
+
+  /**
+   * @brief Returns the ContextData for the last level, the bottom of the modulus switching chain.
+   *
+   * @param context The Context object.
+   * @return std::shared_ptr<seal::ContextData> The ContextData of the level.
+   */
+  std::shared_ptr<::seal::ContextData> last_context_data(const ::seal::Context& context);
+
+  /**
+   * @brief Returns the ContextData for the given parms_id.
+   *
+   * @param context The Context object.
+   * @param parms_id The parms_id of the level.
+   * @return std::shared_ptr<seal::ContextData> The ContextData, null if parms_id is not in the chain.
+   */
+  std::shared_ptr<::seal::ContextData> get_context_data(const ::seal::Context& context, const ParmsId& parms_id);
+
+  /**
+   * @brief Returns the parms_id of the key level.
+   *
+   * @param context The Context object.
+   * @return ParmsId The parms_id of the key level.
+   */
+  ParmsId key_parms_id(const ::seal::Context& context);
+
+  /**
+   * @brief Returns the parms_id of the first data level.
+   *
+   * @param context The Context object.
+   * @return ParmsId The parms_id of the first data level.
+   */
+  ParmsId first_parms_id(const ::seal::Context& context);
+
+  /**
+   * @brief Returns the parms_id of the last level.
+   *
+   * @param context The Context object.
+   * @return ParmsId The parms_id of the last level.
+   */
+  ParmsId last_parms_id(const ::seal::Context& context);
+
+  /**
+   * @brief Returns the ContextData of the next level down the modulus switching chain.
+   *
+   * @param context_data The ContextData object.
+   * @return std::shared_ptr<seal::ContextData> The next ContextData, null at the last level.
+   */
+  std::shared_ptr<::seal::ContextData> next_context_data(const ::seal::ContextData& context_data);
+
+  /**
+   * @brief Returns the ContextData of the previous level up the modulus switching chain.
+   *
+   * @param context_data The ContextData object.
+   * @return std::shared_ptr<seal::ContextData> The previous ContextData, null at the key level.
+   */
+  std::shared_ptr<::seal::ContextData> prev_context_data(const ::seal::ContextData& context_data);
+
+  /**
+   * @brief Returns the parms_id of the ContextData.
+   *
+   * @param context_data The ContextData object.
+   * @return ParmsId The parms_id of the level.
+   */
+  ParmsId context_data_parms_id(const ::seal::ContextData& context_data);
+
+  /**
+   * @brief Returns the primes of the coefficient modulus at the level of the ContextData.
+   *
+   * @param context_data The ContextData object.
+   * @return rust::Vec<uint64_t> The primes of the coefficient modulus.
+   */
+  rust::Vec<uint64_t> context_data_coeff_modulus(const ::seal::ContextData& context_data);
+
+  /**
+   * @brief Returns the degree of the polynomial modulus of the ContextData.
+   *
+   * @param context_data The ContextData object.
+   * @return uint64_t The polynomial modulus degree.
+   */
+  uint64_t context_data_poly_modulus_degree(const ::seal::ContextData& context_data);
+
+  /**
+   * @brief Returns the plaintext modulus of the ContextData.
+   *
+   * @param context_data The ContextData object.
+   * @return uint64_t The plaintext modulus, 0 for CKKS.
+   */
+  uint64_t context_data_plain_modulus(const ::seal::ContextData& context_data);
+
+  /**
+   * @brief Returns the scheme of the ContextData.
+   *
+   * @param context_data The ContextData object.
+   * @return SchemeType The scheme of the encryption parameters.
+   */
+  SchemeType context_data_scheme(const ::seal::ContextData& context_data);
+
+  /**
+   * @brief Returns the qualifiers SEAL derived for the encryption parameters of the ContextData.
+   *
+   * @param context_data The ContextData object.
+   * @return ParameterQualifiers The qualifiers of the encryption parameters.
+   */
+  ParameterQualifiers context_data_qualifiers(const ::seal::ContextData& context_data);
+
+  /**
+   * @brief Returns the parms_id of the Ciphertext, identifying its level in the modulus switching chain.
+   *
+   * @param encrypted The Ciphertext object.
+   * @return ParmsId The parms_id of the Ciphertext.
+   */
+  ParmsId ciphertext_parms_id(const ::seal::Ciphertext& encrypted);
+
+  /**
+   * @brief Returns the scale of a CKKS Ciphertext.
+   *
+   * @param encrypted The Ciphertext object.
+   * @return double The scale of the Ciphertext.
+   */
+  double ciphertext_scale(const ::seal::Ciphertext& encrypted);
+
+  /**
+   * @brief Overwrites the scale of a CKKS Ciphertext, without changing its data.
+   *
+   * @param encrypted The Ciphertext object.
+   * @param scale The new scale.
+   */
+  void set_ciphertext_scale(::seal::Ciphertext& encrypted, double scale);
+
+  /**
+   * @brief Returns the scale of a CKKS Plaintext.
+   *
+   * @param plain The Plaintext object.
+   * @return double The scale of the Plaintext.
+   */
+  double plaintext_scale(const ::seal::Plaintext& plain);
+
+  /**
+   * @brief Creates a new Evaluator for the given Context.
+   *
+   * @param context The Context to evaluate in.
+   * @return std::unique_ptr<seal::Evaluator> A unique pointer to the created Evaluator object.
+   */
+  std::unique_ptr<::seal::Evaluator> new_evaluator(const ::seal::Context& context);
+
+  /**
+   * @brief Negates a ciphertext.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the resulting Ciphertext object.
+   *
+   * @note `negate_inplace` overwrites `encrypted` with the result instead.
+   */
+  std::unique_ptr<::seal::Ciphertext> negate(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted);
+  void negate_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted);
+
+  /**
+   * @brief Adds two ciphertexts.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted1 The Ciphertext operand.
+   * @param encrypted2 The Ciphertext operand.
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the resulting Ciphertext object.
+   *
+   * @note `add_inplace` overwrites `encrypted1` with the result instead.
+   */
+  std::unique_ptr<::seal::Ciphertext> add(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted1, const ::seal::Ciphertext& encrypted2);
+  void add_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted1, const ::seal::Ciphertext& encrypted2);
+
+  /**
+   * @brief Subtracts the second ciphertext from the first.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted1 The Ciphertext operand.
+   * @param encrypted2 The Ciphertext operand.
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the resulting Ciphertext object.
+   *
+   * @note `sub_inplace` overwrites `encrypted1` with the result instead.
+   */
+  std::unique_ptr<::seal::Ciphertext> sub(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted1, const ::seal::Ciphertext& encrypted2);
+  void sub_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted1, const ::seal::Ciphertext& encrypted2);
+
+  /**
+   * @brief Multiplies two ciphertexts.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted1 The Ciphertext operand.
+   * @param encrypted2 The Ciphertext operand.
+   * @param pool The MemoryPoolHandle to allocate temporaries and the result from.
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the resulting Ciphertext object.
+   *
+   * @note `multiply_inplace` overwrites `encrypted1` with the result instead.
+   */
+  std::unique_ptr<::seal::Ciphertext> multiply(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted1, const ::seal::Ciphertext& encrypted2, const ::seal::MemoryPoolHandle& pool);
+  void multiply_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted1, const ::seal::Ciphertext& encrypted2, const ::seal::MemoryPoolHandle& pool);
+
+  /**
+   * @brief Squares a ciphertext.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param pool The MemoryPoolHandle to allocate temporaries and the result from.
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the resulting Ciphertext object.
+   *
+   * @note `square_inplace` overwrites `encrypted` with the result instead.
+   */
+  std::unique_ptr<::seal::Ciphertext> square(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, const ::seal::MemoryPoolHandle& pool);
+  void square_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, const ::seal::MemoryPoolHandle& pool);
+
+  /**
+   * @brief Adds a ciphertext and a plaintext.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param plain The Plaintext operand.
+   * @param pool The MemoryPoolHandle to allocate temporaries and the result from.
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the resulting Ciphertext object.
+   *
+   * @note `add_plain_inplace` overwrites `encrypted` with the result instead.
+   */
+  std::unique_ptr<::seal::Ciphertext> add_plain(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, const ::seal::Plaintext& plain, const ::seal::MemoryPoolHandle& pool);
+  void add_plain_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, const ::seal::Plaintext& plain, const ::seal::MemoryPoolHandle& pool);
+
+  /**
+   * @brief Subtracts a plaintext from a ciphertext.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param plain The Plaintext operand.
+   * @param pool The MemoryPoolHandle to allocate temporaries and the result from.
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the resulting Ciphertext object.
+   *
+   * @note `sub_plain_inplace` overwrites `encrypted` with the result instead.
+   */
+  std::unique_ptr<::seal::Ciphertext> sub_plain(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, const ::seal::Plaintext& plain, const ::seal::MemoryPoolHandle& pool);
+  void sub_plain_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, const ::seal::Plaintext& plain, const ::seal::MemoryPoolHandle& pool);
+
+  /**
+   * @brief Multiplies a ciphertext with a plaintext.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param plain The Plaintext operand.
+   * @param pool The MemoryPoolHandle to allocate temporaries and the result from.
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the resulting Ciphertext object.
+   *
+   * @note `multiply_plain_inplace` overwrites `encrypted` with the result instead.
+   */
+  std::unique_ptr<::seal::Ciphertext> multiply_plain(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, const ::seal::Plaintext& plain, const ::seal::MemoryPoolHandle& pool);
+  void multiply_plain_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, const ::seal::Plaintext& plain, const ::seal::MemoryPoolHandle& pool);
+
+  /**
+   * @brief Relinearizes a ciphertext, reducing its size to 2.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param relin_keys The RelinKeys used for key switching.
+   * @param pool The MemoryPoolHandle to allocate temporaries and the result from.
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the resulting Ciphertext object.
+   *
+   * @note `relinearize_inplace` overwrites `encrypted` with the result instead.
+   */
+  std::unique_ptr<::seal::Ciphertext> relinearize(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, const ::seal::RelinKeys& relin_keys, const ::seal::MemoryPoolHandle& pool);
+  void relinearize_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, const ::seal::RelinKeys& relin_keys, const ::seal::MemoryPoolHandle& pool);
+
+  /**
+   * @brief Switches a ciphertext down to the next modulus in the modulus switching chain.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param pool The MemoryPoolHandle to allocate temporaries and the result from.
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the resulting Ciphertext object.
+   *
+   * @note `mod_switch_to_next_inplace` overwrites `encrypted` with the result instead.
+   */
+  std::unique_ptr<::seal::Ciphertext> mod_switch_to_next(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, const ::seal::MemoryPoolHandle& pool);
+  void mod_switch_to_next_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, const ::seal::MemoryPoolHandle& pool);
+
+  /**
+   * @brief Switches a ciphertext down to the modulus given by parms_id.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param parms_id The value of `parms_id`.
+   * @param pool The MemoryPoolHandle to allocate temporaries and the result from.
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the resulting Ciphertext object.
+   *
+   * @note `mod_switch_to_inplace` overwrites `encrypted` with the result instead.
+   */
+  std::unique_ptr<::seal::Ciphertext> mod_switch_to(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, const ::seal::ParmsId& parms_id, const ::seal::MemoryPoolHandle& pool);
+  void mod_switch_to_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, const ::seal::ParmsId& parms_id, const ::seal::MemoryPoolHandle& pool);
+
+  /**
+   * @brief Switches a CKKS ciphertext down to the next modulus, dividing its scale by the dropped prime.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param pool The MemoryPoolHandle to allocate temporaries and the result from.
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the resulting Ciphertext object.
+   *
+   * @note `rescale_to_next_inplace` overwrites `encrypted` with the result instead.
+   */
+  std::unique_ptr<::seal::Ciphertext> rescale_to_next(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, const ::seal::MemoryPoolHandle& pool);
+  void rescale_to_next_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, const ::seal::MemoryPoolHandle& pool);
+
+  /**
+   * @brief Cyclically rotates the rows of a batched ciphertext by the given number of steps.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param steps The value of `steps`.
+   * @param galois_keys The GaloisKeys used for key switching.
+   * @param pool The MemoryPoolHandle to allocate temporaries and the result from.
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the resulting Ciphertext object.
+   *
+   * @note `rotate_rows_inplace` overwrites `encrypted` with the result instead.
+   */
+  std::unique_ptr<::seal::Ciphertext> rotate_rows(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, int steps, const ::seal::GaloisKeys& galois_keys, const ::seal::MemoryPoolHandle& pool);
+  void rotate_rows_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, int steps, const ::seal::GaloisKeys& galois_keys, const ::seal::MemoryPoolHandle& pool);
+
+  /**
+   * @brief Swaps the two rows (rotates the columns) of a batched ciphertext.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param galois_keys The GaloisKeys used for key switching.
+   * @param pool The MemoryPoolHandle to allocate temporaries and the result from.
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the resulting Ciphertext object.
+   *
+   * @note `rotate_columns_inplace` overwrites `encrypted` with the result instead.
+   */
+  std::unique_ptr<::seal::Ciphertext> rotate_columns(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, const ::seal::GaloisKeys& galois_keys, const ::seal::MemoryPoolHandle& pool);
+  void rotate_columns_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, const ::seal::GaloisKeys& galois_keys, const ::seal::MemoryPoolHandle& pool);
+
+  /**
+   * @brief Applies the Galois automorphism given by galois_elt to a ciphertext.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param galois_elt The value of `galois_elt`.
+   * @param galois_keys The GaloisKeys used for key switching.
+   * @param pool The MemoryPoolHandle to allocate temporaries and the result from.
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the resulting Ciphertext object.
+   *
+   * @note `apply_galois_inplace` overwrites `encrypted` with the result instead.
+   */
+  std::unique_ptr<::seal::Ciphertext> apply_galois(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, uint32_t galois_elt, const ::seal::GaloisKeys& galois_keys, const ::seal::MemoryPoolHandle& pool);
+  void apply_galois_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, uint32_t galois_elt, const ::seal::GaloisKeys& galois_keys, const ::seal::MemoryPoolHandle& pool);
+
+  /**
+   * @brief Raises a ciphertext to the given power, relinearizing after every multiplication.
+   *
+   * @param evaluator The Evaluator object.
+   * @param encrypted The Ciphertext operand.
+   * @param exponent The value of `exponent`.
+   * @param relin_keys The RelinKeys used for key switching.
+   * @param pool The MemoryPoolHandle to allocate temporaries and the result from.
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the resulting Ciphertext object.
+   *
+   * @note `exponentiate_inplace` overwrites `encrypted` with the result instead.
+   */
+  std::unique_ptr<::seal::Ciphertext> exponentiate(const ::seal::Evaluator& evaluator, const ::seal::Ciphertext& encrypted, uint64_t exponent, const ::seal::RelinKeys& relin_keys, const ::seal::MemoryPoolHandle& pool);
+  void exponentiate_inplace(const ::seal::Evaluator& evaluator, ::seal::Ciphertext& encrypted, uint64_t exponent, const ::seal::RelinKeys& relin_keys, const ::seal::MemoryPoolHandle& pool);
+
+  /**
+   * @brief Creates a new BatchEncoder for the given Context.
+   *
+   * @note Batching requires a plain modulus that is a prime congruent to 1 modulo 2 * poly_modulus_degree.
+   *
+   * @param context The Context to encode for.
+   * @return std::unique_ptr<seal::BatchEncoder> A unique pointer to the created BatchEncoder object.
+   */
+  std::unique_ptr<::seal::BatchEncoder> new_batch_encoder(const ::seal::Context& context);
+
+  /**
+   * @brief Encodes unsigned integers into the slots of a new Plaintext.
+   *
+   * @note Slots beyond the length of `values` are set to zero.
+   *
+   * @param encoder The BatchEncoder object.
+   * @param values At most slot_count() values, each less than the plain modulus.
+   * @return std::unique_ptr<seal::Plaintext> A unique pointer to the encoded Plaintext object.
+   */
+  std::unique_ptr<::seal::Plaintext> encode_u64(const ::seal::BatchEncoder& encoder, rust::Slice<const uint64_t> values);
+
+  /**
+   * @brief Encodes signed integers into the slots of a new Plaintext.
+   *
+   * @note Slots beyond the length of `values` are set to zero.
+   *
+   * @param encoder The BatchEncoder object.
+   * @param values At most slot_count() values, each of absolute value at most plain_modulus / 2.
+   * @return std::unique_ptr<seal::Plaintext> A unique pointer to the encoded Plaintext object.
+   */
+  std::unique_ptr<::seal::Plaintext> encode_i64(const ::seal::BatchEncoder& encoder, rust::Slice<const int64_t> values);
+
+  /**
+   * @brief Decodes the slots of a Plaintext into unsigned integers.
+   *
+   * @param encoder The BatchEncoder object.
+   * @param plain The Plaintext to decode.
+   * @return rust::Vec<uint64_t> The slot_count() decoded values.
+   */
+  rust::Vec<uint64_t> decode_u64(const ::seal::BatchEncoder& encoder, const ::seal::Plaintext& plain);
+
+  /**
+   * @brief Decodes the slots of a Plaintext into signed integers.
+   *
+   * @param encoder The BatchEncoder object.
+   * @param plain The Plaintext to decode.
+   * @return rust::Vec<int64_t> The slot_count() decoded values.
+   */
+  rust::Vec<int64_t> decode_i64(const ::seal::BatchEncoder& encoder, const ::seal::Plaintext& plain);
+
+  /**
+   * @brief Creates a new CKKSEncoder for the given Context.
+   *
+   * @param context The Context to encode for, which must use the CKKS scheme.
+   * @return std::unique_ptr<seal::CKKSEncoder> A unique pointer to the created CKKSEncoder object.
+   */
+  std::unique_ptr<::seal::CKKSEncoder> new_ckks_encoder(const ::seal::Context& context);
+
+  /**
+   * @brief Encodes real numbers into the slots of a new Plaintext.
+   *
+   * @note Slots beyond the length of `values` are set to zero.
+   *
+   * @param encoder The CKKSEncoder object.
+   * @param values At most slot_count() values.
+   * @param parms_id The level to encode at.
+   * @param scale The scale the values are multiplied by before rounding.
+   * @return std::unique_ptr<seal::Plaintext> A unique pointer to the encoded Plaintext object.
+   */
+  std::unique_ptr<::seal::Plaintext> ckks_encode_f64(const ::seal::CKKSEncoder& encoder, rust::Slice<const double> values, const ParmsId& parms_id, double scale);
+
+  /**
+   * @brief Encodes complex numbers into the slots of a new Plaintext.
+   *
+   * @note Slots beyond the length of `values` are set to zero.
+   *
+   * @param encoder The CKKSEncoder object.
+   * @param values At most slot_count() complex numbers, as interleaved real and imaginary parts.
+   * @param parms_id The level to encode at.
+   * @param scale The scale the values are multiplied by before rounding.
+   * @return std::unique_ptr<seal::Plaintext> A unique pointer to the encoded Plaintext object.
+   */
+  std::unique_ptr<::seal::Plaintext> ckks_encode_complex(const ::seal::CKKSEncoder& encoder, rust::Slice<const double> values, const ParmsId& parms_id, double scale);
+
+  /**
+   * @brief Decodes the slots of a Plaintext into real numbers, discarding the imaginary parts.
+   *
+   * @param encoder The CKKSEncoder object.
+   * @param plain The Plaintext to decode.
+   * @return rust::Vec<double> The slot_count() decoded values.
+   */
+  rust::Vec<double> ckks_decode_f64(const ::seal::CKKSEncoder& encoder, const ::seal::Plaintext& plain);
+
+  /**
+   * @brief Decodes the slots of a Plaintext into complex numbers.
+   *
+   * @param encoder The CKKSEncoder object.
+   * @param plain The Plaintext to decode.
+   * @return rust::Vec<double> The slot_count() decoded values, as interleaved real and imaginary parts.
+   */
+  rust::Vec<double> ckks_decode_complex(const ::seal::CKKSEncoder& encoder, const ::seal::Plaintext& plain);
+
+  /**
+   * @brief Returns an upper bound on the number of bytes save_ciphertext writes.
+   *
+   * @param obj The Ciphertext object.
+   * @param compr_mode The compression mode.
+   * @return int64_t The upper bound, in bytes.
+   */
+  int64_t save_size_ciphertext(const ::seal::Ciphertext& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Serializes the Ciphertext object, prefixed by a SEAL header.
+   *
+   * @param obj The Ciphertext object.
+   * @param compr_mode The compression mode.
+   * @return rust::Vec<uint8_t> The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_ciphertext(const ::seal::Ciphertext& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Deserializes a Ciphertext saved by save_ciphertext.
+   *
+   * The data is not checked against the Context; run is_valid_for on the result. SEAL still
+   * checks the metadata while parsing, see is_foreign_parms_id.
+   *
+   * @param context The Context the Ciphertext belongs to.
+   * @param bytes The serialized bytes.
+   * @return std::unique_ptr<seal::Ciphertext> A unique pointer to the loaded Ciphertext object.
+   */
+  std::unique_ptr<::seal::Ciphertext> load_ciphertext(const ::seal::Context& context, rust::Slice<const uint8_t> bytes);
+
+  /**
+   * @brief Returns an upper bound on the number of bytes save_plaintext writes.
+   *
+   * @param obj The Plaintext object.
+   * @param compr_mode The compression mode.
+   * @return int64_t The upper bound, in bytes.
+   */
+  int64_t save_size_plaintext(const ::seal::Plaintext& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Serializes the Plaintext object, prefixed by a SEAL header.
+   *
+   * @param obj The Plaintext object.
+   * @param compr_mode The compression mode.
+   * @return rust::Vec<uint8_t> The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_plaintext(const ::seal::Plaintext& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Deserializes a Plaintext saved by save_plaintext.
+   *
+   * The data is not checked against the Context; run is_valid_for on the result. SEAL still
+   * checks the metadata while parsing, see is_foreign_parms_id.
+   *
+   * @param context The Context the Plaintext belongs to.
+   * @param bytes The serialized bytes.
+   * @return std::unique_ptr<seal::Plaintext> A unique pointer to the loaded Plaintext object.
+   */
+  std::unique_ptr<::seal::Plaintext> load_plaintext(const ::seal::Context& context, rust::Slice<const uint8_t> bytes);
+
+  /**
+   * @brief Returns an upper bound on the number of bytes save_secret_key writes.
+   *
+   * @param obj The SecretKey object.
+   * @param compr_mode The compression mode.
+   * @return int64_t The upper bound, in bytes.
+   */
+  int64_t save_size_secret_key(const ::seal::SecretKey& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Serializes the SecretKey object, prefixed by a SEAL header.
+   *
+   * @param obj The SecretKey object.
+   * @param compr_mode The compression mode.
+   * @return rust::Vec<uint8_t> The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_secret_key(const ::seal::SecretKey& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Deserializes a SecretKey saved by save_secret_key.
+   *
+   * The data is not checked against the Context; run is_valid_for on the result. SEAL still
+   * checks the metadata while parsing, see is_foreign_parms_id.
+   *
+   * @param context The Context the SecretKey belongs to.
+   * @param bytes The serialized bytes.
+   * @return std::unique_ptr<seal::SecretKey> A unique pointer to the loaded SecretKey object.
+   */
+  std::unique_ptr<::seal::SecretKey> load_secret_key(const ::seal::Context& context, rust::Slice<const uint8_t> bytes);
+
+  /**
+   * @brief Returns an upper bound on the number of bytes save_public_key writes.
+   *
+   * @param obj The PublicKey object.
+   * @param compr_mode The compression mode.
+   * @return int64_t The upper bound, in bytes.
+   */
+  int64_t save_size_public_key(const ::seal::PublicKey& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Serializes the PublicKey object, prefixed by a SEAL header.
+   *
+   * @param obj The PublicKey object.
+   * @param compr_mode The compression mode.
+   * @return rust::Vec<uint8_t> The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_public_key(const ::seal::PublicKey& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Deserializes a PublicKey saved by save_public_key.
+   *
+   * The data is not checked against the Context; run is_valid_for on the result. SEAL still
+   * checks the metadata while parsing, see is_foreign_parms_id.
+   *
+   * @param context The Context the PublicKey belongs to.
+   * @param bytes The serialized bytes.
+   * @return std::unique_ptr<seal::PublicKey> A unique pointer to the loaded PublicKey object.
+   */
+  std::unique_ptr<::seal::PublicKey> load_public_key(const ::seal::Context& context, rust::Slice<const uint8_t> bytes);
+
+  /**
+   * @brief Returns an upper bound on the number of bytes save_relin_keys writes.
+   *
+   * @param obj The RelinKeys object.
+   * @param compr_mode The compression mode.
+   * @return int64_t The upper bound, in bytes.
+   */
+  int64_t save_size_relin_keys(const ::seal::RelinKeys& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Serializes the RelinKeys object, prefixed by a SEAL header.
+   *
+   * @param obj The RelinKeys object.
+   * @param compr_mode The compression mode.
+   * @return rust::Vec<uint8_t> The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_relin_keys(const ::seal::RelinKeys& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Deserializes a RelinKeys saved by save_relin_keys.
+   *
+   * The data is not checked against the Context; run is_valid_for on the result. SEAL still
+   * checks the metadata while parsing, see is_foreign_parms_id.
+   *
+   * @param context The Context the RelinKeys belongs to.
+   * @param bytes The serialized bytes.
+   * @return std::unique_ptr<seal::RelinKeys> A unique pointer to the loaded RelinKeys object.
+   */
+  std::unique_ptr<::seal::RelinKeys> load_relin_keys(const ::seal::Context& context, rust::Slice<const uint8_t> bytes);
+
+  /**
+   * @brief Returns an upper bound on the number of bytes save_galois_keys writes.
+   *
+   * @param obj The GaloisKeys object.
+   * @param compr_mode The compression mode.
+   * @return int64_t The upper bound, in bytes.
+   */
+  int64_t save_size_galois_keys(const ::seal::GaloisKeys& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Serializes the GaloisKeys object, prefixed by a SEAL header.
+   *
+   * @param obj The GaloisKeys object.
+   * @param compr_mode The compression mode.
+   * @return rust::Vec<uint8_t> The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_galois_keys(const ::seal::GaloisKeys& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Deserializes a GaloisKeys saved by save_galois_keys.
+   *
+   * The data is not checked against the Context; run is_valid_for on the result. SEAL still
+   * checks the metadata while parsing, see is_foreign_parms_id.
+   *
+   * @param context The Context the GaloisKeys belongs to.
+   * @param bytes The serialized bytes.
+   * @return std::unique_ptr<seal::GaloisKeys> A unique pointer to the loaded GaloisKeys object.
+   */
+  std::unique_ptr<::seal::GaloisKeys> load_galois_keys(const ::seal::Context& context, rust::Slice<const uint8_t> bytes);
+
+  /**
+   * @brief Returns an upper bound on the number of bytes save_encryption_parameters writes.
+   *
+   * @param obj The EncryptionParameters object.
+   * @param compr_mode The compression mode.
+   * @return int64_t The upper bound, in bytes.
+   */
+  int64_t save_size_encryption_parameters(const ::seal::BGVContextBuilder& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Serializes the EncryptionParameters object, prefixed by a SEAL header.
+   *
+   * @param obj The EncryptionParameters object.
+   * @param compr_mode The compression mode.
+   * @return rust::Vec<uint8_t> The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_encryption_parameters(const ::seal::BGVContextBuilder& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Deserializes EncryptionParameters saved by save_encryption_parameters.
+   *
+   * @param bytes The serialized bytes.
+   * @return std::unique_ptr<seal::BGVContextBuilder> A unique pointer to the loaded EncryptionParameters object.
+   */
+  std::unique_ptr<::seal::BGVContextBuilder> load_encryption_parameters(rust::Slice<const uint8_t> bytes);
+
+  /**
+   * @brief Checks whether serialized bytes hold an object from another Context.
+   *
+   * Every object SEAL loads with a Context starts its members with the parms_id of its
+   * parameters, and SEAL rejects an unknown parms_id while parsing. Only that parms_id is read here.
+   *
+   * @param context The Context the object is loaded into.
+   * @param bytes The serialized bytes.
+   * @return bool True if the parms_id was read and the Context does not know it; false otherwise,
+   * including when the bytes are malformed.
+   */
+  bool is_foreign_parms_id(const ::seal::Context& context, rust::Slice<const uint8_t> bytes);
+
+  /**
+   * @brief Creates a new public key from the secret key, to be serialized only.
+   *
+   * @note The second polynomial is replaced by the PRNG seed that expands to it, which halves the serialized size.
+   *
+   * @param keygen The KeyGenerator object.
+   * @return std::unique_ptr<seal::SerializablePublicKey> A unique pointer to the Serializable<PublicKey> object.
+   */
+  std::unique_ptr<::seal::SerializablePublicKey> create_serializable_public_key(const ::seal::KeyGenerator& keygen);
+
+  /**
+   * @brief Creates new relinearization keys from the secret key, to be serialized only.
+   *
+   * @param keygen The KeyGenerator object.
+   * @return std::unique_ptr<seal::SerializableRelinKeys> A unique pointer to the Serializable<RelinKeys> object.
+   */
+  std::unique_ptr<::seal::SerializableRelinKeys> create_serializable_relin_keys(::seal::KeyGenerator& keygen);
+
+  /**
+   * @brief Creates new Galois keys for every rotation step, to be serialized only.
+   *
+   * @param keygen The KeyGenerator object.
+   * @return std::unique_ptr<seal::SerializableGaloisKeys> A unique pointer to the Serializable<GaloisKeys> object.
+   */
+  std::unique_ptr<::seal::SerializableGaloisKeys> create_serializable_galois_keys(::seal::KeyGenerator& keygen);
+
+  /**
+   * @brief Encrypts a Plaintext with the secret key, to be serialized only.
+   *
+   * @param encryptor The Encryptor object, holding a secret key.
+   * @param plain The Plaintext to encrypt.
+   * @return std::unique_ptr<seal::SerializableCiphertext> A unique pointer to the Serializable<Ciphertext> object.
+   */
+  std::unique_ptr<::seal::SerializableCiphertext> encrypt_symmetric_serializable(const ::seal::Encryptor& encryptor, const ::seal::Plaintext& plain);
+
+  /**
+   * @brief Encrypts a zero plaintext with the secret key, to be serialized only.
+   *
+   * @param encryptor The Encryptor object, holding a secret key.
+   * @return std::unique_ptr<seal::SerializableCiphertext> A unique pointer to the Serializable<Ciphertext> object.
+   */
+  std::unique_ptr<::seal::SerializableCiphertext> encrypt_zero_symmetric_serializable(const ::seal::Encryptor& encryptor);
+
+  /**
+   * @brief Returns an upper bound on the number of bytes save_serializable_public_key writes.
+   *
+   * @param obj The Serializable<PublicKey> object.
+   * @param compr_mode The compression mode.
+   * @return int64_t The upper bound, in bytes.
+   */
+  int64_t save_size_serializable_public_key(const ::seal::SerializablePublicKey& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Serializes the Serializable<PublicKey> object, prefixed by a SEAL header. Load it back as the underlying type.
+   *
+   * @param obj The Serializable<PublicKey> object.
+   * @param compr_mode The compression mode.
+   * @return rust::Vec<uint8_t> The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_serializable_public_key(const ::seal::SerializablePublicKey& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Returns an upper bound on the number of bytes save_serializable_relin_keys writes.
+   *
+   * @param obj The Serializable<RelinKeys> object.
+   * @param compr_mode The compression mode.
+   * @return int64_t The upper bound, in bytes.
+   */
+  int64_t save_size_serializable_relin_keys(const ::seal::SerializableRelinKeys& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Serializes the Serializable<RelinKeys> object, prefixed by a SEAL header. Load it back as the underlying type.
+   *
+   * @param obj The Serializable<RelinKeys> object.
+   * @param compr_mode The compression mode.
+   * @return rust::Vec<uint8_t> The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_serializable_relin_keys(const ::seal::SerializableRelinKeys& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Returns an upper bound on the number of bytes save_serializable_galois_keys writes.
+   *
+   * @param obj The Serializable<GaloisKeys> object.
+   * @param compr_mode The compression mode.
+   * @return int64_t The upper bound, in bytes.
+   */
+  int64_t save_size_serializable_galois_keys(const ::seal::SerializableGaloisKeys& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Serializes the Serializable<GaloisKeys> object, prefixed by a SEAL header. Load it back as the underlying type.
+   *
+   * @param obj The Serializable<GaloisKeys> object.
+   * @param compr_mode The compression mode.
+   * @return rust::Vec<uint8_t> The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_serializable_galois_keys(const ::seal::SerializableGaloisKeys& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Returns an upper bound on the number of bytes save_serializable_ciphertext writes.
+   *
+   * @param obj The Serializable<Ciphertext> object.
+   * @param compr_mode The compression mode.
+   * @return int64_t The upper bound, in bytes.
+   */
+  int64_t save_size_serializable_ciphertext(const ::seal::SerializableCiphertext& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Serializes the Serializable<Ciphertext> object, prefixed by a SEAL header. Load it back as the underlying type.
+   *
+   * @param obj The Serializable<Ciphertext> object.
+   * @param compr_mode The compression mode.
+   * @return rust::Vec<uint8_t> The serialized bytes.
+   */
+  rust::Vec<uint8_t> save_serializable_ciphertext(const ::seal::SerializableCiphertext& obj, ComprMode compr_mode);
+
+  /**
+   * @brief Returns a handle to the global memory pool, shared by the whole process.
+   *
+   * @return std::unique_ptr<seal::MemoryPoolHandle> A unique pointer to the handle.
+   */
+  std::unique_ptr<::seal::MemoryPoolHandle> memory_pool_global();
+
+  /**
+   * @brief Returns a handle to the memory pool of the calling thread.
+   *
+   * @note The pool lives until the thread exits; do not use the handle from another thread.
+   *
+   * @return std::unique_ptr<seal::MemoryPoolHandle> A unique pointer to the handle.
+   */
+  std::unique_ptr<::seal::MemoryPoolHandle> memory_pool_thread_local();
+
+  /**
+   * @brief Creates a new memory pool, freed when the last handle to it is destroyed.
+   *
+   * @param clear_on_destruction Whether to zero the memory before it is freed.
+   * @return std::unique_ptr<seal::MemoryPoolHandle> A unique pointer to the handle.
+   */
+  std::unique_ptr<::seal::MemoryPoolHandle> memory_pool_new(bool clear_on_destruction);
+
+  /**
+   * @brief Returns another handle to the same memory pool.
+   *
+   * @param pool The MemoryPoolHandle to copy.
+   * @return std::unique_ptr<seal::MemoryPoolHandle> A unique pointer to the copy.
+   */
+  std::unique_ptr<::seal::MemoryPoolHandle> memory_pool_clone(const ::seal::MemoryPoolHandle& pool);
+
+  /**
+   * @brief Checks that a Ciphertext is valid for the Context: its metadata, its buffer size and its data.
+   *
+   * @param obj The Ciphertext object.
+   * @param context The Context to check against.
+   * @return bool True if the Ciphertext can safely be used with the Context.
+   */
+  bool is_valid_for_ciphertext(const ::seal::Ciphertext& obj, const ::seal::Context& context);
+
+  /**
+   * @brief Checks that the metadata of a Ciphertext, such as its parms_id, matches the Context.
+   *
+   * @param obj The Ciphertext object.
+   * @param context The Context to check against.
+   * @return bool True if the metadata is valid for the Context.
+   */
+  bool is_metadata_valid_for_ciphertext(const ::seal::Ciphertext& obj, const ::seal::Context& context);
+
+  /**
+   * @brief Checks that the buffer of a Ciphertext has the size its metadata claims.
+   *
+   * @param obj The Ciphertext object.
+   * @return bool True if the buffer size is consistent.
+   */
+  bool is_buffer_valid_ciphertext(const ::seal::Ciphertext& obj);
+
+  /**
+   * @brief Checks that a Plaintext is valid for the Context: its metadata, its buffer size and its data.
+   *
+   * @param obj The Plaintext object.
+   * @param context The Context to check against.
+   * @return bool True if the Plaintext can safely be used with the Context.
+   */
+  bool is_valid_for_plaintext(const ::seal::Plaintext& obj, const ::seal::Context& context);
+
+  /**
+   * @brief Checks that the metadata of a Plaintext, such as its parms_id, matches the Context.
+   *
+   * @param obj The Plaintext object.
+   * @param context The Context to check against.
+   * @return bool True if the metadata is valid for the Context.
+   */
+  bool is_metadata_valid_for_plaintext(const ::seal::Plaintext& obj, const ::seal::Context& context);
+
+  /**
+   * @brief Checks that the buffer of a Plaintext has the size its metadata claims.
+   *
+   * @param obj The Plaintext object.
+   * @return bool True if the buffer size is consistent.
+   */
+  bool is_buffer_valid_plaintext(const ::seal::Plaintext& obj);
+
+  /**
+   * @brief Checks that a SecretKey is valid for the Context: its metadata, its buffer size and its data.
+   *
+   * @param obj The SecretKey object.
+   * @param context The Context to check against.
+   * @return bool True if the SecretKey can safely be used with the Context.
+   */
+  bool is_valid_for_secret_key(const ::seal::SecretKey& obj, const ::seal::Context& context);
+
+  /**
+   * @brief Checks that the metadata of a SecretKey, such as its parms_id, matches the Context.
+   *
+   * @param obj The SecretKey object.
+   * @param context The Context to check against.
+   * @return bool True if the metadata is valid for the Context.
+   */
+  bool is_metadata_valid_for_secret_key(const ::seal::SecretKey& obj, const ::seal::Context& context);
+
+  /**
+   * @brief Checks that the buffer of a SecretKey has the size its metadata claims.
+   *
+   * @param obj The SecretKey object.
+   * @return bool True if the buffer size is consistent.
+   */
+  bool is_buffer_valid_secret_key(const ::seal::SecretKey& obj);
+
+  /**
+   * @brief Checks that a PublicKey is valid for the Context: its metadata, its buffer size and its data.
+   *
+   * @param obj The PublicKey object.
+   * @param context The Context to check against.
+   * @return bool True if the PublicKey can safely be used with the Context.
+   */
+  bool is_valid_for_public_key(const ::seal::PublicKey& obj, const ::seal::Context& context);
+
+  /**
+   * @brief Checks that the metadata of a PublicKey, such as its parms_id, matches the Context.
+   *
+   * @param obj The PublicKey object.
+   * @param context The Context to check against.
+   * @return bool True if the metadata is valid for the Context.
+   */
+  bool is_metadata_valid_for_public_key(const ::seal::PublicKey& obj, const ::seal::Context& context);
+
+  /**
+   * @brief Checks that the buffer of a PublicKey has the size its metadata claims.
+   *
+   * @param obj The PublicKey object.
+   * @return bool True if the buffer size is consistent.
+   */
+  bool is_buffer_valid_public_key(const ::seal::PublicKey& obj);
+
+  /**
+   * @brief Checks that a RelinKeys is valid for the Context: its metadata, its buffer size and its data.
+   *
+   * @param obj The RelinKeys object.
+   * @param context The Context to check against.
+   * @return bool True if the RelinKeys can safely be used with the Context.
+   */
+  bool is_valid_for_relin_keys(const ::seal::RelinKeys& obj, const ::seal::Context& context);
+
+  /**
+   * @brief Checks that the metadata of a RelinKeys, such as its parms_id, matches the Context.
+   *
+   * @param obj The RelinKeys object.
+   * @param context The Context to check against.
+   * @return bool True if the metadata is valid for the Context.
+   */
+  bool is_metadata_valid_for_relin_keys(const ::seal::RelinKeys& obj, const ::seal::Context& context);
+
+  /**
+   * @brief Checks that the buffer of a RelinKeys has the size its metadata claims.
+   *
+   * @param obj The RelinKeys object.
+   * @return bool True if the buffer size is consistent.
+   */
+  bool is_buffer_valid_relin_keys(const ::seal::RelinKeys& obj);
+
+  /**
+   * @brief Checks that a GaloisKeys is valid for the Context: its metadata, its buffer size and its data.
+   *
+   * @param obj The GaloisKeys object.
+   * @param context The Context to check against.
+   * @return bool True if the GaloisKeys can safely be used with the Context.
+   */
+  bool is_valid_for_galois_keys(const ::seal::GaloisKeys& obj, const ::seal::Context& context);
+
+  /**
+   * @brief Checks that the metadata of a GaloisKeys, such as its parms_id, matches the Context.
+   *
+   * @param obj The GaloisKeys object.
+   * @param context The Context to check against.
+   * @return bool True if the metadata is valid for the Context.
+   */
+  bool is_metadata_valid_for_galois_keys(const ::seal::GaloisKeys& obj, const ::seal::Context& context);
+
+  /**
+   * @brief Checks that the buffer of a GaloisKeys has the size its metadata claims.
+   *
+   * @param obj The GaloisKeys object.
+   * @return bool True if the buffer size is consistent.
+   */
+  bool is_buffer_valid_galois_keys(const ::seal::GaloisKeys& obj);
+
   // Examples:
   // - seal/native/examples/4_bgv_basics.cpp
   // - seal/native/examples/1_bfv_basics.cpp
@@ -0,0 +1,172 @@
+use super::*;
+
+/// Define the Rust struct to represent the C++ BatchEncoder class
+///
+/// A `BatchEncoder` packs a vector of integers into the slots of a single
+/// `Plaintext`, so that one homomorphic operation acts on every slot at once.
+/// The slots form a matrix of 2 rows of `slot_count() / 2` columns, which
+/// `Evaluator::rotate_rows` and `Evaluator::rotate_columns` act on.
+///
+/// Batching requires the plain modulus to be a prime congruent to 1 modulo
+/// `2 * poly_modulus_degree`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use zno_seal_sys::bgv::*;
+/// let builder = ffi::set_poly_modulus_degree(ffi::init(0x3).unwrap(), 8192).unwrap();
+/// let builder = ffi::set_coeff_modulus(builder, &ffi::coeff_modulus_bfv_default(8192).unwrap()).unwrap();
+/// let builder = ffi::set_plain_modulus(builder, ffi::plain_modulus_batching(8192, 20).unwrap()).unwrap();
+/// let context = Context::new(builder).expect("BGV context creation");
+/// let encoder = BatchEncoder::new(&context).expect("BatchEncoder creation");
+/// let plain = encoder.encode_u64(&[1, 2, 3]).expect("Plaintext");
+/// assert_eq!(&encoder.decode_u64(&plain).unwrap()[..3], &[1, 2, 3]);
+/// ```
+pub struct BatchEncoder {
+    pub(crate) inner: cxx::UniquePtr<ffi::BatchEncoder>,
+}
+
+impl BatchEncoder {
+    /// Creates a `BatchEncoder` for a built `Context`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::NullPointer` if the C++ side did not return an encoder,
+    /// or `FFIError::CppException` if the encryption parameters do not support batching.
+    pub fn new(context: &Context) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::new_batch_encoder(&context.inner)?)?;
+        Ok(BatchEncoder { inner })
+    }
+
+    /// Returns the number of slots in a `Plaintext`, equal to the polynomial modulus degree.
+    pub fn slot_count(&self) -> usize {
+        self.inner.slot_count()
+    }
+
+    /// Encodes unsigned integers into the slots of a new `Plaintext`.
+    ///
+    /// Slots beyond `values.len()` are set to zero. Every value must be less
+    /// than the plain modulus.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::CppException` if there are more values than slots.
+    pub fn encode_u64(&self, values: &[u64]) -> Result<Plaintext, FFIError> {
+        let inner = non_null(ffi::encode_u64(&self.inner, values)?)?;
+        Ok(Plaintext { inner })
+    }
+
+    /// Encodes signed integers into the slots of a new `Plaintext`.
+    ///
+    /// Slots beyond `values.len()` are set to zero. Every value must be at
+    /// most `plain_modulus / 2` in absolute value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::CppException` if there are more values than slots.
+    pub fn encode_i64(&self, values: &[i64]) -> Result<Plaintext, FFIError> {
+        let inner = non_null(ffi::encode_i64(&self.inner, values)?)?;
+        Ok(Plaintext { inner })
+    }
+
+    /// Decodes the slots of a `Plaintext` into `slot_count()` unsigned integers.
+    pub fn decode_u64(&self, plain: &Plaintext) -> Result<Vec<u64>, FFIError> {
+        Ok(ffi::decode_u64(&self.inner, &plain.inner)?)
+    }
+
+    /// Decodes the slots of a `Plaintext` into `slot_count()` signed integers.
+    pub fn decode_i64(&self, plain: &Plaintext) -> Result<Vec<i64>, FFIError> {
+        Ok(ffi::decode_i64(&self.inner, &plain.inner)?)
+    }
+}
+
+// Implement Display for printing, debugging, etc.
+impl core::fmt::Display for BatchEncoder {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "BatchEncoder(slots={})", self.slot_count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bgv::testing::setup_bgv_context;
+
+    #[test]
+    fn test_slot_count() {
+        let context = setup_bgv_context();
+        let encoder = BatchEncoder::new(&context).expect("BatchEncoder creation");
+        assert_eq!(encoder.slot_count(), 8192);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let context = setup_bgv_context();
+        let encoder = BatchEncoder::new(&context).expect("BatchEncoder creation");
+
+        let plain = encoder.encode_u64(&[1, 2, 3, 4]).expect("Plaintext");
+        let decoded = encoder.decode_u64(&plain).unwrap();
+        assert_eq!(decoded.len(), encoder.slot_count());
+        assert_eq!(&decoded[..4], &[1, 2, 3, 4]);
+        assert!(decoded[4..].iter().all(|&value| value == 0));
+
+        let plain = encoder.encode_i64(&[-7, 0, 7]).expect("Plaintext");
+        assert_eq!(&encoder.decode_i64(&plain).unwrap()[..3], &[-7, 0, 7]);
+    }
+
+    #[test]
+    fn test_encode_too_many_values() {
+        let context = setup_bgv_context();
+        let encoder = BatchEncoder::new(&context).expect("BatchEncoder creation");
+        let values = vec![0u64; encoder.slot_count() + 1];
+        assert!(matches!(encoder.encode_u64(&values), Err(FFIError::CppException(_))));
+    }
+
+    #[test]
+    fn test_batched_arithmetic() {
+        let context = setup_bgv_context();
+        let mut keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let relin_keys = keygen.create_relin_keys().expect("RelinKeys");
+        let encryptor = Encryptor::new(&context, &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+        let mut decryptor = Decryptor::new(&context, &keygen.secret_key().unwrap()).expect("Decryptor creation");
+        let evaluator = Evaluator::new(&context).expect("Evaluator creation");
+        let encoder = BatchEncoder::new(&context).expect("BatchEncoder creation");
+
+        let encrypted = encryptor.encrypt(&encoder.encode_i64(&[1, -2, 3]).unwrap()).expect("Ciphertext");
+        let mut squared = evaluator.square(&encrypted).expect("Ciphertext");
+        evaluator.relinearize_inplace(&mut squared, &relin_keys).unwrap();
+
+        let decoded = encoder.decode_i64(&decryptor.decrypt(&squared).unwrap()).unwrap();
+        assert_eq!(&decoded[..3], &[1, 4, 9]);
+    }
+
+    #[test]
+    fn test_encode_value_above_plain_modulus() {
+        let context = setup_bgv_context();
+        let encoder = BatchEncoder::new(&context).expect("BatchEncoder creation");
+        let plain_modulus = context.key_context_data().unwrap().plain_modulus();
+        assert!(matches!(encoder.encode_u64(&[plain_modulus]), Err(FFIError::CppException(_))));
+        assert!(encoder.encode_u64(&[plain_modulus - 1]).is_ok());
+    }
+
+    #[test]
+    fn test_batched_rotation() {
+        let context = setup_bgv_context();
+        let mut keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let galois_keys = keygen.create_galois_keys_from_steps(&[1]).expect("GaloisKeys");
+        let encryptor = Encryptor::new(&context, &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+        let mut decryptor = Decryptor::new(&context, &keygen.secret_key().unwrap()).expect("Decryptor creation");
+        let evaluator = Evaluator::new(&context).expect("Evaluator creation");
+        let encoder = BatchEncoder::new(&context).expect("BatchEncoder creation");
+
+        // The slots form a 2 x (n / 2) matrix, and rows rotate to the left.
+        let row_size = encoder.slot_count() / 2;
+        let encrypted = encryptor.encrypt(&encoder.encode_u64(&[1, 2, 3]).unwrap()).expect("Ciphertext");
+        let rotated = evaluator.rotate_rows(&encrypted, 1, &galois_keys).expect("Ciphertext");
+
+        let decoded = encoder.decode_u64(&decryptor.decrypt(&rotated).unwrap()).unwrap();
+        assert_eq!(&decoded[..2], &[2, 3]);
+        assert_eq!(decoded[row_size - 1], 1);
+        assert!(decoded[row_size..].iter().all(|&value| value == 0));
+    }
+}
@@ -0,0 +1,49 @@
+use super::*;
+
+/// Define the Rust struct to represent the C++ Ciphertext class
+///
+/// A freshly encrypted `Ciphertext` has size 2. Multiplication grows the size,
+/// and relinearization brings it back down to 2.
+pub struct Ciphertext {
+    pub(crate) inner: cxx::UniquePtr<ffi::Ciphertext>,
+}
+
+impl Ciphertext {
+    /// Creates a new, empty `Ciphertext`.
+    pub fn new() -> Result<Self, FFIError> {
+        let inner = non_null(ffi::new_ciphertext())?;
+        Ok(Ciphertext { inner })
+    }
+
+    /// Returns the number of polynomials in the ciphertext.
+    pub fn size(&self) -> usize {
+        self.inner.size()
+    }
+
+    /// Returns the `ParmsId` of the ciphertext, that is its level in the
+    /// modulus switching chain.
+    pub fn parms_id(&self) -> ffi::ParmsId {
+        ffi::ciphertext_parms_id(&self.inner)
+    }
+
+    /// Returns the scale of a CKKS ciphertext, `1.0` for the other schemes.
+    pub fn scale(&self) -> f64 {
+        ffi::ciphertext_scale(&self.inner)
+    }
+
+    /// Overwrites the scale of a CKKS ciphertext without touching its data.
+    ///
+    /// After `Evaluator::rescale_to_next` the scale is only close to the
+    /// intended one, since the primes are not exact powers of two. Set it
+    /// back before adding ciphertexts, whose scales must match exactly.
+    pub fn set_scale(&mut self, scale: f64) {
+        ffi::set_ciphertext_scale(self.inner.pin_mut(), scale)
+    }
+}
+
+// Implement Display for printing, debugging, etc.
+impl core::fmt::Display for Ciphertext {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Ciphertext(size={})", self.size())
+    }
+}
@@ -0,0 +1,174 @@
+use super::*;
+
+pub use num_complex::Complex;
+
+/// Define the Rust struct to represent the C++ CKKSEncoder class
+///
+/// A `CKKSEncoder` packs a vector of real or complex numbers into the slots of
+/// a single `Plaintext` for the CKKS scheme. Values are multiplied by `scale`
+/// and rounded, so the arithmetic is approximate: the precision is roughly
+/// `log2(scale)` bits minus the noise the operations add.
+///
+/// There are `poly_modulus_degree / 2` slots. Multiplication multiplies the
+/// scales, so rescale with `Evaluator::rescale_to_next` after each one.
+///
+/// # Example
+///
+/// ```no_run
+/// # use zno_seal_sys::bgv::*;
+/// let builder = ffi::set_poly_modulus_degree(ffi::init(0x2).unwrap(), 8192).unwrap();
+/// let builder = ffi::set_coeff_modulus(builder, &ffi::coeff_modulus_create(8192, &[60, 40, 40, 60]).unwrap()).unwrap();
+/// let context = Context::new(builder).expect("CKKS context creation");
+/// let encoder = CKKSEncoder::new(&context).expect("CKKSEncoder creation");
+/// let plain = encoder.encode_f64(&[0.5, 1.5], 2f64.powi(40)).expect("Plaintext");
+/// assert!((encoder.decode_f64(&plain).unwrap()[1] - 1.5).abs() < 1e-6);
+/// ```
+pub struct CKKSEncoder {
+    pub(crate) inner: cxx::UniquePtr<ffi::CKKSEncoder>,
+    first_parms_id: ffi::ParmsId,
+}
+
+impl CKKSEncoder {
+    /// Creates a `CKKSEncoder` for a built `Context`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::NullPointer` if the C++ side did not return an encoder,
+    /// or `FFIError::CppException` if the context does not use the CKKS scheme.
+    pub fn new(context: &Context) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::new_ckks_encoder(&context.inner)?)?;
+        Ok(CKKSEncoder { inner, first_parms_id: context.first_parms_id() })
+    }
+
+    /// Returns the number of slots in a `Plaintext`, half the polynomial modulus degree.
+    pub fn slot_count(&self) -> usize {
+        self.inner.slot_count()
+    }
+
+    /// Encodes real numbers at the first level of the modulus chain.
+    ///
+    /// Slots beyond `values.len()` are set to zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::CppException` if there are more values than slots,
+    /// or if `scale` does not fit in the coefficient modulus.
+    pub fn encode_f64(&self, values: &[f64], scale: f64) -> Result<Plaintext, FFIError> {
+        self.encode_f64_at(values, &self.first_parms_id, scale)
+    }
+
+    /// Encodes real numbers at the level identified by `parms_id`, for
+    /// example that of a `Ciphertext` to multiply with.
+    pub fn encode_f64_at(&self, values: &[f64], parms_id: &ffi::ParmsId, scale: f64) -> Result<Plaintext, FFIError> {
+        let inner = non_null(ffi::ckks_encode_f64(&self.inner, values, parms_id, scale)?)?;
+        Ok(Plaintext { inner })
+    }
+
+    /// Encodes complex numbers at the first level of the modulus chain.
+    ///
+    /// Slots beyond `values.len()` are set to zero.
+    pub fn encode_complex(&self, values: &[Complex<f64>], scale: f64) -> Result<Plaintext, FFIError> {
+        self.encode_complex_at(values, &self.first_parms_id, scale)
+    }
+
+    /// Encodes complex numbers at the level identified by `parms_id`.
+    pub fn encode_complex_at(&self, values: &[Complex<f64>], parms_id: &ffi::ParmsId, scale: f64) -> Result<Plaintext, FFIError> {
+        let parts: Vec<f64> = values.iter().flat_map(|value| [value.re, value.im]).collect();
+        let inner = non_null(ffi::ckks_encode_complex(&self.inner, &parts, parms_id, scale)?)?;
+        Ok(Plaintext { inner })
+    }
+
+    /// Decodes the slots of a `Plaintext` into `slot_count()` real numbers,
+    /// discarding the imaginary parts.
+    pub fn decode_f64(&self, plain: &Plaintext) -> Result<Vec<f64>, FFIError> {
+        Ok(ffi::ckks_decode_f64(&self.inner, &plain.inner)?)
+    }
+
+    /// Decodes the slots of a `Plaintext` into `slot_count()` complex numbers.
+    pub fn decode_complex(&self, plain: &Plaintext) -> Result<Vec<Complex<f64>>, FFIError> {
+        let parts = ffi::ckks_decode_complex(&self.inner, &plain.inner)?;
+        Ok(parts.chunks_exact(2).map(|part| Complex::new(part[0], part[1])).collect())
+    }
+}
+
+// Implement Display for printing, debugging, etc.
+impl core::fmt::Display for CKKSEncoder {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CKKSEncoder(slots={})", self.slot_count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Helper function to create a CKKS Context with two rescaling levels.
+    fn setup_ckks_context() -> Context {
+        let builder = ffi::set_poly_modulus_degree(ffi::init(0x2).unwrap(), 8192).unwrap();
+        let builder = ffi::set_coeff_modulus(builder, &ffi::coeff_modulus_create(8192, &[60, 40, 40, 60]).unwrap()).unwrap();
+        Context::new(builder).expect("CKKS context creation")
+    }
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        for (actual, expected) in actual.iter().zip(expected) {
+            assert!((actual - expected).abs() < 1e-4, "{} != {}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let context = setup_ckks_context();
+        let encoder = CKKSEncoder::new(&context).expect("CKKSEncoder creation");
+        assert_eq!(encoder.slot_count(), 4096);
+
+        let plain = encoder.encode_f64(&[3.25, -1.5, 0.0], 2f64.powi(40)).expect("Plaintext");
+        assert_eq!(plain.scale(), 2f64.powi(40));
+        let decoded = encoder.decode_f64(&plain).unwrap();
+        assert_eq!(decoded.len(), encoder.slot_count());
+        assert_close(&decoded, &[3.25, -1.5, 0.0]);
+
+        let values = [Complex::new(1.0, -2.0), Complex::new(0.5, 0.25)];
+        let plain = encoder.encode_complex(&values, 2f64.powi(40)).expect("Plaintext");
+        let decoded = encoder.decode_complex(&plain).unwrap();
+        assert!((decoded[0] - values[0]).norm() < 1e-4);
+        assert!((decoded[1] - values[1]).norm() < 1e-4);
+    }
+
+    #[test]
+    fn test_batch_encoder_rejects_ckks() {
+        let context = setup_ckks_context();
+        assert!(matches!(BatchEncoder::new(&context), Err(FFIError::CppException(_))));
+    }
+
+    #[test]
+    fn test_multiply_and_rescale() {
+        let context = setup_ckks_context();
+        let mut keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let relin_keys = keygen.create_relin_keys().expect("RelinKeys");
+        let encryptor = Encryptor::new(&context, &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+        let mut decryptor = Decryptor::new(&context, &keygen.secret_key().unwrap()).expect("Decryptor creation");
+        let evaluator = Evaluator::new(&context).expect("Evaluator creation");
+        let encoder = CKKSEncoder::new(&context).expect("CKKSEncoder creation");
+        let scale = 2f64.powi(40);
+
+        // (x * x) * 0.5 + 1.0
+        let x = encryptor.encrypt(&encoder.encode_f64(&[1.0, 2.0, -3.0], scale).unwrap()).expect("Ciphertext");
+        let mut result = evaluator.square(&x).expect("Ciphertext");
+        evaluator.relinearize_inplace(&mut result, &relin_keys).unwrap();
+        evaluator.rescale_to_next_inplace(&mut result).unwrap();
+        assert_ne!(result.parms_id(), x.parms_id());
+
+        let half = encoder.encode_f64_at(&[0.5; 3], &result.parms_id(), scale).unwrap();
+        evaluator.multiply_plain_inplace(&mut result, &half).unwrap();
+        let mut result = evaluator.rescale_to_next(&result).expect("Ciphertext");
+
+        // The primes are close to, but not exactly, 2^40.
+        assert!((result.scale().log2() - 40.0).abs() < 0.1);
+        result.set_scale(scale);
+        let one = encoder.encode_f64_at(&[1.0; 3], &result.parms_id(), scale).unwrap();
+        evaluator.add_plain_inplace(&mut result, &one).unwrap();
+
+        let decoded = encoder.decode_f64(&decryptor.decrypt(&result).unwrap()).unwrap();
+        assert_close(&decoded, &[1.5, 3.0, 5.5]);
+    }
+}
@@ -0,0 +1,127 @@
+use super::*;
+
+/// Define the Rust struct to represent the C++ Context class
+pub struct Context {
+    // This holds a pointer to the C++ object.
+    // UniquePtr ensures proper destruction, preventing memory leaks.
+    pub(crate) inner: cxx::UniquePtr<ffi::Context>,
+}
+
+impl Context {
+    /// Builds a `Context` from a configured `BGVContextBuilder`.
+    ///
+    /// Note: This consumes the builder. That is you won't be able to use it afterward.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::NullPointer` if the C++ side did not return a context.
+    pub fn new(builder: cxx::UniquePtr<ffi::BGVContextBuilder>) -> Result<Self, FFIError> {
+        // This is safe because it transfers ownership of the Context
+        let inner = non_null(ffi::build(builder)?)?;
+        Ok(Context { inner })
+    }
+
+    /// Returns whether the encryption parameters are valid.
+    ///
+    /// SEAL builds a `Context` even from invalid parameters. When this returns
+    /// `false`, `parameter_error_name` and `parameter_error_message` tell why.
+    pub fn parameters_set(&self) -> bool {
+        self.inner.parameters_set()
+    }
+
+    /// Returns the name of the error that invalidated the encryption parameters,
+    /// `"success"` when they are valid.
+    pub fn parameter_error_name(&self) -> String {
+        ffi::parameter_error_name(&self.inner)
+    }
+
+    /// Returns a description of the error that invalidated the encryption parameters.
+    pub fn parameter_error_message(&self) -> String {
+        ffi::parameter_error_message(&self.inner)
+    }
+
+    /// Returns whether the coefficient modulus has more than one prime, so that
+    /// the key level is reserved for key switching.
+    pub fn using_keyswitching(&self) -> bool {
+        self.inner.using_keyswitching()
+    }
+
+    /// Returns the key level, the top of the modulus switching chain.
+    pub fn key_context_data(&self) -> Option<ContextData> {
+        ContextData::from_ptr(ffi::key_context_data(&self.inner))
+    }
+
+    /// Returns the first data level, where fresh ciphertexts are encrypted.
+    pub fn first_context_data(&self) -> Option<ContextData> {
+        ContextData::from_ptr(ffi::first_context_data(&self.inner))
+    }
+
+    /// Returns the last level, the bottom of the modulus switching chain.
+    pub fn last_context_data(&self) -> Option<ContextData> {
+        ContextData::from_ptr(ffi::last_context_data(&self.inner))
+    }
+
+    /// Returns the level identified by `parms_id`, `None` if it is not in the chain.
+    pub fn get_context_data(&self, parms_id: &ffi::ParmsId) -> Option<ContextData> {
+        ContextData::from_ptr(ffi::get_context_data(&self.inner, parms_id))
+    }
+
+    /// Returns the `ParmsId` of the key level.
+    pub fn key_parms_id(&self) -> ffi::ParmsId {
+        ffi::key_parms_id(&self.inner)
+    }
+
+    /// Returns the `ParmsId` of the first data level.
+    pub fn first_parms_id(&self) -> ffi::ParmsId {
+        ffi::first_parms_id(&self.inner)
+    }
+
+    /// Returns the `ParmsId` of the last level.
+    pub fn last_parms_id(&self) -> ffi::ParmsId {
+        ffi::last_parms_id(&self.inner)
+    }
+}
+
+// Implement Display for printing, debugging, etc.
+impl core::fmt::Display for Context {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Context") // How this type name should appear
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bgv::testing::setup_bgv_context;
+
+    #[test]
+    fn test_parameters_set() {
+        let context = setup_bgv_context();
+
+        assert!(context.parameters_set());
+        assert!(context.using_keyswitching());
+        assert_eq!(context.parameter_error_name(), "success");
+        assert!(context.get_context_data(&context.first_parms_id()).is_some());
+    }
+
+    #[test]
+    fn test_parameter_error_message() {
+        // Without a coefficient modulus, SEAL rejects the parameters but still builds the Context.
+        let builder = ffi::set_poly_modulus_degree(ffi::init(0x3).unwrap(), 8192).unwrap();
+        let context = Context::new(builder).expect("BGV context creation");
+
+        assert!(!context.parameters_set());
+        assert_ne!(context.parameter_error_name(), "success");
+        assert!(!context.parameter_error_message().is_empty());
+        assert!(!context.key_context_data().unwrap().qualifiers().parameters_set);
+    }
+
+    #[test]
+    fn test_scheme_type() {
+        let builder = ffi::init(ffi::SchemeType::Bfv.repr).unwrap();
+        assert_eq!(ffi::scheme_type(&builder), ffi::SchemeType::Bfv);
+        let builder = ffi::init(ffi::SchemeType::Bgv.repr).unwrap();
+        assert_eq!(ffi::scheme_type(&builder), ffi::SchemeType::Bgv);
+        assert!(ffi::init(0x7).is_err());
+    }
+}
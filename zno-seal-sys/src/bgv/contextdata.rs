@@ -0,0 +1,148 @@
+use super::*;
+
+/// Define the Rust struct to represent the C++ SEALContext::ContextData class
+///
+/// A `ContextData` describes one level of the modulus switching chain: its
+/// `ParmsId`, the primes left in the coefficient modulus and the qualifiers
+/// SEAL derived for them. Walk the chain from `Context::key_context_data`
+/// down with `next`, or from `Context::last_context_data` up with `prev`.
+///
+/// The chain index counts down: the key level has the highest index and the
+/// last level has index `0`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use zno_seal_sys::bgv::*;
+/// # let builder = ffi::set_poly_modulus_degree(ffi::init(0x3).unwrap(), 8192).unwrap();
+/// # let builder = ffi::set_coeff_modulus(builder, &ffi::coeff_modulus_bfv_default(8192).unwrap()).unwrap();
+/// # let builder = ffi::set_plain_modulus(builder, ffi::plain_modulus_batching(8192, 20).unwrap()).unwrap();
+/// # let context = Context::new(builder).expect("BGV context creation");
+/// let mut level = context.key_context_data();
+/// while let Some(context_data) = level {
+///     println!("{}", context_data);
+///     level = context_data.next();
+/// }
+/// ```
+pub struct ContextData {
+    pub(crate) inner: cxx::SharedPtr<ffi::ContextData>,
+}
+
+impl ContextData {
+    // SEAL returns a null pointer for a missing level, e.g. past either end of the chain.
+    pub(crate) fn from_ptr(inner: cxx::SharedPtr<ffi::ContextData>) -> Option<Self> {
+        if inner.is_null() {
+            return None;
+        }
+        Some(ContextData { inner })
+    }
+
+    /// Returns the `ParmsId` of this level.
+    pub fn parms_id(&self) -> ffi::ParmsId {
+        ffi::context_data_parms_id(&self.inner)
+    }
+
+    /// Returns the index of this level in the modulus switching chain.
+    pub fn chain_index(&self) -> usize {
+        self.inner.chain_index()
+    }
+
+    /// Returns the primes of the coefficient modulus at this level.
+    pub fn coeff_modulus(&self) -> Vec<u64> {
+        ffi::context_data_coeff_modulus(&self.inner)
+    }
+
+    /// Returns the degree `n` of the polynomial modulus `x^n + 1`, the same at every level.
+    pub fn poly_modulus_degree(&self) -> usize {
+        ffi::context_data_poly_modulus_degree(&self.inner) as usize
+    }
+
+    /// Returns the plaintext modulus, the same at every level, or `0` for CKKS.
+    pub fn plain_modulus(&self) -> u64 {
+        ffi::context_data_plain_modulus(&self.inner)
+    }
+
+    /// Returns the scheme of the encryption parameters.
+    pub fn scheme(&self) -> ffi::SchemeType {
+        ffi::context_data_scheme(&self.inner)
+    }
+
+    /// Returns the bit count of the product of the coefficient modulus primes at this level.
+    pub fn total_coeff_modulus_bit_count(&self) -> i32 {
+        self.inner.total_coeff_modulus_bit_count()
+    }
+
+    /// Returns the qualifiers SEAL derived for the encryption parameters at this level.
+    pub fn qualifiers(&self) -> ffi::ParameterQualifiers {
+        ffi::context_data_qualifiers(&self.inner)
+    }
+
+    /// Returns the next level down the chain, `None` at the last level.
+    pub fn next(&self) -> Option<ContextData> {
+        ContextData::from_ptr(ffi::next_context_data(&self.inner))
+    }
+
+    /// Returns the previous level up the chain, `None` at the key level.
+    pub fn prev(&self) -> Option<ContextData> {
+        ContextData::from_ptr(ffi::prev_context_data(&self.inner))
+    }
+}
+
+// Implement Display for printing, debugging, etc.
+impl core::fmt::Display for ContextData {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "ContextData(chain_index={}, coeff_modulus_bits={})",
+            self.chain_index(),
+            self.total_coeff_modulus_bit_count()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bgv::testing::setup_bgv_context;
+
+    #[test]
+    fn test_walk_the_chain() {
+        let context = setup_bgv_context();
+        let key = context.key_context_data().expect("key level");
+        let first = key.next().expect("first data level");
+        assert_eq!(first.parms_id(), context.first_parms_id());
+        assert_eq!(first.coeff_modulus().len() + 1, key.coeff_modulus().len());
+
+        let mut level = first;
+        while let Some(next) = level.next() {
+            assert_eq!(next.chain_index() + 1, level.chain_index());
+            level = next;
+        }
+        assert_eq!(level.chain_index(), 0);
+        assert_eq!(level.parms_id(), context.last_parms_id());
+        assert!(context.key_context_data().unwrap().prev().is_none());
+    }
+
+    #[test]
+    fn test_qualifiers() {
+        let context = setup_bgv_context();
+        let qualifiers = context.first_context_data().expect("first data level").qualifiers();
+        assert!(qualifiers.parameters_set);
+        assert!(qualifiers.using_ntt);
+        assert!(qualifiers.using_batching);
+        assert_eq!(qualifiers.sec_level, 128);
+    }
+
+    #[test]
+    fn test_encryption_parameters() {
+        let context = setup_bgv_context();
+        let key = context.key_context_data().expect("key level");
+        assert_eq!(key.poly_modulus_degree(), 8192);
+        assert_eq!(key.plain_modulus(), ffi::plain_modulus_batching(8192, 20).unwrap());
+        assert_eq!(key.scheme(), ffi::SchemeType::Bgv);
+
+        let last = context.last_context_data().expect("last data level");
+        assert_eq!(last.poly_modulus_degree(), key.poly_modulus_degree());
+        assert_eq!(last.plain_modulus(), key.plain_modulus());
+    }
+}
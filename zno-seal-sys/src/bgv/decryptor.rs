@@ -0,0 +1,83 @@
+use super::*;
+
+/// Define the Rust struct to represent the C++ Decryptor class
+///
+/// A `Decryptor` holds the secret key. Besides decryption it reports the
+/// invariant noise budget of a ciphertext, which tells how much more
+/// computation the ciphertext can absorb before it no longer decrypts correctly.
+pub struct Decryptor {
+    pub(crate) inner: cxx::UniquePtr<ffi::Decryptor>,
+}
+
+impl Decryptor {
+    /// Creates a `Decryptor` for the given `Context` and `SecretKey`.
+    pub fn new(context: &Context, secret_key: &SecretKey) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::new_decryptor(&context.inner, &secret_key.inner)?)?;
+        Ok(Decryptor { inner })
+    }
+
+    /// Decrypts a `Ciphertext`.
+    pub fn decrypt(&mut self, encrypted: &Ciphertext) -> Result<Plaintext, FFIError> {
+        let inner = non_null(ffi::decrypt(self.inner.pin_mut(), &encrypted.inner)?)?;
+        Ok(Plaintext { inner })
+    }
+
+    /// Returns the invariant noise budget of a `Ciphertext`, in bits.
+    ///
+    /// A budget of `0` means the ciphertext is too noisy to decrypt correctly.
+    /// Only meaningful for the BFV and BGV schemes.
+    pub fn invariant_noise_budget(&mut self, encrypted: &Ciphertext) -> Result<i32, FFIError> {
+        Ok(self.inner.pin_mut().invariant_noise_budget(&encrypted.inner)?)
+    }
+}
+
+// Implement Display for printing, debugging, etc.
+impl core::fmt::Display for Decryptor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Decryptor") // How this type name should appear
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bgv::testing::setup_bgv_context;
+
+    #[test]
+    fn test_invariant_noise_budget() {
+        let context = setup_bgv_context();
+        let keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let encryptor = Encryptor::new(&context, &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+        let mut decryptor = Decryptor::new(&context, &keygen.secret_key().unwrap()).expect("Decryptor creation");
+
+        let encrypted = encryptor.encrypt(&Plaintext::from_hex("6").unwrap()).expect("Ciphertext");
+        assert!(decryptor.invariant_noise_budget(&encrypted).unwrap() > 0);
+    }
+
+    #[test]
+    fn test_noise_budget_shrinks_with_multiplication() {
+        let context = setup_bgv_context();
+        let keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let encryptor = Encryptor::new(&context, &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+        let mut decryptor = Decryptor::new(&context, &keygen.secret_key().unwrap()).expect("Decryptor creation");
+        let evaluator = Evaluator::new(&context).expect("Evaluator creation");
+
+        let encrypted = encryptor.encrypt(&Plaintext::from_hex("6").unwrap()).expect("Ciphertext");
+        let squared = evaluator.square(&encrypted).expect("Ciphertext");
+        assert!(decryptor.invariant_noise_budget(&squared).unwrap() < decryptor.invariant_noise_budget(&encrypted).unwrap());
+        assert_eq!(decryptor.decrypt(&squared).unwrap().to_string(), "24");
+    }
+
+    #[test]
+    fn test_decrypt_with_other_key() {
+        let context = setup_bgv_context();
+        let keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let encryptor = Encryptor::new(&context, &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+        let other = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let mut decryptor = Decryptor::new(&context, &other.secret_key().unwrap()).expect("Decryptor creation");
+
+        let encrypted = encryptor.encrypt(&Plaintext::from_hex("6").unwrap()).expect("Ciphertext");
+        assert_eq!(decryptor.invariant_noise_budget(&encrypted).unwrap(), 0);
+        assert_ne!(decryptor.decrypt(&encrypted).unwrap().to_string(), "6");
+    }
+}
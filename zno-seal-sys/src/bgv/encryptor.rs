@@ -0,0 +1,173 @@
+use super::*;
+
+/// Define the Rust struct to represent the C++ Encryptor class
+///
+/// An `Encryptor` encrypts in one of two modes:
+///
+/// - **Public-key:** created with `Encryptor::new`, encrypts with `encrypt` and `encrypt_zero`.
+///   Anyone holding the public key can encrypt.
+/// - **Symmetric:** created with `Encryptor::new_symmetric`, encrypts with `encrypt_symmetric`
+///   and `encrypt_zero_symmetric`. Only the owner of the secret key can encrypt, and the
+///   resulting ciphertexts carry less noise.
+///
+/// Both keys can be held at once, see `set_public_key` and `set_secret_key`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use zno_seal_sys::bgv::*;
+/// # let builder = ffi::set_poly_modulus_degree(ffi::init(0x3).unwrap(), 8192).unwrap();
+/// # let builder = ffi::set_coeff_modulus(builder, &ffi::coeff_modulus_bfv_default(8192).unwrap()).unwrap();
+/// # let builder = ffi::set_plain_modulus(builder, ffi::plain_modulus_batching(8192, 20).unwrap()).unwrap();
+/// # let context = Context::new(builder).expect("BGV context creation");
+/// let keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+/// let public_key = keygen.create_public_key().expect("PublicKey");
+/// let encryptor = Encryptor::new(&context, &public_key).expect("Encryptor creation");
+/// let encrypted = encryptor.encrypt(&Plaintext::from_hex("6").unwrap()).expect("Ciphertext");
+/// ```
+pub struct Encryptor {
+    pub(crate) inner: cxx::UniquePtr<ffi::Encryptor>,
+    pool: MemoryPoolHandle,
+}
+
+impl Encryptor {
+    /// Creates an `Encryptor` for public-key encryption.
+    pub fn new(context: &Context, public_key: &PublicKey) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::new_encryptor(&context.inner, &public_key.inner)?)?;
+        Ok(Encryptor { inner, pool: MemoryPoolHandle::global()? })
+    }
+
+    /// Creates an `Encryptor` for secret-key (symmetric) encryption.
+    pub fn new_symmetric(context: &Context, secret_key: &SecretKey) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::new_symmetric_encryptor(&context.inner, &secret_key.inner)?)?;
+        Ok(Encryptor { inner, pool: MemoryPoolHandle::global()? })
+    }
+
+    /// Gives a new public key to the `Encryptor`.
+    pub fn set_public_key(&mut self, public_key: &PublicKey) -> Result<(), FFIError> {
+        self.inner.pin_mut().set_public_key(&public_key.inner)?;
+        Ok(())
+    }
+
+    /// Gives a new secret key to the `Encryptor`.
+    pub fn set_secret_key(&mut self, secret_key: &SecretKey) -> Result<(), FFIError> {
+        self.inner.pin_mut().set_secret_key(&secret_key.inner)?;
+        Ok(())
+    }
+
+    /// Returns the memory pool the `Encryptor` allocates ciphertexts from.
+    pub fn pool(&self) -> &MemoryPoolHandle {
+        &self.pool
+    }
+
+    /// Allocates later ciphertexts from `pool` instead of the global memory pool.
+    ///
+    /// The seeded `_serializable` variants always use the global pool.
+    pub fn set_pool(&mut self, pool: &MemoryPoolHandle) {
+        self.pool = pool.clone();
+    }
+
+    /// Encrypts a `Plaintext` with the public key.
+    pub fn encrypt(&self, plain: &Plaintext) -> Result<Ciphertext, FFIError> {
+        let inner = non_null(ffi::encrypt(&self.inner, &plain.inner, &self.pool.inner)?)?;
+        Ok(Ciphertext { inner })
+    }
+
+    /// Encrypts a zero plaintext with the public key.
+    pub fn encrypt_zero(&self) -> Result<Ciphertext, FFIError> {
+        let inner = non_null(ffi::encrypt_zero(&self.inner, &self.pool.inner)?)?;
+        Ok(Ciphertext { inner })
+    }
+
+    /// Encrypts a `Plaintext` with the secret key.
+    pub fn encrypt_symmetric(&self, plain: &Plaintext) -> Result<Ciphertext, FFIError> {
+        let inner = non_null(ffi::encrypt_symmetric(&self.inner, &plain.inner, &self.pool.inner)?)?;
+        Ok(Ciphertext { inner })
+    }
+
+    /// Encrypts a zero plaintext with the secret key.
+    pub fn encrypt_zero_symmetric(&self) -> Result<Ciphertext, FFIError> {
+        let inner = non_null(ffi::encrypt_zero_symmetric(&self.inner, &self.pool.inner)?)?;
+        Ok(Ciphertext { inner })
+    }
+
+    /// Encrypts a `Plaintext` with the secret key, in seeded form.
+    ///
+    /// The result can only be saved; it is about half the size of the
+    /// `encrypt_symmetric` result and loads back as a `Ciphertext`.
+    pub fn encrypt_symmetric_serializable(&self, plain: &Plaintext) -> Result<Serializable<Ciphertext>, FFIError> {
+        Serializable::from_ptr(ffi::encrypt_symmetric_serializable(&self.inner, &plain.inner)?)
+    }
+
+    /// Encrypts a zero plaintext with the secret key, in seeded form.
+    pub fn encrypt_zero_symmetric_serializable(&self) -> Result<Serializable<Ciphertext>, FFIError> {
+        Serializable::from_ptr(ffi::encrypt_zero_symmetric_serializable(&self.inner)?)
+    }
+}
+
+// Implement Display for printing, debugging, etc.
+impl core::fmt::Display for Encryptor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Encryptor") // How this type name should appear
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bgv::testing::setup_bgv_context;
+
+    #[test]
+    fn test_encrypt_decrypt_public_key() {
+        let context = setup_bgv_context();
+        let keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let public_key = keygen.create_public_key().expect("PublicKey");
+        let mut decryptor = Decryptor::new(&context, &keygen.secret_key().unwrap()).expect("Decryptor creation");
+
+        let encryptor = Encryptor::new(&context, &public_key).expect("Encryptor creation");
+        let encrypted = encryptor.encrypt(&Plaintext::from_hex("1x^1 + 3").unwrap()).expect("Ciphertext");
+
+        assert_eq!(encrypted.size(), 2);
+        assert_eq!(decryptor.decrypt(&encrypted).unwrap().to_string(), "1x^1 + 3");
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_symmetric() {
+        let context = setup_bgv_context();
+        let keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let secret_key = keygen.secret_key().expect("SecretKey");
+        let mut decryptor = Decryptor::new(&context, &secret_key).expect("Decryptor creation");
+
+        let encryptor = Encryptor::new_symmetric(&context, &secret_key).expect("Encryptor creation");
+        let encrypted = encryptor.encrypt_symmetric(&Plaintext::from_hex("6").unwrap()).expect("Ciphertext");
+
+        assert_eq!(decryptor.decrypt(&encrypted).unwrap().to_string(), "6");
+    }
+
+    #[test]
+    fn test_encrypt_zero() {
+        let context = setup_bgv_context();
+        let keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let secret_key = keygen.secret_key().expect("SecretKey");
+        let mut decryptor = Decryptor::new(&context, &secret_key).expect("Decryptor creation");
+
+        let mut encryptor = Encryptor::new(&context, &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+        encryptor.set_secret_key(&secret_key).unwrap();
+
+        assert_eq!(decryptor.decrypt(&encryptor.encrypt_zero().unwrap()).unwrap().to_string(), "0");
+        assert_eq!(decryptor.decrypt(&encryptor.encrypt_zero_symmetric().unwrap()).unwrap().to_string(), "0");
+    }
+
+    #[test]
+    fn test_encrypt_without_matching_key_is_error() {
+        let context = setup_bgv_context();
+        let keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let plain = Plaintext::from_hex("6").unwrap();
+
+        let symmetric = Encryptor::new_symmetric(&context, &keygen.secret_key().unwrap()).expect("Encryptor creation");
+        assert!(matches!(symmetric.encrypt(&plain), Err(FFIError::CppException(_))));
+
+        let public = Encryptor::new(&context, &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+        assert!(matches!(public.encrypt_symmetric(&plain), Err(FFIError::CppException(_))));
+    }
+}
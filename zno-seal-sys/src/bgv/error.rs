@@ -0,0 +1,92 @@
+use core::fmt;
+use std::{fmt::{Display, Formatter}, error::Error};
+
+/// - Rust-side Null Pointer Check: On receipt of a raw pointer from C++,
+///   immediately check if it's null before converting it to a safe Rust type.
+///   If it's null, return an error.
+/// - C++-side Error Handling: C++ functions called from Rust should signal
+///   when an error has occurred. This could be returning a null pointer,
+///   or some other error signaling mechanism.
+/// - Error Type: The `NullPointerError` type represents an error in the
+///   case of a null pointer.
+///
+/// A more general FFIError type represents other kinds of errors that can occur in the FFI context.
+
+// `NullPointerError`: An error type for null pointer exceptions
+#[derive(Debug, Clone)]
+pub struct NullPointerError;
+
+impl std::error::Error for NullPointerError {}
+
+impl Display for NullPointerError {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        write!(f, "Received null pointer from C++ library")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum FFIError {
+    NullPointer(NullPointerError),
+    // Other FFI-related errors can be added here
+    CppException(String), // This can represent an exception thrown by C++
+    Io(String), // Reading or writing a serialized object failed
+    InvalidData(String), // A loaded object is not valid for the context, see `ValCheck`
+    // ...
+}
+
+impl Error for FFIError {}
+
+impl Display for FFIError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            FFIError::NullPointer(err) => write!(f, "Null pointer error: {}", err),
+            FFIError::CppException(err) => write!(f, "C++ exception: {}", err),
+            FFIError::Io(err) => write!(f, "I/O error: {}", err),
+            FFIError::InvalidData(err) => write!(f, "Invalid data: {}", err),
+            // other cases as needed
+        }
+    }
+}
+
+// SEAL signals invalid arguments and parameters by throwing, e.g. `std::invalid_argument`
+// or `std::logic_error`. The bridge functions return `Result<_, cxx::Exception>`, so the
+// exception message is caught and carried over here instead of aborting the process.
+impl From<cxx::Exception> for FFIError {
+    fn from(err: cxx::Exception) -> Self {
+        FFIError::CppException(err.what().to_string())
+    }
+}
+
+impl From<std::io::Error> for FFIError {
+    fn from(err: std::io::Error) -> Self {
+        FFIError::Io(err.to_string())
+    }
+}
+
+/// Checks a `UniquePtr` received from C++ and returns it unchanged when it is not null.
+///
+/// # Errors
+///
+/// Returns `FFIError::NullPointer` when the C++ side returned a null pointer.
+pub(crate) fn non_null<T>(ptr: cxx::UniquePtr<T>) -> Result<cxx::UniquePtr<T>, FFIError>
+where
+    T: cxx::memory::UniquePtrTarget,
+{
+    if ptr.is_null() {
+        return Err(FFIError::NullPointer(NullPointerError));
+    }
+    Ok(ptr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bgv::ffi;
+
+    #[test]
+    fn test_cpp_exception_is_returned() {
+        // A 61-bit prime is rejected by SEAL with `std::invalid_argument`.
+        let result = ffi::coeff_modulus_create(8192, &[61]).map_err(FFIError::from);
+        assert!(matches!(result, Err(FFIError::CppException(_))));
+    }
+}
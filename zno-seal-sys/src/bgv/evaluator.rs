@@ -0,0 +1,444 @@
+use super::*;
+
+/// Define the Rust struct to represent the C++ Evaluator class
+///
+/// An `Evaluator` computes on ciphertexts without access to the secret key.
+/// Every operation comes in two forms: one that returns a new `Ciphertext`,
+/// and an `_inplace` one that overwrites its first operand. Both return
+/// `FFIError::CppException` when SEAL rejects the operands, for example when
+/// they are at different levels or a required key is missing.
+///
+/// Multiplication grows the size of a ciphertext, so relinearize after each
+/// `multiply` or `square`. Every operation consumes noise budget, and
+/// `mod_switch_to_next` trades a level of the modulus chain for smaller,
+/// faster ciphertexts.
+///
+/// # Example
+///
+/// ```no_run
+/// # use zno_seal_sys::bgv::*;
+/// let builder = ffi::set_poly_modulus_degree(ffi::init(0x3).unwrap(), 8192).unwrap();
+/// let builder = ffi::set_coeff_modulus(builder, &ffi::coeff_modulus_bfv_default(8192).unwrap()).unwrap();
+/// let builder = ffi::set_plain_modulus(builder, ffi::plain_modulus_batching(8192, 20).unwrap()).unwrap();
+/// let context = Context::new(builder).expect("BGV context creation");
+/// let mut keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+/// let relin_keys = keygen.create_relin_keys().expect("RelinKeys");
+/// let encryptor = Encryptor::new(&context, &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+/// let evaluator = Evaluator::new(&context).expect("Evaluator creation");
+///
+/// let x = encryptor.encrypt(&Plaintext::from_hex("3").unwrap()).expect("Ciphertext");
+/// let mut x_squared = evaluator.square(&x).expect("Ciphertext");
+/// evaluator.relinearize_inplace(&mut x_squared, &relin_keys).unwrap();
+/// ```
+pub struct Evaluator {
+    pub(crate) inner: cxx::UniquePtr<ffi::Evaluator>,
+    pool: MemoryPoolHandle,
+}
+
+impl Evaluator {
+    /// Creates an `Evaluator` for a built `Context`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::NullPointer` if the C++ side did not return an evaluator,
+    /// or `FFIError::CppException` if the encryption parameters are not valid.
+    pub fn new(context: &Context) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::new_evaluator(&context.inner)?)?;
+        Ok(Evaluator { inner, pool: MemoryPoolHandle::global()? })
+    }
+
+    /// Returns the memory pool the `Evaluator` allocates from.
+    pub fn pool(&self) -> &MemoryPoolHandle {
+        &self.pool
+    }
+
+    /// Allocates the results and temporaries of later operations from `pool`
+    /// instead of the global memory pool.
+    pub fn set_pool(&mut self, pool: &MemoryPoolHandle) {
+        self.pool = pool.clone();
+    }
+
+    /// Negates a `Ciphertext`.
+    pub fn negate(&self, encrypted: &Ciphertext) -> Result<Ciphertext, FFIError> {
+        let inner = non_null(ffi::negate(&self.inner, &encrypted.inner)?)?;
+        Ok(Ciphertext { inner })
+    }
+
+    /// Negates a `Ciphertext` in place.
+    pub fn negate_inplace(&self, encrypted: &mut Ciphertext) -> Result<(), FFIError> {
+        ffi::negate_inplace(&self.inner, encrypted.inner.pin_mut())?;
+        Ok(())
+    }
+
+    /// Adds two ciphertexts.
+    pub fn add(&self, encrypted1: &Ciphertext, encrypted2: &Ciphertext) -> Result<Ciphertext, FFIError> {
+        let inner = non_null(ffi::add(&self.inner, &encrypted1.inner, &encrypted2.inner)?)?;
+        Ok(Ciphertext { inner })
+    }
+
+    /// Adds `encrypted2` to `encrypted1` in place.
+    pub fn add_inplace(&self, encrypted1: &mut Ciphertext, encrypted2: &Ciphertext) -> Result<(), FFIError> {
+        ffi::add_inplace(&self.inner, encrypted1.inner.pin_mut(), &encrypted2.inner)?;
+        Ok(())
+    }
+
+    /// Subtracts `encrypted2` from `encrypted1`.
+    pub fn sub(&self, encrypted1: &Ciphertext, encrypted2: &Ciphertext) -> Result<Ciphertext, FFIError> {
+        let inner = non_null(ffi::sub(&self.inner, &encrypted1.inner, &encrypted2.inner)?)?;
+        Ok(Ciphertext { inner })
+    }
+
+    /// Subtracts `encrypted2` from `encrypted1` in place.
+    pub fn sub_inplace(&self, encrypted1: &mut Ciphertext, encrypted2: &Ciphertext) -> Result<(), FFIError> {
+        ffi::sub_inplace(&self.inner, encrypted1.inner.pin_mut(), &encrypted2.inner)?;
+        Ok(())
+    }
+
+    /// Multiplies two ciphertexts.
+    ///
+    /// The result has size `encrypted1.size() + encrypted2.size() - 1`.
+    pub fn multiply(&self, encrypted1: &Ciphertext, encrypted2: &Ciphertext) -> Result<Ciphertext, FFIError> {
+        let inner = non_null(ffi::multiply(&self.inner, &encrypted1.inner, &encrypted2.inner, &self.pool.inner)?)?;
+        Ok(Ciphertext { inner })
+    }
+
+    /// Multiplies `encrypted1` by `encrypted2` in place.
+    pub fn multiply_inplace(&self, encrypted1: &mut Ciphertext, encrypted2: &Ciphertext) -> Result<(), FFIError> {
+        ffi::multiply_inplace(&self.inner, encrypted1.inner.pin_mut(), &encrypted2.inner, &self.pool.inner)?;
+        Ok(())
+    }
+
+    /// Squares a `Ciphertext`. Faster than multiplying it by itself.
+    pub fn square(&self, encrypted: &Ciphertext) -> Result<Ciphertext, FFIError> {
+        let inner = non_null(ffi::square(&self.inner, &encrypted.inner, &self.pool.inner)?)?;
+        Ok(Ciphertext { inner })
+    }
+
+    /// Squares a `Ciphertext` in place.
+    pub fn square_inplace(&self, encrypted: &mut Ciphertext) -> Result<(), FFIError> {
+        ffi::square_inplace(&self.inner, encrypted.inner.pin_mut(), &self.pool.inner)?;
+        Ok(())
+    }
+
+    /// Adds a `Plaintext` to a `Ciphertext`.
+    pub fn add_plain(&self, encrypted: &Ciphertext, plain: &Plaintext) -> Result<Ciphertext, FFIError> {
+        let inner = non_null(ffi::add_plain(&self.inner, &encrypted.inner, &plain.inner, &self.pool.inner)?)?;
+        Ok(Ciphertext { inner })
+    }
+
+    /// Adds a `Plaintext` to a `Ciphertext` in place.
+    pub fn add_plain_inplace(&self, encrypted: &mut Ciphertext, plain: &Plaintext) -> Result<(), FFIError> {
+        ffi::add_plain_inplace(&self.inner, encrypted.inner.pin_mut(), &plain.inner, &self.pool.inner)?;
+        Ok(())
+    }
+
+    /// Subtracts a `Plaintext` from a `Ciphertext`.
+    pub fn sub_plain(&self, encrypted: &Ciphertext, plain: &Plaintext) -> Result<Ciphertext, FFIError> {
+        let inner = non_null(ffi::sub_plain(&self.inner, &encrypted.inner, &plain.inner, &self.pool.inner)?)?;
+        Ok(Ciphertext { inner })
+    }
+
+    /// Subtracts a `Plaintext` from a `Ciphertext` in place.
+    pub fn sub_plain_inplace(&self, encrypted: &mut Ciphertext, plain: &Plaintext) -> Result<(), FFIError> {
+        ffi::sub_plain_inplace(&self.inner, encrypted.inner.pin_mut(), &plain.inner, &self.pool.inner)?;
+        Ok(())
+    }
+
+    /// Multiplies a `Ciphertext` by a `Plaintext`.
+    ///
+    /// With CKKS, the plaintext must be encoded at the level of the ciphertext,
+    /// see `CKKSEncoder::encode_f64_at`, and the scale of the result is the
+    /// product of both scales.
+    pub fn multiply_plain(&self, encrypted: &Ciphertext, plain: &Plaintext) -> Result<Ciphertext, FFIError> {
+        let inner = non_null(ffi::multiply_plain(&self.inner, &encrypted.inner, &plain.inner, &self.pool.inner)?)?;
+        Ok(Ciphertext { inner })
+    }
+
+    /// Multiplies a `Ciphertext` by a `Plaintext` in place.
+    pub fn multiply_plain_inplace(&self, encrypted: &mut Ciphertext, plain: &Plaintext) -> Result<(), FFIError> {
+        ffi::multiply_plain_inplace(&self.inner, encrypted.inner.pin_mut(), &plain.inner, &self.pool.inner)?;
+        Ok(())
+    }
+
+    /// Relinearizes a `Ciphertext` back to size 2.
+    pub fn relinearize(&self, encrypted: &Ciphertext, relin_keys: &RelinKeys) -> Result<Ciphertext, FFIError> {
+        let inner = non_null(ffi::relinearize(&self.inner, &encrypted.inner, &relin_keys.inner, &self.pool.inner)?)?;
+        Ok(Ciphertext { inner })
+    }
+
+    /// Relinearizes a `Ciphertext` back to size 2 in place.
+    pub fn relinearize_inplace(&self, encrypted: &mut Ciphertext, relin_keys: &RelinKeys) -> Result<(), FFIError> {
+        ffi::relinearize_inplace(&self.inner, encrypted.inner.pin_mut(), &relin_keys.inner, &self.pool.inner)?;
+        Ok(())
+    }
+
+    /// Switches a `Ciphertext` down to the next level of the modulus chain.
+    pub fn mod_switch_to_next(&self, encrypted: &Ciphertext) -> Result<Ciphertext, FFIError> {
+        let inner = non_null(ffi::mod_switch_to_next(&self.inner, &encrypted.inner, &self.pool.inner)?)?;
+        Ok(Ciphertext { inner })
+    }
+
+    /// Switches a `Ciphertext` down to the next level of the modulus chain in place.
+    pub fn mod_switch_to_next_inplace(&self, encrypted: &mut Ciphertext) -> Result<(), FFIError> {
+        ffi::mod_switch_to_next_inplace(&self.inner, encrypted.inner.pin_mut(), &self.pool.inner)?;
+        Ok(())
+    }
+
+    /// Switches a `Ciphertext` down to the level identified by `parms_id`.
+    pub fn mod_switch_to(&self, encrypted: &Ciphertext, parms_id: &ffi::ParmsId) -> Result<Ciphertext, FFIError> {
+        let inner = non_null(ffi::mod_switch_to(&self.inner, &encrypted.inner, parms_id, &self.pool.inner)?)?;
+        Ok(Ciphertext { inner })
+    }
+
+    /// Switches a `Ciphertext` down to the level identified by `parms_id` in place.
+    pub fn mod_switch_to_inplace(&self, encrypted: &mut Ciphertext, parms_id: &ffi::ParmsId) -> Result<(), FFIError> {
+        ffi::mod_switch_to_inplace(&self.inner, encrypted.inner.pin_mut(), parms_id, &self.pool.inner)?;
+        Ok(())
+    }
+
+    /// Switches a CKKS `Ciphertext` down to the next level of the modulus
+    /// chain, dividing its scale by the prime that was dropped.
+    ///
+    /// Rescale after every multiplication to keep the scale from growing.
+    pub fn rescale_to_next(&self, encrypted: &Ciphertext) -> Result<Ciphertext, FFIError> {
+        let inner = non_null(ffi::rescale_to_next(&self.inner, &encrypted.inner, &self.pool.inner)?)?;
+        Ok(Ciphertext { inner })
+    }
+
+    /// Switches a CKKS `Ciphertext` down to the next level of the modulus chain and rescales it in place.
+    pub fn rescale_to_next_inplace(&self, encrypted: &mut Ciphertext) -> Result<(), FFIError> {
+        ffi::rescale_to_next_inplace(&self.inner, encrypted.inner.pin_mut(), &self.pool.inner)?;
+        Ok(())
+    }
+
+    /// Rotates both rows of a batched `Ciphertext` cyclically by `steps`.
+    ///
+    /// Positive steps rotate left, negative steps rotate right.
+    pub fn rotate_rows(&self, encrypted: &Ciphertext, steps: i32, galois_keys: &GaloisKeys) -> Result<Ciphertext, FFIError> {
+        let inner = non_null(ffi::rotate_rows(&self.inner, &encrypted.inner, steps, &galois_keys.inner, &self.pool.inner)?)?;
+        Ok(Ciphertext { inner })
+    }
+
+    /// Rotates both rows of a batched `Ciphertext` cyclically by `steps` in place.
+    pub fn rotate_rows_inplace(&self, encrypted: &mut Ciphertext, steps: i32, galois_keys: &GaloisKeys) -> Result<(), FFIError> {
+        ffi::rotate_rows_inplace(&self.inner, encrypted.inner.pin_mut(), steps, &galois_keys.inner, &self.pool.inner)?;
+        Ok(())
+    }
+
+    /// Swaps the two rows of a batched `Ciphertext`.
+    pub fn rotate_columns(&self, encrypted: &Ciphertext, galois_keys: &GaloisKeys) -> Result<Ciphertext, FFIError> {
+        let inner = non_null(ffi::rotate_columns(&self.inner, &encrypted.inner, &galois_keys.inner, &self.pool.inner)?)?;
+        Ok(Ciphertext { inner })
+    }
+
+    /// Swaps the two rows of a batched `Ciphertext` in place.
+    pub fn rotate_columns_inplace(&self, encrypted: &mut Ciphertext, galois_keys: &GaloisKeys) -> Result<(), FFIError> {
+        ffi::rotate_columns_inplace(&self.inner, encrypted.inner.pin_mut(), &galois_keys.inner, &self.pool.inner)?;
+        Ok(())
+    }
+
+    /// Applies the Galois automorphism `galois_elt` to a `Ciphertext`.
+    ///
+    /// `galois_elt` must be odd and less than `2 * poly_modulus_degree`, and
+    /// `galois_keys` must hold a key for it.
+    pub fn apply_galois(&self, encrypted: &Ciphertext, galois_elt: u32, galois_keys: &GaloisKeys) -> Result<Ciphertext, FFIError> {
+        let inner = non_null(ffi::apply_galois(&self.inner, &encrypted.inner, galois_elt, &galois_keys.inner, &self.pool.inner)?)?;
+        Ok(Ciphertext { inner })
+    }
+
+    /// Applies the Galois automorphism `galois_elt` to a `Ciphertext` in place.
+    pub fn apply_galois_inplace(&self, encrypted: &mut Ciphertext, galois_elt: u32, galois_keys: &GaloisKeys) -> Result<(), FFIError> {
+        ffi::apply_galois_inplace(&self.inner, encrypted.inner.pin_mut(), galois_elt, &galois_keys.inner, &self.pool.inner)?;
+        Ok(())
+    }
+
+    /// Raises a `Ciphertext` to the power `exponent`, relinearizing after every multiplication.
+    pub fn exponentiate(&self, encrypted: &Ciphertext, exponent: u64, relin_keys: &RelinKeys) -> Result<Ciphertext, FFIError> {
+        let inner = non_null(ffi::exponentiate(&self.inner, &encrypted.inner, exponent, &relin_keys.inner, &self.pool.inner)?)?;
+        Ok(Ciphertext { inner })
+    }
+
+    /// Raises a `Ciphertext` to the power `exponent` in place.
+    pub fn exponentiate_inplace(&self, encrypted: &mut Ciphertext, exponent: u64, relin_keys: &RelinKeys) -> Result<(), FFIError> {
+        ffi::exponentiate_inplace(&self.inner, encrypted.inner.pin_mut(), exponent, &relin_keys.inner, &self.pool.inner)?;
+        Ok(())
+    }
+}
+
+// Implement Display for printing, debugging, etc.
+impl core::fmt::Display for Evaluator {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Evaluator") // How this type name should appear
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bgv::testing::setup_bgv_context;
+
+    #[test]
+    fn test_add_multiply_relinearize() {
+        let context = setup_bgv_context();
+        let mut keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let relin_keys = keygen.create_relin_keys().expect("RelinKeys");
+        let encryptor = Encryptor::new(&context, &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+        let mut decryptor = Decryptor::new(&context, &keygen.secret_key().unwrap()).expect("Decryptor creation");
+        let evaluator = Evaluator::new(&context).expect("Evaluator creation");
+
+        let two = encryptor.encrypt(&Plaintext::from_hex("2").unwrap()).expect("Ciphertext");
+        let three = encryptor.encrypt(&Plaintext::from_hex("3").unwrap()).expect("Ciphertext");
+
+        let sum = evaluator.add(&two, &three).expect("Ciphertext");
+        assert_eq!(decryptor.decrypt(&sum).unwrap().to_string(), "5");
+
+        let mut product = evaluator.multiply(&two, &three).expect("Ciphertext");
+        assert_eq!(product.size(), 3);
+        evaluator.relinearize_inplace(&mut product, &relin_keys).unwrap();
+        assert_eq!(product.size(), 2);
+        assert_eq!(decryptor.decrypt(&product).unwrap().to_string(), "6");
+
+        let cubed = evaluator.exponentiate(&two, 3, &relin_keys).expect("Ciphertext");
+        assert_eq!(decryptor.decrypt(&cubed).unwrap().to_string(), "8");
+    }
+
+    #[test]
+    fn test_plain_operations() {
+        let context = setup_bgv_context();
+        let keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let encryptor = Encryptor::new(&context, &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+        let mut decryptor = Decryptor::new(&context, &keygen.secret_key().unwrap()).expect("Decryptor creation");
+        let evaluator = Evaluator::new(&context).expect("Evaluator creation");
+
+        let mut encrypted = encryptor.encrypt(&Plaintext::from_hex("4").unwrap()).expect("Ciphertext");
+        let plain = Plaintext::from_hex("2").unwrap();
+
+        evaluator.add_plain_inplace(&mut encrypted, &plain).unwrap();
+        assert_eq!(decryptor.decrypt(&encrypted).unwrap().to_string(), "6");
+        evaluator.multiply_plain_inplace(&mut encrypted, &plain).unwrap();
+        assert_eq!(decryptor.decrypt(&encrypted).unwrap().to_string(), "C");
+        evaluator.sub_plain_inplace(&mut encrypted, &plain).unwrap();
+        assert_eq!(decryptor.decrypt(&encrypted).unwrap().to_string(), "A");
+    }
+
+    #[test]
+    fn test_rotations_restore_the_original() {
+        let context = setup_bgv_context();
+        let mut keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let galois_keys = keygen.create_galois_keys_from_steps(&[1, -1, 0]).expect("GaloisKeys");
+        let encryptor = Encryptor::new(&context, &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+        let mut decryptor = Decryptor::new(&context, &keygen.secret_key().unwrap()).expect("Decryptor creation");
+        let evaluator = Evaluator::new(&context).expect("Evaluator creation");
+
+        let encrypted = encryptor.encrypt(&Plaintext::from_hex("1x^1 + 3").unwrap()).expect("Ciphertext");
+
+        let mut rotated = evaluator.rotate_rows(&encrypted, 1, &galois_keys).expect("Ciphertext");
+        evaluator.rotate_rows_inplace(&mut rotated, -1, &galois_keys).unwrap();
+        assert_eq!(decryptor.decrypt(&rotated).unwrap().to_string(), "1x^1 + 3");
+
+        let mut swapped = evaluator.rotate_columns(&encrypted, &galois_keys).expect("Ciphertext");
+        evaluator.rotate_columns_inplace(&mut swapped, &galois_keys).unwrap();
+        assert_eq!(decryptor.decrypt(&swapped).unwrap().to_string(), "1x^1 + 3");
+    }
+
+    #[test]
+    fn test_mod_switch_to_next() {
+        let context = setup_bgv_context();
+        let keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let encryptor = Encryptor::new(&context, &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+        let mut decryptor = Decryptor::new(&context, &keygen.secret_key().unwrap()).expect("Decryptor creation");
+        let evaluator = Evaluator::new(&context).expect("Evaluator creation");
+
+        let encrypted = encryptor.encrypt(&Plaintext::from_hex("7").unwrap()).expect("Ciphertext");
+        let switched = evaluator.mod_switch_to_next(&encrypted).expect("Ciphertext");
+
+        assert_ne!(switched.parms_id(), encrypted.parms_id());
+        assert_eq!(decryptor.decrypt(&switched).unwrap().to_string(), "7");
+
+        let mut negated = evaluator.negate(&switched).expect("Ciphertext");
+        evaluator.negate_inplace(&mut negated).unwrap();
+        let mut same_level = encrypted;
+        evaluator.mod_switch_to_inplace(&mut same_level, &switched.parms_id()).unwrap();
+        assert_eq!(same_level.parms_id(), negated.parms_id());
+    }
+
+    #[test]
+    fn test_mismatched_levels_is_error() {
+        let context = setup_bgv_context();
+        let keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let encryptor = Encryptor::new(&context, &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+        let evaluator = Evaluator::new(&context).expect("Evaluator creation");
+
+        let encrypted = encryptor.encrypt(&Plaintext::from_hex("7").unwrap()).expect("Ciphertext");
+        let switched = evaluator.mod_switch_to_next(&encrypted).expect("Ciphertext");
+
+        assert!(matches!(evaluator.add(&encrypted, &switched), Err(FFIError::CppException(_))));
+    }
+
+    #[test]
+    fn test_in_place_matches_out_of_place() {
+        let context = setup_bgv_context();
+        let mut keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let relin_keys = keygen.create_relin_keys().expect("RelinKeys");
+        let encryptor = Encryptor::new(&context, &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+        let mut decryptor = Decryptor::new(&context, &keygen.secret_key().unwrap()).expect("Decryptor creation");
+        let evaluator = Evaluator::new(&context).expect("Evaluator creation");
+
+        let five = encryptor.encrypt(&Plaintext::from_hex("5").unwrap()).expect("Ciphertext");
+        let three = encryptor.encrypt(&Plaintext::from_hex("3").unwrap()).expect("Ciphertext");
+
+        let mut encrypted = evaluator.sub(&five, &three).expect("Ciphertext");
+        assert_eq!(decryptor.decrypt(&encrypted).unwrap().to_string(), "2");
+        evaluator.add_inplace(&mut encrypted, &three).unwrap();
+        evaluator.add_inplace(&mut encrypted, &three).unwrap();
+        evaluator.sub_inplace(&mut encrypted, &three).unwrap();
+        assert_eq!(decryptor.decrypt(&encrypted).unwrap().to_string(), "5");
+
+        evaluator.multiply_inplace(&mut encrypted, &three).unwrap();
+        encrypted = evaluator.relinearize(&encrypted, &relin_keys).expect("Ciphertext");
+        assert_eq!(decryptor.decrypt(&encrypted).unwrap().to_string(), "F");
+
+        let squared = evaluator.square(&three).expect("Ciphertext");
+        let mut three_squared = three;
+        evaluator.square_inplace(&mut three_squared).unwrap();
+        assert_eq!(decryptor.decrypt(&squared).unwrap().to_string(), "9");
+        assert_eq!(decryptor.decrypt(&three_squared).unwrap().to_string(), "9");
+
+        let mut cubed = five;
+        evaluator.exponentiate_inplace(&mut cubed, 3, &relin_keys).unwrap();
+        assert_eq!(decryptor.decrypt(&cubed).unwrap().to_string(), "7D");
+
+        let plain = Plaintext::from_hex("2").unwrap();
+        let sum = evaluator.add_plain(&cubed, &plain).expect("Ciphertext");
+        let difference = evaluator.sub_plain(&sum, &plain).expect("Ciphertext");
+        let product = evaluator.multiply_plain(&difference, &plain).expect("Ciphertext");
+        assert_eq!(decryptor.decrypt(&product).unwrap().to_string(), "FA");
+
+        let mut switched = product;
+        evaluator.mod_switch_to_next_inplace(&mut switched).unwrap();
+        assert_eq!(decryptor.decrypt(&switched).unwrap().to_string(), "FA");
+        assert!(evaluator.mod_switch_to(&cubed, &switched.parms_id()).is_ok());
+    }
+
+    #[test]
+    fn test_apply_galois() {
+        let context = setup_bgv_context();
+        let mut keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let galois_keys = keygen.create_galois_keys().expect("GaloisKeys");
+        let encryptor = Encryptor::new(&context, &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+        let mut decryptor = Decryptor::new(&context, &keygen.secret_key().unwrap()).expect("Decryptor creation");
+        let evaluator = Evaluator::new(&context).expect("Evaluator creation");
+
+        let encrypted = encryptor.encrypt(&Plaintext::from_hex("1x^1 + 3").unwrap()).expect("Ciphertext");
+
+        // 2n - 1 is the Galois element of `rotate_columns`, which is an involution.
+        let galois_elt = 2 * 8192 - 1;
+        let mut swapped = evaluator.apply_galois(&encrypted, galois_elt, &galois_keys).expect("Ciphertext");
+        assert_ne!(decryptor.decrypt(&swapped).unwrap().to_string(), "1x^1 + 3");
+        evaluator.apply_galois_inplace(&mut swapped, galois_elt, &galois_keys).unwrap();
+        assert_eq!(decryptor.decrypt(&swapped).unwrap().to_string(), "1x^1 + 3");
+
+        // Galois elements must be odd.
+        assert!(matches!(evaluator.apply_galois(&encrypted, 2, &galois_keys), Err(FFIError::CppException(_))));
+    }
+}
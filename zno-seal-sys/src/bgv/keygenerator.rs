@@ -0,0 +1,167 @@
+use super::*;
+
+/// Define the Rust struct to represent the C++ KeyGenerator class
+///
+/// A `KeyGenerator` samples a fresh secret key when it is created, and then
+/// derives the public, relinearization and Galois keys from that secret key.
+///
+/// The C++ object holds its own copy of the `Context`, which shares the
+/// underlying context data. The `Context` can therefore be dropped before the
+/// `KeyGenerator`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use zno_seal_sys::bgv::*;
+/// let builder = ffi::set_poly_modulus_degree(ffi::init(0x3).unwrap(), 8192).unwrap();
+/// let builder = ffi::set_coeff_modulus(builder, &ffi::coeff_modulus_bfv_default(8192).unwrap()).unwrap();
+/// let builder = ffi::set_plain_modulus(builder, ffi::plain_modulus_batching(8192, 20).unwrap()).unwrap();
+/// let context = Context::new(builder).expect("BGV context creation");
+/// let mut keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+/// let secret_key = keygen.secret_key().expect("SecretKey");
+/// let public_key = keygen.create_public_key().expect("PublicKey");
+/// let relin_keys = keygen.create_relin_keys().expect("RelinKeys");
+/// let galois_keys = keygen.create_galois_keys().expect("GaloisKeys");
+/// ```
+pub struct KeyGenerator {
+    pub(crate) inner: cxx::UniquePtr<ffi::KeyGenerator>,
+}
+
+impl KeyGenerator {
+    /// Creates a `KeyGenerator` for a built `Context`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::NullPointer` if the C++ side did not return a key generator,
+    /// or `FFIError::CppException` if the encryption parameters are not valid.
+    pub fn new(context: &Context) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::new_key_generator(&context.inner)?)?;
+        Ok(KeyGenerator { inner })
+    }
+
+    /// Returns a copy of the secret key held by this `KeyGenerator`.
+    pub fn secret_key(&self) -> Result<SecretKey, FFIError> {
+        let inner = non_null(ffi::get_secret_key(&self.inner)?)?;
+        Ok(SecretKey { inner })
+    }
+
+    /// Creates a new public key from the secret key.
+    ///
+    /// Every call samples new randomness, so two public keys created by the
+    /// same `KeyGenerator` differ, but both encrypt for the same secret key.
+    pub fn create_public_key(&self) -> Result<PublicKey, FFIError> {
+        let inner = non_null(ffi::create_public_key(&self.inner)?)?;
+        Ok(PublicKey { inner })
+    }
+
+    /// Creates new relinearization keys from the secret key.
+    pub fn create_relin_keys(&mut self) -> Result<RelinKeys, FFIError> {
+        let inner = non_null(ffi::create_relin_keys(self.inner.pin_mut())?)?;
+        Ok(RelinKeys { inner })
+    }
+
+    /// Creates new Galois keys for every rotation step.
+    ///
+    /// The result is large, roughly `2 * log2(n)` key-switching keys.
+    /// Prefer `create_galois_keys_from_steps` when only a few rotations are needed.
+    pub fn create_galois_keys(&mut self) -> Result<GaloisKeys, FFIError> {
+        let inner = non_null(ffi::create_galois_keys(self.inner.pin_mut())?)?;
+        Ok(GaloisKeys { inner })
+    }
+
+    /// Creates new Galois keys for the chosen rotation steps only.
+    ///
+    /// Positive steps rotate left, negative steps rotate right, and a step of
+    /// `0` selects the column swap.
+    pub fn create_galois_keys_from_steps(&mut self, steps: &[i32]) -> Result<GaloisKeys, FFIError> {
+        let inner = non_null(ffi::create_galois_keys_with_steps(self.inner.pin_mut(), steps)?)?;
+        Ok(GaloisKeys { inner })
+    }
+
+    /// Creates a new public key in seeded form.
+    ///
+    /// The result can only be saved; it is about half the size of a saved
+    /// `create_public_key` result and loads back as a `PublicKey`.
+    pub fn create_public_key_serializable(&self) -> Result<Serializable<PublicKey>, FFIError> {
+        Serializable::from_ptr(ffi::create_serializable_public_key(&self.inner)?)
+    }
+
+    /// Creates new relinearization keys in seeded form, see `create_public_key_serializable`.
+    pub fn create_relin_keys_serializable(&mut self) -> Result<Serializable<RelinKeys>, FFIError> {
+        Serializable::from_ptr(ffi::create_serializable_relin_keys(self.inner.pin_mut())?)
+    }
+
+    /// Creates new Galois keys for every rotation step in seeded form, see `create_public_key_serializable`.
+    pub fn create_galois_keys_serializable(&mut self) -> Result<Serializable<GaloisKeys>, FFIError> {
+        Serializable::from_ptr(ffi::create_serializable_galois_keys(self.inner.pin_mut())?)
+    }
+}
+
+// Implement Display for printing, debugging, etc.
+impl core::fmt::Display for KeyGenerator {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "KeyGenerator") // How this type name should appear
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bgv::testing::setup_bgv_context;
+
+    #[test]
+    fn test_key_generator_new() {
+        let context = setup_bgv_context();
+        let keygen = KeyGenerator::new(&context);
+        assert!(keygen.is_ok());
+    }
+
+    #[test]
+    fn test_create_keys() {
+        let context = setup_bgv_context();
+        let mut keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        assert!(keygen.secret_key().is_ok());
+        assert!(keygen.create_public_key().is_ok());
+        assert!(keygen.create_relin_keys().is_ok());
+    }
+
+    #[test]
+    fn test_create_galois_keys_from_steps() {
+        let context = setup_bgv_context();
+        let mut keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        assert!(keygen.create_galois_keys_from_steps(&[1, -1, 0]).is_ok());
+        assert!(keygen.create_galois_keys().is_ok());
+    }
+
+    #[test]
+    fn test_secret_key_is_stable() {
+        let context = setup_bgv_context();
+        let keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let first = keygen.secret_key().unwrap().save(ComprMode::None).unwrap();
+        let second = keygen.secret_key().unwrap().save(ComprMode::None).unwrap();
+        assert_eq!(first, second);
+
+        let other = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        assert_ne!(first, other.secret_key().unwrap().save(ComprMode::None).unwrap());
+    }
+
+    #[test]
+    fn test_keys_work_together() {
+        let context = setup_bgv_context();
+        let mut keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let relin_keys = keygen.create_relin_keys().expect("RelinKeys");
+        let galois_keys = keygen.create_galois_keys_from_steps(&[1]).expect("GaloisKeys");
+        let encryptor = Encryptor::new(&context, &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+        let mut decryptor = Decryptor::new(&context, &keygen.secret_key().unwrap()).expect("Decryptor creation");
+        let evaluator = Evaluator::new(&context).expect("Evaluator creation");
+
+        let encrypted = encryptor.encrypt(&Plaintext::from_hex("3").unwrap()).expect("Ciphertext");
+        let mut squared = evaluator.square(&encrypted).unwrap();
+        evaluator.relinearize_inplace(&mut squared, &relin_keys).unwrap();
+        assert_eq!(decryptor.decrypt(&squared).unwrap().to_string(), "9");
+
+        // Only the requested steps get a key.
+        assert!(evaluator.rotate_rows(&encrypted, 1, &galois_keys).is_ok());
+        assert!(matches!(evaluator.rotate_rows(&encrypted, 2, &galois_keys), Err(FFIError::CppException(_))));
+    }
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+/// Define the Rust struct to represent the C++ SecretKey class
+///
+/// The secret key is required to decrypt, and to create every other key.
+/// It must never leave the party that owns it.
+pub struct SecretKey {
+    pub(crate) inner: cxx::UniquePtr<ffi::SecretKey>,
+}
+
+/// Define the Rust struct to represent the C++ PublicKey class
+///
+/// The public key is used to encrypt, and may be shared freely.
+pub struct PublicKey {
+    pub(crate) inner: cxx::UniquePtr<ffi::PublicKey>,
+}
+
+/// Define the Rust struct to represent the C++ RelinKeys class
+///
+/// Relinearization keys reduce the size of a ciphertext back to 2
+/// after a multiplication.
+pub struct RelinKeys {
+    pub(crate) inner: cxx::UniquePtr<ffi::RelinKeys>,
+}
+
+/// Define the Rust struct to represent the C++ GaloisKeys class
+///
+/// Galois keys are required for the rotation and Galois automorphism
+/// operations on batched ciphertexts.
+pub struct GaloisKeys {
+    pub(crate) inner: cxx::UniquePtr<ffi::GaloisKeys>,
+}
+
+// Implement Display for printing, debugging, etc.
+// The key material itself is never printed.
+impl core::fmt::Display for SecretKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SecretKey")
+    }
+}
+
+impl core::fmt::Display for PublicKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "PublicKey")
+    }
+}
+
+impl core::fmt::Display for RelinKeys {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "RelinKeys")
+    }
+}
+
+impl core::fmt::Display for GaloisKeys {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "GaloisKeys")
+    }
+}
@@ -0,0 +1,131 @@
+use super::*;
+
+/// Define the Rust struct to represent the C++ MemoryPoolHandle class
+///
+/// SEAL allocates the temporaries of every operation from a memory pool. By
+/// default this is the global pool, which grows but never shrinks for the
+/// life of the process. A `MemoryPoolHandle` selects another pool:
+///
+/// - `MemoryPoolHandle::global()` shares the process-wide pool.
+/// - `MemoryPoolHandle::thread_local()` uses a pool owned by the calling thread,
+///   which avoids contention between threads.
+/// - `MemoryPoolHandle::new()` creates a separate pool that is freed when the
+///   last handle to it is dropped.
+///
+/// Give a handle to `Evaluator::set_pool` or `Encryptor::set_pool` to route
+/// their allocations through it, then watch `alloc_byte_count()`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use zno_seal_sys::bgv::*;
+/// # let builder = ffi::set_poly_modulus_degree(ffi::init(0x3).unwrap(), 8192).unwrap();
+/// # let builder = ffi::set_coeff_modulus(builder, &ffi::coeff_modulus_bfv_default(8192).unwrap()).unwrap();
+/// # let builder = ffi::set_plain_modulus(builder, ffi::plain_modulus_batching(8192, 20).unwrap()).unwrap();
+/// # let context = Context::new(builder).expect("BGV context creation");
+/// let pool = MemoryPoolHandle::new(false).expect("MemoryPoolHandle creation");
+/// let mut evaluator = Evaluator::new(&context).expect("Evaluator creation");
+/// evaluator.set_pool(&pool);
+/// // ... compute with evaluator
+/// println!("{} bytes in {} pools", pool.alloc_byte_count(), pool.pool_count());
+/// ```
+pub struct MemoryPoolHandle {
+    pub(crate) inner: cxx::UniquePtr<ffi::MemoryPoolHandle>,
+}
+
+impl MemoryPoolHandle {
+    /// Returns a handle to the global memory pool.
+    pub fn global() -> Result<Self, FFIError> {
+        let inner = non_null(ffi::memory_pool_global())?;
+        Ok(MemoryPoolHandle { inner })
+    }
+
+    /// Returns a handle to the memory pool of the calling thread.
+    ///
+    /// The pool lives until the thread exits, so the handle must not be used
+    /// from, or outlive, another thread.
+    pub fn thread_local() -> Result<Self, FFIError> {
+        let inner = non_null(ffi::memory_pool_thread_local())?;
+        Ok(MemoryPoolHandle { inner })
+    }
+
+    /// Creates a new memory pool, freed when the last handle to it is dropped.
+    ///
+    /// With `clear_on_destruction`, the memory is zeroed before it is freed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::CppException` if the pool could not be allocated.
+    pub fn new(clear_on_destruction: bool) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::memory_pool_new(clear_on_destruction)?)?;
+        Ok(MemoryPoolHandle { inner })
+    }
+
+    /// Returns the number of size classes the pool has allocated.
+    pub fn pool_count(&self) -> usize {
+        self.inner.pool_count()
+    }
+
+    /// Returns the number of bytes the pool has allocated.
+    ///
+    /// Memory is returned to the pool, not to the system, when SEAL is done
+    /// with it, so this only grows until the pool itself is freed.
+    pub fn alloc_byte_count(&self) -> usize {
+        self.inner.alloc_byte_count()
+    }
+}
+
+impl Clone for MemoryPoolHandle {
+    /// Returns another handle to the same pool.
+    fn clone(&self) -> Self {
+        MemoryPoolHandle { inner: ffi::memory_pool_clone(&self.inner) }
+    }
+}
+
+// Implement Display for printing, debugging, etc.
+impl core::fmt::Display for MemoryPoolHandle {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "MemoryPoolHandle(pools={}, bytes={})", self.pool_count(), self.alloc_byte_count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bgv::testing::setup_bgv_context;
+
+    #[test]
+    fn test_new_pool_is_empty() {
+        let pool = MemoryPoolHandle::new(false).expect("MemoryPoolHandle creation");
+        assert_eq!(pool.pool_count(), 0);
+        assert_eq!(pool.alloc_byte_count(), 0);
+        assert!(MemoryPoolHandle::global().is_ok());
+        assert!(MemoryPoolHandle::thread_local().is_ok());
+    }
+
+    #[test]
+    fn test_evaluator_allocates_from_pool() {
+        let context = setup_bgv_context();
+        let mut keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let relin_keys = keygen.create_relin_keys().expect("RelinKeys");
+        let mut decryptor = Decryptor::new(&context, &keygen.secret_key().unwrap()).expect("Decryptor creation");
+        let pool = MemoryPoolHandle::new(true).expect("MemoryPoolHandle creation");
+
+        let mut encryptor = Encryptor::new(&context, &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+        encryptor.set_pool(&pool);
+        let two = encryptor.encrypt(&Plaintext::from_hex("2").unwrap()).expect("Ciphertext");
+        let after_encrypt = pool.alloc_byte_count();
+        assert!(after_encrypt > 0);
+
+        let mut evaluator = Evaluator::new(&context).expect("Evaluator creation");
+        evaluator.set_pool(&pool);
+        let mut product = evaluator.square(&two).expect("Ciphertext");
+        evaluator.relinearize_inplace(&mut product, &relin_keys).unwrap();
+        assert!(pool.alloc_byte_count() > after_encrypt);
+        assert!(pool.pool_count() > 0);
+
+        // The clone shares the pool, it does not copy it.
+        assert_eq!(pool.clone().alloc_byte_count(), pool.alloc_byte_count());
+        assert_eq!(decryptor.decrypt(&product).unwrap().to_string(), "4");
+    }
+}
@@ -1,27 +1,96 @@
-use core::pin::Pin;
-use core::fmt;
 use core::convert::TryFrom;
-use core::convert::TryInto;
-use std::ptr;
-use std::{fmt::{Display, Formatter}, error::Error};
 
-use cxx::CxxString;
-use cxx::CxxVector;
-use cxx::UniquePtr;
+// Include the modules
+pub mod batchencoder;
+pub mod ciphertext;
+pub mod ckksencoder;
+pub mod context;
+pub mod contextdata;
+pub mod decryptor;
+pub mod encryptor;
+pub mod error;
+pub mod evaluator;
+pub mod keygenerator;
+pub mod keys;
+pub mod memorypool;
+pub mod plaintext;
+pub mod serializable;
+pub mod serialization;
+pub mod valcheck;
+#[cfg(any(test, feature = "test-rng"))]
+pub mod testing;
 
-// Import the BGV struct and its fields
-use crate::prelude::*;
+// Re-export the types for external use as `crate::bgv::<type>`
+pub use self::batchencoder::*;
+pub use self::ciphertext::*;
+pub use self::ckksencoder::*;
+pub use self::context::*;
+pub use self::contextdata::*;
+pub use self::decryptor::*;
+pub use self::encryptor::*;
+pub use self::error::*;
+pub use self::evaluator::*;
+pub use self::keygenerator::*;
+pub use self::keys::*;
+pub use self::memorypool::*;
+pub use self::plaintext::*;
+pub use self::serializable::*;
+pub use self::serialization::*;
+pub use self::valcheck::*;
 
 #[cxx::bridge(namespace="seal")]
 pub mod ffi {
 
+    /// Identifies a set of encryption parameters, and so a level in the
+    /// modulus switching chain. Mirrors SEAL's `parms_id_type`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    struct ParmsId {
+        value: [u64; 4],
+    }
+
+    /// The properties SEAL derived for a set of encryption parameters.
+    /// Mirrors SEAL's `EncryptionParameterQualifiers`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct ParameterQualifiers {
+        parameters_set: bool,
+        using_fft: bool,
+        using_ntt: bool,
+        using_batching: bool,
+        using_fast_plain_lift: bool,
+        using_descending_modulus_chain: bool,
+        /// The security level in bits, `0` when the parameters do not meet the HomomorphicEncryption.org standard.
+        sec_level: i32,
+    }
+
+    /// The compression applied by `save`. Mirrors SEAL's `compr_mode_type`.
+    ///
+    /// `Zlib` and `Zstd` are only available when SEAL was built with them.
+    #[repr(u8)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum ComprMode {
+        None = 0,
+        Zlib = 1,
+        Zstd = 2,
+    }
+
+    /// The homomorphic encryption scheme. Mirrors SEAL's `scheme_type`.
+    ///
+    /// Pass `SchemeType::Bfv.repr` etc. to `init`.
+    #[repr(u8)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum SchemeType {
+        None = 0,
+        Bfv = 1,
+        Ckks = 2,
+        Bgv = 3,
+    }
+
     unsafe extern "C++" {
         include!("zno-seal-sys/ffi/ffi_wrapper.h");
 
         type EncryptionParameters;
 
         fn get_scheme(params: UniquePtr<EncryptionParameters>) -> u8;
-        fn set_scheme(params: UniquePtr<EncryptionParameters>, scheme: u8);
 
         type BGVContextBuilder;
         type Context;
@@ -37,32 +106,222 @@ pub mod ffi {
 
         pub fn version() -> UniquePtr<CxxString>;
 
-        fn init(schema: u8) -> UniquePtr<BGVContextBuilder>;
-
-        fn build(builder: UniquePtr<BGVContextBuilder>) -> UniquePtr<Context>;
-
-        fn set_m(builder: UniquePtr<BGVContextBuilder>, m: u32) -> UniquePtr<BGVContextBuilder>;
-
-        // // Methods of SEALContext
-        // fn get_context_data(self: &SEALContext);
-        // fn key_context_data(self: &SEALContext);
-        // fn first_context_data(self: &SEALContext);
-        // fn parameters_set(self: &SEALContext);
-        // fn first_parms_id(self: &SEALContext);
-        // fn last_parms_id(self: &SEALContext);
-        // fn using_keyswitching(self: &SEALContext);
-
-        // Pending implementation...
-        //
-        // // ... from_*_str function load helper methods for SEALContext.
-        // // The `load` functions are member functions of the `Ciphertext`, `Plaintext`, `SecretKey`, `PublicKey`, `RelinKeys`, and `GaloisKeys` classes.
-        // fn load(self: Pin<&mut Ciphertext>, context: &SEALContext, in_stream: &String);
-        // fn load(self: Pin<&mut Plaintext>, context: &SEALContext, in_stream: &String);
-        // fn load(self: Pin<&mut SecretKey>, context: &SEALContext, in_stream: &String);
-        // fn load(self: Pin<&mut PublicKey>, context: &SEALContext, in_stream: &String);
-        // fn load(self: Pin<&mut RelinKeys>, context: &SEALContext, in_stream: &String);
-        // // fn loader(self: Pin<&mut GaloisKeys>, context: &SEALContext, in_stream: &String);
-        // fn loader(self: Pin<&mut GaloisKeysWrapper>, context: &SEALContext, in_str: &str) -> i64;
+        fn init(schema: u8) -> Result<UniquePtr<BGVContextBuilder>>;
+        fn scheme_type(builder: &BGVContextBuilder) -> SchemeType;
+
+        fn build(builder: UniquePtr<BGVContextBuilder>) -> Result<UniquePtr<Context>>;
+
+        fn set_m(builder: UniquePtr<BGVContextBuilder>, m: u32) -> Result<UniquePtr<BGVContextBuilder>>;
+        fn set_poly_modulus_degree(builder: UniquePtr<BGVContextBuilder>, degree: u64) -> Result<UniquePtr<BGVContextBuilder>>;
+        fn set_coeff_modulus(builder: UniquePtr<BGVContextBuilder>, moduli: &[u64]) -> Result<UniquePtr<BGVContextBuilder>>;
+        fn set_plain_modulus(builder: UniquePtr<BGVContextBuilder>, modulus: u64) -> Result<UniquePtr<BGVContextBuilder>>;
+        fn poly_modulus_degree(self: &BGVContextBuilder) -> usize;
+
+        fn coeff_modulus_bfv_default(poly_modulus_degree: u64) -> Result<Vec<u64>>;
+        fn coeff_modulus_create(poly_modulus_degree: u64, bit_sizes: &[i32]) -> Result<Vec<u64>>;
+        fn plain_modulus_batching(poly_modulus_degree: u64, bit_size: i32) -> Result<u64>;
+
+        type KeyGenerator;
+
+        fn new_key_generator(context: &Context) -> Result<UniquePtr<KeyGenerator>>;
+        fn get_secret_key(keygen: &KeyGenerator) -> Result<UniquePtr<SecretKey>>;
+        fn create_public_key(keygen: &KeyGenerator) -> Result<UniquePtr<PublicKey>>;
+        fn create_relin_keys(keygen: Pin<&mut KeyGenerator>) -> Result<UniquePtr<RelinKeys>>;
+        fn create_galois_keys(keygen: Pin<&mut KeyGenerator>) -> Result<UniquePtr<GaloisKeys>>;
+        fn create_galois_keys_with_steps(keygen: Pin<&mut KeyGenerator>, steps: &[i32]) -> Result<UniquePtr<GaloisKeys>>;
+
+        fn new_plaintext() -> UniquePtr<Plaintext>;
+        fn new_plaintext_from_hex(hex_poly: &str) -> Result<UniquePtr<Plaintext>>;
+        fn plaintext_to_string(plain: &Plaintext) -> String;
+        fn coeff_count(self: &Plaintext) -> usize;
+
+        fn new_ciphertext() -> UniquePtr<Ciphertext>;
+        fn size(self: &Ciphertext) -> usize;
+
+        type Encryptor;
+
+        fn new_encryptor(context: &Context, public_key: &PublicKey) -> Result<UniquePtr<Encryptor>>;
+        fn new_symmetric_encryptor(context: &Context, secret_key: &SecretKey) -> Result<UniquePtr<Encryptor>>;
+        fn set_public_key(self: Pin<&mut Encryptor>, public_key: &PublicKey) -> Result<()>;
+        fn set_secret_key(self: Pin<&mut Encryptor>, secret_key: &SecretKey) -> Result<()>;
+        fn encrypt(encryptor: &Encryptor, plain: &Plaintext, pool: &MemoryPoolHandle) -> Result<UniquePtr<Ciphertext>>;
+        fn encrypt_zero(encryptor: &Encryptor, pool: &MemoryPoolHandle) -> Result<UniquePtr<Ciphertext>>;
+        fn encrypt_symmetric(encryptor: &Encryptor, plain: &Plaintext, pool: &MemoryPoolHandle) -> Result<UniquePtr<Ciphertext>>;
+        fn encrypt_zero_symmetric(encryptor: &Encryptor, pool: &MemoryPoolHandle) -> Result<UniquePtr<Ciphertext>>;
+
+        type Decryptor;
+
+        fn new_decryptor(context: &Context, secret_key: &SecretKey) -> Result<UniquePtr<Decryptor>>;
+        fn decrypt(decryptor: Pin<&mut Decryptor>, encrypted: &Ciphertext) -> Result<UniquePtr<Plaintext>>;
+        fn invariant_noise_budget(self: Pin<&mut Decryptor>, encrypted: &Ciphertext) -> Result<i32>;
+
+        fn ciphertext_parms_id(encrypted: &Ciphertext) -> ParmsId;
+        fn ciphertext_scale(encrypted: &Ciphertext) -> f64;
+        fn set_ciphertext_scale(encrypted: Pin<&mut Ciphertext>, scale: f64);
+        fn plaintext_scale(plain: &Plaintext) -> f64;
+
+        type Evaluator;
+
+        fn new_evaluator(context: &Context) -> Result<UniquePtr<Evaluator>>;
+
+        fn negate(evaluator: &Evaluator, encrypted: &Ciphertext) -> Result<UniquePtr<Ciphertext>>;
+        fn negate_inplace(evaluator: &Evaluator, encrypted: Pin<&mut Ciphertext>) -> Result<()>;
+        fn add(evaluator: &Evaluator, encrypted1: &Ciphertext, encrypted2: &Ciphertext) -> Result<UniquePtr<Ciphertext>>;
+        fn add_inplace(evaluator: &Evaluator, encrypted1: Pin<&mut Ciphertext>, encrypted2: &Ciphertext) -> Result<()>;
+        fn sub(evaluator: &Evaluator, encrypted1: &Ciphertext, encrypted2: &Ciphertext) -> Result<UniquePtr<Ciphertext>>;
+        fn sub_inplace(evaluator: &Evaluator, encrypted1: Pin<&mut Ciphertext>, encrypted2: &Ciphertext) -> Result<()>;
+        fn multiply(evaluator: &Evaluator, encrypted1: &Ciphertext, encrypted2: &Ciphertext, pool: &MemoryPoolHandle) -> Result<UniquePtr<Ciphertext>>;
+        fn multiply_inplace(evaluator: &Evaluator, encrypted1: Pin<&mut Ciphertext>, encrypted2: &Ciphertext, pool: &MemoryPoolHandle) -> Result<()>;
+        fn square(evaluator: &Evaluator, encrypted: &Ciphertext, pool: &MemoryPoolHandle) -> Result<UniquePtr<Ciphertext>>;
+        fn square_inplace(evaluator: &Evaluator, encrypted: Pin<&mut Ciphertext>, pool: &MemoryPoolHandle) -> Result<()>;
+        fn add_plain(evaluator: &Evaluator, encrypted: &Ciphertext, plain: &Plaintext, pool: &MemoryPoolHandle) -> Result<UniquePtr<Ciphertext>>;
+        fn add_plain_inplace(evaluator: &Evaluator, encrypted: Pin<&mut Ciphertext>, plain: &Plaintext, pool: &MemoryPoolHandle) -> Result<()>;
+        fn sub_plain(evaluator: &Evaluator, encrypted: &Ciphertext, plain: &Plaintext, pool: &MemoryPoolHandle) -> Result<UniquePtr<Ciphertext>>;
+        fn sub_plain_inplace(evaluator: &Evaluator, encrypted: Pin<&mut Ciphertext>, plain: &Plaintext, pool: &MemoryPoolHandle) -> Result<()>;
+        fn multiply_plain(evaluator: &Evaluator, encrypted: &Ciphertext, plain: &Plaintext, pool: &MemoryPoolHandle) -> Result<UniquePtr<Ciphertext>>;
+        fn multiply_plain_inplace(evaluator: &Evaluator, encrypted: Pin<&mut Ciphertext>, plain: &Plaintext, pool: &MemoryPoolHandle) -> Result<()>;
+        fn relinearize(evaluator: &Evaluator, encrypted: &Ciphertext, relin_keys: &RelinKeys, pool: &MemoryPoolHandle) -> Result<UniquePtr<Ciphertext>>;
+        fn relinearize_inplace(evaluator: &Evaluator, encrypted: Pin<&mut Ciphertext>, relin_keys: &RelinKeys, pool: &MemoryPoolHandle) -> Result<()>;
+        fn mod_switch_to_next(evaluator: &Evaluator, encrypted: &Ciphertext, pool: &MemoryPoolHandle) -> Result<UniquePtr<Ciphertext>>;
+        fn mod_switch_to_next_inplace(evaluator: &Evaluator, encrypted: Pin<&mut Ciphertext>, pool: &MemoryPoolHandle) -> Result<()>;
+        fn mod_switch_to(evaluator: &Evaluator, encrypted: &Ciphertext, parms_id: &ParmsId, pool: &MemoryPoolHandle) -> Result<UniquePtr<Ciphertext>>;
+        fn mod_switch_to_inplace(evaluator: &Evaluator, encrypted: Pin<&mut Ciphertext>, parms_id: &ParmsId, pool: &MemoryPoolHandle) -> Result<()>;
+        fn rescale_to_next(evaluator: &Evaluator, encrypted: &Ciphertext, pool: &MemoryPoolHandle) -> Result<UniquePtr<Ciphertext>>;
+        fn rescale_to_next_inplace(evaluator: &Evaluator, encrypted: Pin<&mut Ciphertext>, pool: &MemoryPoolHandle) -> Result<()>;
+        fn rotate_rows(evaluator: &Evaluator, encrypted: &Ciphertext, steps: i32, galois_keys: &GaloisKeys, pool: &MemoryPoolHandle) -> Result<UniquePtr<Ciphertext>>;
+        fn rotate_rows_inplace(evaluator: &Evaluator, encrypted: Pin<&mut Ciphertext>, steps: i32, galois_keys: &GaloisKeys, pool: &MemoryPoolHandle) -> Result<()>;
+        fn rotate_columns(evaluator: &Evaluator, encrypted: &Ciphertext, galois_keys: &GaloisKeys, pool: &MemoryPoolHandle) -> Result<UniquePtr<Ciphertext>>;
+        fn rotate_columns_inplace(evaluator: &Evaluator, encrypted: Pin<&mut Ciphertext>, galois_keys: &GaloisKeys, pool: &MemoryPoolHandle) -> Result<()>;
+        fn apply_galois(evaluator: &Evaluator, encrypted: &Ciphertext, galois_elt: u32, galois_keys: &GaloisKeys, pool: &MemoryPoolHandle) -> Result<UniquePtr<Ciphertext>>;
+        fn apply_galois_inplace(evaluator: &Evaluator, encrypted: Pin<&mut Ciphertext>, galois_elt: u32, galois_keys: &GaloisKeys, pool: &MemoryPoolHandle) -> Result<()>;
+        fn exponentiate(evaluator: &Evaluator, encrypted: &Ciphertext, exponent: u64, relin_keys: &RelinKeys, pool: &MemoryPoolHandle) -> Result<UniquePtr<Ciphertext>>;
+        fn exponentiate_inplace(evaluator: &Evaluator, encrypted: Pin<&mut Ciphertext>, exponent: u64, relin_keys: &RelinKeys, pool: &MemoryPoolHandle) -> Result<()>;
+
+        type BatchEncoder;
+
+        fn new_batch_encoder(context: &Context) -> Result<UniquePtr<BatchEncoder>>;
+        fn slot_count(self: &BatchEncoder) -> usize;
+        fn encode_u64(encoder: &BatchEncoder, values: &[u64]) -> Result<UniquePtr<Plaintext>>;
+        fn encode_i64(encoder: &BatchEncoder, values: &[i64]) -> Result<UniquePtr<Plaintext>>;
+        fn decode_u64(encoder: &BatchEncoder, plain: &Plaintext) -> Result<Vec<u64>>;
+        fn decode_i64(encoder: &BatchEncoder, plain: &Plaintext) -> Result<Vec<i64>>;
+
+        type CKKSEncoder;
+
+        fn new_ckks_encoder(context: &Context) -> Result<UniquePtr<CKKSEncoder>>;
+        fn slot_count(self: &CKKSEncoder) -> usize;
+        fn ckks_encode_f64(encoder: &CKKSEncoder, values: &[f64], parms_id: &ParmsId, scale: f64) -> Result<UniquePtr<Plaintext>>;
+        fn ckks_encode_complex(encoder: &CKKSEncoder, values: &[f64], parms_id: &ParmsId, scale: f64) -> Result<UniquePtr<Plaintext>>;
+        fn ckks_decode_f64(encoder: &CKKSEncoder, plain: &Plaintext) -> Result<Vec<f64>>;
+        fn ckks_decode_complex(encoder: &CKKSEncoder, plain: &Plaintext) -> Result<Vec<f64>>;
+
+        // Methods of SEALContext
+        type ContextData;
+
+        fn parameters_set(self: &Context) -> bool;
+        fn using_keyswitching(self: &Context) -> bool;
+        fn parameter_error_name(context: &Context) -> String;
+        fn parameter_error_message(context: &Context) -> String;
+        fn key_context_data(context: &Context) -> SharedPtr<ContextData>;
+        fn first_context_data(context: &Context) -> SharedPtr<ContextData>;
+        fn last_context_data(context: &Context) -> SharedPtr<ContextData>;
+        fn get_context_data(context: &Context, parms_id: &ParmsId) -> SharedPtr<ContextData>;
+        fn key_parms_id(context: &Context) -> ParmsId;
+        fn first_parms_id(context: &Context) -> ParmsId;
+        fn last_parms_id(context: &Context) -> ParmsId;
+
+        fn next_context_data(context_data: &ContextData) -> SharedPtr<ContextData>;
+        fn prev_context_data(context_data: &ContextData) -> SharedPtr<ContextData>;
+        fn chain_index(self: &ContextData) -> usize;
+        fn total_coeff_modulus_bit_count(self: &ContextData) -> i32;
+        fn context_data_parms_id(context_data: &ContextData) -> ParmsId;
+        fn context_data_coeff_modulus(context_data: &ContextData) -> Vec<u64>;
+        fn context_data_poly_modulus_degree(context_data: &ContextData) -> u64;
+        fn context_data_plain_modulus(context_data: &ContextData) -> u64;
+        fn context_data_scheme(context_data: &ContextData) -> SchemeType;
+        fn context_data_qualifiers(context_data: &ContextData) -> ParameterQualifiers;
+
+        type MemoryPoolHandle;
+
+        fn memory_pool_global() -> UniquePtr<MemoryPoolHandle>;
+        fn memory_pool_thread_local() -> UniquePtr<MemoryPoolHandle>;
+        fn memory_pool_new(clear_on_destruction: bool) -> Result<UniquePtr<MemoryPoolHandle>>;
+        fn memory_pool_clone(pool: &MemoryPoolHandle) -> UniquePtr<MemoryPoolHandle>;
+        fn pool_count(self: &MemoryPoolHandle) -> usize;
+        fn alloc_byte_count(self: &MemoryPoolHandle) -> usize;
+
+        fn save_size_ciphertext(obj: &Ciphertext, compr_mode: ComprMode) -> Result<i64>;
+        fn save_ciphertext(obj: &Ciphertext, compr_mode: ComprMode) -> Result<Vec<u8>>;
+        fn load_ciphertext(context: &Context, bytes: &[u8]) -> Result<UniquePtr<Ciphertext>>;
+
+        fn save_size_plaintext(obj: &Plaintext, compr_mode: ComprMode) -> Result<i64>;
+        fn save_plaintext(obj: &Plaintext, compr_mode: ComprMode) -> Result<Vec<u8>>;
+        fn load_plaintext(context: &Context, bytes: &[u8]) -> Result<UniquePtr<Plaintext>>;
+
+        fn save_size_secret_key(obj: &SecretKey, compr_mode: ComprMode) -> Result<i64>;
+        fn save_secret_key(obj: &SecretKey, compr_mode: ComprMode) -> Result<Vec<u8>>;
+        fn load_secret_key(context: &Context, bytes: &[u8]) -> Result<UniquePtr<SecretKey>>;
+
+        fn save_size_public_key(obj: &PublicKey, compr_mode: ComprMode) -> Result<i64>;
+        fn save_public_key(obj: &PublicKey, compr_mode: ComprMode) -> Result<Vec<u8>>;
+        fn load_public_key(context: &Context, bytes: &[u8]) -> Result<UniquePtr<PublicKey>>;
+
+        fn save_size_relin_keys(obj: &RelinKeys, compr_mode: ComprMode) -> Result<i64>;
+        fn save_relin_keys(obj: &RelinKeys, compr_mode: ComprMode) -> Result<Vec<u8>>;
+        fn load_relin_keys(context: &Context, bytes: &[u8]) -> Result<UniquePtr<RelinKeys>>;
+
+        fn save_size_galois_keys(obj: &GaloisKeys, compr_mode: ComprMode) -> Result<i64>;
+        fn save_galois_keys(obj: &GaloisKeys, compr_mode: ComprMode) -> Result<Vec<u8>>;
+        fn load_galois_keys(context: &Context, bytes: &[u8]) -> Result<UniquePtr<GaloisKeys>>;
+
+        fn save_size_encryption_parameters(obj: &BGVContextBuilder, compr_mode: ComprMode) -> Result<i64>;
+        fn save_encryption_parameters(obj: &BGVContextBuilder, compr_mode: ComprMode) -> Result<Vec<u8>>;
+        fn load_encryption_parameters(bytes: &[u8]) -> Result<UniquePtr<BGVContextBuilder>>;
+        fn is_foreign_parms_id(context: &Context, bytes: &[u8]) -> bool;
+
+        fn is_valid_for_ciphertext(obj: &Ciphertext, context: &Context) -> bool;
+        fn is_metadata_valid_for_ciphertext(obj: &Ciphertext, context: &Context) -> bool;
+        fn is_buffer_valid_ciphertext(obj: &Ciphertext) -> bool;
+
+        fn is_valid_for_plaintext(obj: &Plaintext, context: &Context) -> bool;
+        fn is_metadata_valid_for_plaintext(obj: &Plaintext, context: &Context) -> bool;
+        fn is_buffer_valid_plaintext(obj: &Plaintext) -> bool;
+
+        fn is_valid_for_secret_key(obj: &SecretKey, context: &Context) -> bool;
+        fn is_metadata_valid_for_secret_key(obj: &SecretKey, context: &Context) -> bool;
+        fn is_buffer_valid_secret_key(obj: &SecretKey) -> bool;
+
+        fn is_valid_for_public_key(obj: &PublicKey, context: &Context) -> bool;
+        fn is_metadata_valid_for_public_key(obj: &PublicKey, context: &Context) -> bool;
+        fn is_buffer_valid_public_key(obj: &PublicKey) -> bool;
+
+        fn is_valid_for_relin_keys(obj: &RelinKeys, context: &Context) -> bool;
+        fn is_metadata_valid_for_relin_keys(obj: &RelinKeys, context: &Context) -> bool;
+        fn is_buffer_valid_relin_keys(obj: &RelinKeys) -> bool;
+
+        fn is_valid_for_galois_keys(obj: &GaloisKeys, context: &Context) -> bool;
+        fn is_metadata_valid_for_galois_keys(obj: &GaloisKeys, context: &Context) -> bool;
+        fn is_buffer_valid_galois_keys(obj: &GaloisKeys) -> bool;
+
+        type SerializablePublicKey;
+        type SerializableRelinKeys;
+        type SerializableGaloisKeys;
+        type SerializableCiphertext;
+
+        fn create_serializable_public_key(keygen: &KeyGenerator) -> Result<UniquePtr<SerializablePublicKey>>;
+        fn create_serializable_relin_keys(keygen: Pin<&mut KeyGenerator>) -> Result<UniquePtr<SerializableRelinKeys>>;
+        fn create_serializable_galois_keys(keygen: Pin<&mut KeyGenerator>) -> Result<UniquePtr<SerializableGaloisKeys>>;
+        fn encrypt_symmetric_serializable(encryptor: &Encryptor, plain: &Plaintext) -> Result<UniquePtr<SerializableCiphertext>>;
+        fn encrypt_zero_symmetric_serializable(encryptor: &Encryptor) -> Result<UniquePtr<SerializableCiphertext>>;
+
+        fn save_size_serializable_public_key(obj: &SerializablePublicKey, compr_mode: ComprMode) -> Result<i64>;
+        fn save_serializable_public_key(obj: &SerializablePublicKey, compr_mode: ComprMode) -> Result<Vec<u8>>;
+        fn save_size_serializable_relin_keys(obj: &SerializableRelinKeys, compr_mode: ComprMode) -> Result<i64>;
+        fn save_serializable_relin_keys(obj: &SerializableRelinKeys, compr_mode: ComprMode) -> Result<Vec<u8>>;
+        fn save_size_serializable_galois_keys(obj: &SerializableGaloisKeys, compr_mode: ComprMode) -> Result<i64>;
+        fn save_serializable_galois_keys(obj: &SerializableGaloisKeys, compr_mode: ComprMode) -> Result<Vec<u8>>;
+        fn save_size_serializable_ciphertext(obj: &SerializableCiphertext, compr_mode: ComprMode) -> Result<i64>;
+        fn save_serializable_ciphertext(obj: &SerializableCiphertext, compr_mode: ComprMode) -> Result<Vec<u8>>;
     }
 
 }
@@ -0,0 +1,52 @@
+use super::*;
+
+/// Define the Rust struct to represent the C++ Plaintext class
+///
+/// A `Plaintext` is a polynomial with coefficients modulo the plaintext modulus.
+/// It is the input to encryption and the output of decryption.
+pub struct Plaintext {
+    pub(crate) inner: cxx::UniquePtr<ffi::Plaintext>,
+}
+
+impl Plaintext {
+    /// Creates a new, empty `Plaintext`.
+    pub fn new() -> Result<Self, FFIError> {
+        let inner = non_null(ffi::new_plaintext())?;
+        Ok(Plaintext { inner })
+    }
+
+    /// Creates a `Plaintext` from a polynomial in hexadecimal form.
+    ///
+    /// The string has the form `"7FFx^3 + 1x^1 + 3"`, that is, terms are
+    /// written in decreasing degree and coefficients are in hexadecimal.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use zno_seal_sys::bgv::Plaintext;
+    /// let plain = Plaintext::from_hex("1x^1 + 3").expect("Plaintext");
+    /// assert_eq!(plain.to_string(), "1x^1 + 3");
+    /// ```
+    pub fn from_hex(hex_poly: &str) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::new_plaintext_from_hex(hex_poly)?)?;
+        Ok(Plaintext { inner })
+    }
+
+    /// Returns the number of coefficients in the plaintext polynomial.
+    pub fn coeff_count(&self) -> usize {
+        self.inner.coeff_count()
+    }
+
+    /// Returns the scale of a CKKS plaintext, `1.0` for the other schemes.
+    pub fn scale(&self) -> f64 {
+        ffi::plaintext_scale(&self.inner)
+    }
+}
+
+// Implement Display for printing, debugging, etc.
+// The polynomial is written in the same hexadecimal form `from_hex` accepts.
+impl core::fmt::Display for Plaintext {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", ffi::plaintext_to_string(&self.inner))
+    }
+}
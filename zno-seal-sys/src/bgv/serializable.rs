@@ -0,0 +1,165 @@
+use core::marker::PhantomData;
+
+use super::*;
+
+/// Define the Rust struct to represent the C++ Serializable<T> class template
+///
+/// A `Serializable<T>` wraps a `T` that SEAL generated from a PRNG seed.
+/// When saved, the second polynomial is replaced by the seed, which roughly
+/// halves the size of public keys, relinearization keys, Galois keys and
+/// symmetric ciphertexts.
+///
+/// It can only be saved. Load the bytes back as `T` on the receiving side,
+/// where the seed is expanded again.
+///
+/// # Example
+///
+/// ```no_run
+/// # use zno_seal_sys::bgv::*;
+/// # let builder = ffi::set_poly_modulus_degree(ffi::init(0x3).unwrap(), 8192).unwrap();
+/// # let builder = ffi::set_coeff_modulus(builder, &ffi::coeff_modulus_bfv_default(8192).unwrap()).unwrap();
+/// # let builder = ffi::set_plain_modulus(builder, ffi::plain_modulus_batching(8192, 20).unwrap()).unwrap();
+/// # let context = Context::new(builder).expect("BGV context creation");
+/// let keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+/// let upload = keygen.create_public_key_serializable().unwrap().save(ComprMode::Zstd).unwrap();
+/// // ... on the server
+/// let public_key = PublicKey::load(&context, &upload).expect("PublicKey");
+/// ```
+pub struct Serializable<T: SerializableTarget> {
+    pub(crate) inner: cxx::UniquePtr<T::Inner>,
+    _marker: PhantomData<T>,
+}
+
+/// The types SEAL can produce in seeded form, see `Serializable<T>`.
+pub trait SerializableTarget {
+    /// The C++ `Serializable<T>` instantiation.
+    type Inner: cxx::memory::UniquePtrTarget;
+
+    #[doc(hidden)]
+    fn save_size(inner: &Self::Inner, compr_mode: ComprMode) -> Result<i64, cxx::Exception>;
+
+    #[doc(hidden)]
+    fn save(inner: &Self::Inner, compr_mode: ComprMode) -> Result<Vec<u8>, cxx::Exception>;
+}
+
+impl<T: SerializableTarget> Serializable<T> {
+    pub(crate) fn from_ptr(ptr: cxx::UniquePtr<T::Inner>) -> Result<Self, FFIError> {
+        let inner = non_null(ptr)?;
+        Ok(Serializable { inner, _marker: PhantomData })
+    }
+}
+
+impl<T: SerializableTarget> Save for Serializable<T> {
+    fn save_size(&self, compr_mode: ComprMode) -> Result<usize, FFIError> {
+        to_usize(T::save_size(&self.inner, compr_mode)?)
+    }
+
+    fn save(&self, compr_mode: ComprMode) -> Result<Vec<u8>, FFIError> {
+        Ok(T::save(&self.inner, compr_mode)?)
+    }
+}
+
+impl SerializableTarget for PublicKey {
+    type Inner = ffi::SerializablePublicKey;
+
+    fn save_size(inner: &Self::Inner, compr_mode: ComprMode) -> Result<i64, cxx::Exception> {
+        ffi::save_size_serializable_public_key(inner, compr_mode)
+    }
+
+    fn save(inner: &Self::Inner, compr_mode: ComprMode) -> Result<Vec<u8>, cxx::Exception> {
+        ffi::save_serializable_public_key(inner, compr_mode)
+    }
+}
+
+impl SerializableTarget for RelinKeys {
+    type Inner = ffi::SerializableRelinKeys;
+
+    fn save_size(inner: &Self::Inner, compr_mode: ComprMode) -> Result<i64, cxx::Exception> {
+        ffi::save_size_serializable_relin_keys(inner, compr_mode)
+    }
+
+    fn save(inner: &Self::Inner, compr_mode: ComprMode) -> Result<Vec<u8>, cxx::Exception> {
+        ffi::save_serializable_relin_keys(inner, compr_mode)
+    }
+}
+
+impl SerializableTarget for GaloisKeys {
+    type Inner = ffi::SerializableGaloisKeys;
+
+    fn save_size(inner: &Self::Inner, compr_mode: ComprMode) -> Result<i64, cxx::Exception> {
+        ffi::save_size_serializable_galois_keys(inner, compr_mode)
+    }
+
+    fn save(inner: &Self::Inner, compr_mode: ComprMode) -> Result<Vec<u8>, cxx::Exception> {
+        ffi::save_serializable_galois_keys(inner, compr_mode)
+    }
+}
+
+impl SerializableTarget for Ciphertext {
+    type Inner = ffi::SerializableCiphertext;
+
+    fn save_size(inner: &Self::Inner, compr_mode: ComprMode) -> Result<i64, cxx::Exception> {
+        ffi::save_size_serializable_ciphertext(inner, compr_mode)
+    }
+
+    fn save(inner: &Self::Inner, compr_mode: ComprMode) -> Result<Vec<u8>, cxx::Exception> {
+        ffi::save_serializable_ciphertext(inner, compr_mode)
+    }
+}
+
+// Implement Display for printing, debugging, etc.
+impl<T: SerializableTarget> core::fmt::Display for Serializable<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Serializable") // How this type name should appear
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bgv::testing::setup_bgv_context;
+
+    #[test]
+    fn test_seeded_public_key_is_smaller() {
+        let context = setup_bgv_context();
+        let keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+
+        let seeded = keygen.create_public_key_serializable().unwrap().save(ComprMode::None).unwrap();
+        let full = keygen.create_public_key().unwrap().save(ComprMode::None).unwrap();
+        assert!(seeded.len() < full.len());
+        assert!(PublicKey::load(&context, &seeded).is_ok());
+    }
+
+    #[test]
+    fn test_seeded_keys_load() {
+        let context = setup_bgv_context();
+        let mut keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+
+        let relin_keys = keygen.create_relin_keys_serializable().unwrap();
+        assert!(RelinKeys::load(&context, &relin_keys.save(ComprMode::Zstd).unwrap()).is_ok());
+        let galois_keys = keygen.create_galois_keys_serializable().unwrap();
+        assert!(GaloisKeys::load(&context, &galois_keys.save(ComprMode::Zstd).unwrap()).is_ok());
+    }
+
+    #[test]
+    fn test_seeded_symmetric_ciphertext_roundtrip() {
+        let context = setup_bgv_context();
+        let keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let secret_key = keygen.secret_key().expect("SecretKey");
+        let encryptor = Encryptor::new_symmetric(&context, &secret_key).expect("Encryptor creation");
+        let mut decryptor = Decryptor::new(&context, &secret_key).expect("Decryptor creation");
+
+        let seeded = encryptor.encrypt_symmetric_serializable(&Plaintext::from_hex("6").unwrap()).unwrap();
+        let bytes = seeded.save(ComprMode::None).unwrap();
+        assert!(bytes.len() <= seeded.save_size(ComprMode::None).unwrap());
+        let full = encryptor.encrypt_symmetric(&Plaintext::from_hex("6").unwrap()).unwrap();
+        assert!(bytes.len() < full.save(ComprMode::None).unwrap().len());
+
+        let loaded = Ciphertext::load(&context, &bytes).expect("Ciphertext");
+        assert_eq!(decryptor.decrypt(&loaded).unwrap().to_string(), "6");
+
+        let zero = encryptor.encrypt_zero_symmetric_serializable().unwrap().save(ComprMode::None).unwrap();
+        let loaded = Ciphertext::load(&context, &zero).expect("Ciphertext");
+        assert_eq!(decryptor.decrypt(&loaded).unwrap().to_string(), "0");
+    }
+}
@@ -0,0 +1,313 @@
+use std::io::{Read, Write};
+
+use super::*;
+
+pub use super::ffi::ComprMode;
+
+// Every serialized SEAL object starts with a 16 byte header.
+// The total size of the object, header included, is the little-endian u64 at offset 8.
+const SEAL_HEADER_SIZE: usize = 16;
+const SEAL_HEADER_SIZE_OFFSET: usize = 8;
+
+/// Serializes SEAL objects, so keys and ciphertexts can cross process boundaries.
+///
+/// The bytes start with a SEAL header, so objects can be written back to back
+/// to one stream and read again one by one with `Load::load_from`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use zno_seal_sys::bgv::*;
+/// # let builder = ffi::set_poly_modulus_degree(ffi::init(0x3).unwrap(), 8192).unwrap();
+/// # let builder = ffi::set_coeff_modulus(builder, &ffi::coeff_modulus_bfv_default(8192).unwrap()).unwrap();
+/// # let builder = ffi::set_plain_modulus(builder, ffi::plain_modulus_batching(8192, 20).unwrap()).unwrap();
+/// # let context = Context::new(builder).expect("BGV context creation");
+/// let keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+/// let bytes = keygen.create_public_key().unwrap().save(ComprMode::Zstd).expect("PublicKey bytes");
+/// let public_key = PublicKey::load(&context, &bytes).expect("PublicKey");
+/// ```
+pub trait Save {
+    /// Returns an upper bound on the number of bytes `save` returns.
+    fn save_size(&self, compr_mode: ComprMode) -> Result<usize, FFIError>;
+
+    /// Serializes the object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::CppException` if SEAL was built without `compr_mode`.
+    fn save(&self, compr_mode: ComprMode) -> Result<Vec<u8>, FFIError>;
+
+    /// Serializes the object into `writer`, and returns the number of bytes written.
+    fn save_to<W: Write>(&self, writer: &mut W, compr_mode: ComprMode) -> Result<usize, FFIError> {
+        let bytes = self.save(compr_mode)?;
+        writer.write_all(&bytes)?;
+        Ok(bytes.len())
+    }
+}
+
+/// Deserializes SEAL objects saved with `Save`.
+///
+/// The loaded object is checked with `ValCheck::check_valid_for` before it is
+/// returned, so bytes from an untrusted party can be loaded.
+pub trait Load: Sized {
+    /// Deserializes an object from `bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::CppException` if the bytes are malformed, or
+    /// `FFIError::InvalidData` if the object is not valid for `context`.
+    fn load(context: &Context, bytes: &[u8]) -> Result<Self, FFIError>;
+
+    /// Deserializes one object from `reader`.
+    ///
+    /// Reads exactly the bytes of the object, so further objects can be read from the same stream.
+    fn load_from<R: Read>(context: &Context, reader: &mut R) -> Result<Self, FFIError> {
+        let bytes = read_object(reader)?;
+        Self::load(context, &bytes)
+    }
+}
+
+// Reads one serialized object: its header first, then the rest of the size the header reports.
+fn read_object<R: Read>(reader: &mut R) -> Result<Vec<u8>, FFIError> {
+    let mut bytes = vec![0u8; SEAL_HEADER_SIZE];
+    reader.read_exact(&mut bytes)?;
+
+    let mut size = [0u8; 8];
+    size.copy_from_slice(&bytes[SEAL_HEADER_SIZE_OFFSET..SEAL_HEADER_SIZE]);
+    let size = usize::try_from(u64::from_le_bytes(size))
+        .map_err(|err| FFIError::Io(err.to_string()))?;
+    if size < SEAL_HEADER_SIZE {
+        return Err(FFIError::Io(format!("invalid SEAL header: object size {}", size)));
+    }
+
+    // Read through `take` so a corrupt size cannot trigger a huge allocation up front.
+    reader.take((size - SEAL_HEADER_SIZE) as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != size {
+        return Err(FFIError::Io(format!("expected {} bytes, read {}", size, bytes.len())));
+    }
+    Ok(bytes)
+}
+
+// SEAL rejects a parms_id the context does not know while it parses, before
+// there is an object to check, so a failed load asks `is_foreign_parms_id`
+// whether that was the cause. The data is checked after loading, by `check_valid_for`.
+fn load_error(context: &Context, bytes: &[u8], err: cxx::Exception) -> FFIError {
+    if ffi::is_foreign_parms_id(&context.inner, bytes) {
+        FFIError::InvalidData("parms_id does not match the context".into())
+    } else {
+        FFIError::from(err)
+    }
+}
+
+pub(crate) fn to_usize(size: i64) -> Result<usize, FFIError> {
+    usize::try_from(size).map_err(|err| FFIError::Io(err.to_string()))
+}
+
+impl Save for Ciphertext {
+    fn save_size(&self, compr_mode: ComprMode) -> Result<usize, FFIError> {
+        to_usize(ffi::save_size_ciphertext(&self.inner, compr_mode)?)
+    }
+
+    fn save(&self, compr_mode: ComprMode) -> Result<Vec<u8>, FFIError> {
+        Ok(ffi::save_ciphertext(&self.inner, compr_mode)?)
+    }
+}
+
+impl Load for Ciphertext {
+    fn load(context: &Context, bytes: &[u8]) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::load_ciphertext(&context.inner, bytes).map_err(|err| load_error(context, bytes, err))?)?;
+        let obj = Ciphertext { inner };
+        obj.check_valid_for(context)?;
+        Ok(obj)
+    }
+}
+
+impl Save for Plaintext {
+    fn save_size(&self, compr_mode: ComprMode) -> Result<usize, FFIError> {
+        to_usize(ffi::save_size_plaintext(&self.inner, compr_mode)?)
+    }
+
+    fn save(&self, compr_mode: ComprMode) -> Result<Vec<u8>, FFIError> {
+        Ok(ffi::save_plaintext(&self.inner, compr_mode)?)
+    }
+}
+
+impl Load for Plaintext {
+    fn load(context: &Context, bytes: &[u8]) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::load_plaintext(&context.inner, bytes).map_err(|err| load_error(context, bytes, err))?)?;
+        let obj = Plaintext { inner };
+        obj.check_valid_for(context)?;
+        Ok(obj)
+    }
+}
+
+impl Save for SecretKey {
+    fn save_size(&self, compr_mode: ComprMode) -> Result<usize, FFIError> {
+        to_usize(ffi::save_size_secret_key(&self.inner, compr_mode)?)
+    }
+
+    fn save(&self, compr_mode: ComprMode) -> Result<Vec<u8>, FFIError> {
+        Ok(ffi::save_secret_key(&self.inner, compr_mode)?)
+    }
+}
+
+impl Load for SecretKey {
+    fn load(context: &Context, bytes: &[u8]) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::load_secret_key(&context.inner, bytes).map_err(|err| load_error(context, bytes, err))?)?;
+        let obj = SecretKey { inner };
+        obj.check_valid_for(context)?;
+        Ok(obj)
+    }
+}
+
+impl Save for PublicKey {
+    fn save_size(&self, compr_mode: ComprMode) -> Result<usize, FFIError> {
+        to_usize(ffi::save_size_public_key(&self.inner, compr_mode)?)
+    }
+
+    fn save(&self, compr_mode: ComprMode) -> Result<Vec<u8>, FFIError> {
+        Ok(ffi::save_public_key(&self.inner, compr_mode)?)
+    }
+}
+
+impl Load for PublicKey {
+    fn load(context: &Context, bytes: &[u8]) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::load_public_key(&context.inner, bytes).map_err(|err| load_error(context, bytes, err))?)?;
+        let obj = PublicKey { inner };
+        obj.check_valid_for(context)?;
+        Ok(obj)
+    }
+}
+
+impl Save for RelinKeys {
+    fn save_size(&self, compr_mode: ComprMode) -> Result<usize, FFIError> {
+        to_usize(ffi::save_size_relin_keys(&self.inner, compr_mode)?)
+    }
+
+    fn save(&self, compr_mode: ComprMode) -> Result<Vec<u8>, FFIError> {
+        Ok(ffi::save_relin_keys(&self.inner, compr_mode)?)
+    }
+}
+
+impl Load for RelinKeys {
+    fn load(context: &Context, bytes: &[u8]) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::load_relin_keys(&context.inner, bytes).map_err(|err| load_error(context, bytes, err))?)?;
+        let obj = RelinKeys { inner };
+        obj.check_valid_for(context)?;
+        Ok(obj)
+    }
+}
+
+impl Save for GaloisKeys {
+    fn save_size(&self, compr_mode: ComprMode) -> Result<usize, FFIError> {
+        to_usize(ffi::save_size_galois_keys(&self.inner, compr_mode)?)
+    }
+
+    fn save(&self, compr_mode: ComprMode) -> Result<Vec<u8>, FFIError> {
+        Ok(ffi::save_galois_keys(&self.inner, compr_mode)?)
+    }
+}
+
+impl Load for GaloisKeys {
+    fn load(context: &Context, bytes: &[u8]) -> Result<Self, FFIError> {
+        let inner = non_null(ffi::load_galois_keys(&context.inner, bytes).map_err(|err| load_error(context, bytes, err))?)?;
+        let obj = GaloisKeys { inner };
+        obj.check_valid_for(context)?;
+        Ok(obj)
+    }
+}
+
+// Encryption parameters are saved from, and loaded into, the builder a `Context` is built from.
+impl Save for ffi::BGVContextBuilder {
+    fn save_size(&self, compr_mode: ComprMode) -> Result<usize, FFIError> {
+        to_usize(ffi::save_size_encryption_parameters(self, compr_mode)?)
+    }
+
+    fn save(&self, compr_mode: ComprMode) -> Result<Vec<u8>, FFIError> {
+        Ok(ffi::save_encryption_parameters(self, compr_mode)?)
+    }
+}
+
+/// Deserializes encryption parameters saved from a `BGVContextBuilder`.
+///
+/// Unlike the other objects, encryption parameters need no `Context` to load:
+/// they are what a `Context` is built from.
+pub fn load_parameters(bytes: &[u8]) -> Result<cxx::UniquePtr<ffi::BGVContextBuilder>, FFIError> {
+    non_null(ffi::load_encryption_parameters(bytes)?)
+}
+
+/// Deserializes one set of encryption parameters from `reader`.
+pub fn load_parameters_from<R: Read>(reader: &mut R) -> Result<cxx::UniquePtr<ffi::BGVContextBuilder>, FFIError> {
+    let bytes = read_object(reader)?;
+    load_parameters(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bgv::testing::{setup_bgv_builder, setup_bgv_context};
+
+    #[test]
+    fn test_ciphertext_roundtrip() {
+        let context = setup_bgv_context();
+        let keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let encryptor = Encryptor::new(&context, &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+        let mut decryptor = Decryptor::new(&context, &keygen.secret_key().unwrap()).expect("Decryptor creation");
+
+        let encrypted = encryptor.encrypt(&Plaintext::from_hex("1x^1 + 3").unwrap()).expect("Ciphertext");
+        for compr_mode in [ComprMode::None, ComprMode::Zlib, ComprMode::Zstd] {
+            let bytes = encrypted.save(compr_mode).expect("Ciphertext bytes");
+            assert!(bytes.len() <= encrypted.save_size(compr_mode).unwrap());
+
+            let loaded = Ciphertext::load(&context, &bytes).expect("Ciphertext");
+            assert_eq!(decryptor.decrypt(&loaded).unwrap().to_string(), "1x^1 + 3");
+        }
+    }
+
+    #[test]
+    fn test_keys_roundtrip_through_one_stream() {
+        let context = setup_bgv_context();
+        let mut keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+
+        let mut stream = Vec::new();
+        keygen.secret_key().unwrap().save_to(&mut stream, ComprMode::Zstd).unwrap();
+        keygen.create_public_key().unwrap().save_to(&mut stream, ComprMode::Zstd).unwrap();
+        keygen.create_relin_keys().unwrap().save_to(&mut stream, ComprMode::Zstd).unwrap();
+        keygen.create_galois_keys_from_steps(&[1]).unwrap().save_to(&mut stream, ComprMode::Zstd).unwrap();
+
+        let mut reader = stream.as_slice();
+        assert!(SecretKey::load_from(&context, &mut reader).is_ok());
+        assert!(PublicKey::load_from(&context, &mut reader).is_ok());
+        assert!(RelinKeys::load_from(&context, &mut reader).is_ok());
+        assert!(GaloisKeys::load_from(&context, &mut reader).is_ok());
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn test_parameters_roundtrip() {
+        let bytes = setup_bgv_builder(8192).save(ComprMode::None).expect("EncryptionParameters bytes");
+        let context = Context::new(load_parameters(&bytes).unwrap()).expect("BGV context creation");
+        assert!(context.parameters_set());
+    }
+
+    #[test]
+    fn test_load_rejects_garbage() {
+        let context = setup_bgv_context();
+        assert!(matches!(Ciphertext::load(&context, &[0u8; 32]), Err(FFIError::CppException(_))));
+        assert!(matches!(Plaintext::load_from(&context, &mut &[0u8; 4][..]), Err(FFIError::Io(_))));
+    }
+
+    #[test]
+    fn test_load_rejects_other_context() {
+        let context = setup_bgv_context();
+        let other = Context::new(setup_bgv_builder(4096)).expect("BGV context creation");
+        let mut keygen = KeyGenerator::new(&other).expect("KeyGenerator creation");
+
+        for compr_mode in [ComprMode::None, ComprMode::Zlib, ComprMode::Zstd] {
+            let bytes = keygen.secret_key().unwrap().save(compr_mode).unwrap();
+            assert!(matches!(SecretKey::load(&context, &bytes), Err(FFIError::InvalidData(_))));
+            let bytes = keygen.create_relin_keys().unwrap().save(compr_mode).unwrap();
+            assert!(matches!(RelinKeys::load(&context, &bytes), Err(FFIError::InvalidData(_))));
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! Helpers for reproducible test vectors.
+//!
+//! Only compiled for this crate's tests or with the `test-rng` feature.
+//! Do not enable `test-rng` outside of `[dev-dependencies]`: keys created
+//! from seeded parameters are as secret as the seed.
+
+use super::*;
+
+// Kept out of `bgv::ffi`, so that the seeded PRNG is not reachable from a
+// build without `test-rng`.
+#[cxx::bridge(namespace="seal")]
+mod rng {
+    unsafe extern "C++" {
+        include!("zno-seal-sys/ffi/ffi_wrapper.h");
+
+        type BGVContextBuilder = crate::bgv::ffi::BGVContextBuilder;
+
+        fn set_random_generator_seed(builder: UniquePtr<BGVContextBuilder>, seed: &[u64]) -> Result<UniquePtr<BGVContextBuilder>>;
+    }
+}
+
+/// Installs a PRNG factory seeded with `seed` on the encryption parameters.
+///
+/// Every `KeyGenerator` and `Encryptor` created from the resulting `Context`
+/// draws its randomness from this seed, so the same seed and the same
+/// sequence of calls produce byte-identical keys and ciphertexts. Without
+/// this call SEAL seeds each generator from system entropy.
+///
+/// The seed is not part of the `ParmsId` and is not saved with the parameters.
+///
+/// # Example
+///
+/// ```no_run
+/// # use zno_seal_sys::bgv::*;
+/// let builder = ffi::set_poly_modulus_degree(ffi::init(0x3).unwrap(), 8192).unwrap();
+/// let builder = ffi::set_coeff_modulus(builder, &ffi::coeff_modulus_bfv_default(8192).unwrap()).unwrap();
+/// let builder = ffi::set_plain_modulus(builder, ffi::plain_modulus_batching(8192, 20).unwrap()).unwrap();
+/// let builder = testing::set_seed(builder, &[42; 8]).unwrap();
+/// let context = Context::new(builder).expect("BGV context creation");
+/// ```
+pub fn set_seed(
+    builder: cxx::UniquePtr<ffi::BGVContextBuilder>,
+    seed: &[u64; 8],
+) -> Result<cxx::UniquePtr<ffi::BGVContextBuilder>, FFIError> {
+    non_null(rng::set_random_generator_seed(builder, seed)?)
+}
+
+/// Returns the parameters the tests build on: BGV of degree `poly_modulus_degree`,
+/// with SEAL's default coefficient modulus and a 20-bit plain modulus that supports batching.
+#[cfg(test)]
+pub(crate) fn setup_bgv_builder(poly_modulus_degree: u64) -> cxx::UniquePtr<ffi::BGVContextBuilder> {
+    let builder = ffi::set_poly_modulus_degree(ffi::init(0x3).unwrap(), poly_modulus_degree).unwrap();
+    let builder = ffi::set_coeff_modulus(builder, &ffi::coeff_modulus_bfv_default(poly_modulus_degree).unwrap()).unwrap();
+    ffi::set_plain_modulus(builder, ffi::plain_modulus_batching(poly_modulus_degree, 20).unwrap()).unwrap()
+}
+
+/// Returns the BGV context the tests run against, of degree 8192.
+#[cfg(test)]
+pub(crate) fn setup_bgv_context() -> Context {
+    Context::new(setup_bgv_builder(8192)).expect("BGV context creation")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Helper function to create a Context seeded with `seed`.
+    fn setup_seeded_context(seed: &[u64; 8]) -> Context {
+        Context::new(set_seed(setup_bgv_builder(8192), seed).unwrap()).expect("BGV context creation")
+    }
+
+    // Creates keys and a ciphertext, and returns them saved.
+    fn run(context: &Context) -> (Vec<u8>, Vec<u8>) {
+        let keygen = KeyGenerator::new(context).expect("KeyGenerator creation");
+        let public_key = keygen.create_public_key().unwrap();
+        let encryptor = Encryptor::new(context, &public_key).expect("Encryptor creation");
+        let encrypted = encryptor.encrypt(&Plaintext::from_hex("2A").unwrap()).unwrap();
+        (public_key.save(ComprMode::None).unwrap(), encrypted.save(ComprMode::None).unwrap())
+    }
+
+    #[test]
+    fn test_same_seed_is_reproducible() {
+        let first = run(&setup_seeded_context(&[1, 2, 3, 4, 5, 6, 7, 8]));
+        let second = run(&setup_seeded_context(&[1, 2, 3, 4, 5, 6, 7, 8]));
+        assert_eq!(first, second);
+
+        let other = run(&setup_seeded_context(&[8, 7, 6, 5, 4, 3, 2, 1]));
+        assert_ne!(first.1, other.1);
+    }
+}
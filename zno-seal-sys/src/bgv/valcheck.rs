@@ -0,0 +1,196 @@
+use super::*;
+
+/// Checks that a SEAL object is valid for a `Context`, binding SEAL's `valcheck.h`.
+///
+/// An object that does not match the `Context` it is used with leads to
+/// undefined behaviour in SEAL, so anything received from an untrusted party
+/// must be checked first. `Load::load` runs `check_valid_for` on every object
+/// it returns; call it directly for objects built some other way.
+///
+/// # Example
+///
+/// ```no_run
+/// # use zno_seal_sys::bgv::*;
+/// # let builder = ffi::set_poly_modulus_degree(ffi::init(0x3).unwrap(), 8192).unwrap();
+/// # let builder = ffi::set_coeff_modulus(builder, &ffi::coeff_modulus_bfv_default(8192).unwrap()).unwrap();
+/// # let builder = ffi::set_plain_modulus(builder, ffi::plain_modulus_batching(8192, 20).unwrap()).unwrap();
+/// # let context = Context::new(builder).expect("BGV context creation");
+/// # let bytes: Vec<u8> = Vec::new();
+/// match Ciphertext::load(&context, &bytes) {
+///     Ok(encrypted) => assert!(encrypted.is_valid_for(&context)),
+///     Err(FFIError::InvalidData(reason)) => eprintln!("rejected: {}", reason),
+///     Err(err) => eprintln!("malformed: {}", err),
+/// }
+/// ```
+pub trait ValCheck {
+    /// Returns true if the metadata, the buffer size and the data are all valid for `context`.
+    fn is_valid_for(&self, context: &Context) -> bool;
+
+    /// Returns true if the metadata, such as the `parms_id`, matches `context`.
+    ///
+    /// The data itself is not checked.
+    fn is_metadata_valid_for(&self, context: &Context) -> bool;
+
+    /// Returns true if the buffer has the size the metadata claims.
+    fn is_buffer_valid(&self) -> bool;
+
+    /// Checks the object in the same order as SEAL, and reports the first check that fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FFIError::InvalidData` if the object is not valid for `context`.
+    fn check_valid_for(&self, context: &Context) -> Result<(), FFIError> {
+        if !self.is_metadata_valid_for(context) {
+            return Err(FFIError::InvalidData("metadata does not match the context".into()));
+        }
+        if !self.is_buffer_valid() {
+            return Err(FFIError::InvalidData("buffer size does not match the metadata".into()));
+        }
+        if !self.is_valid_for(context) {
+            return Err(FFIError::InvalidData("data is out of range for the context".into()));
+        }
+        Ok(())
+    }
+}
+
+impl ValCheck for Ciphertext {
+    fn is_valid_for(&self, context: &Context) -> bool {
+        ffi::is_valid_for_ciphertext(&self.inner, &context.inner)
+    }
+
+    fn is_metadata_valid_for(&self, context: &Context) -> bool {
+        ffi::is_metadata_valid_for_ciphertext(&self.inner, &context.inner)
+    }
+
+    fn is_buffer_valid(&self) -> bool {
+        ffi::is_buffer_valid_ciphertext(&self.inner)
+    }
+}
+
+impl ValCheck for Plaintext {
+    fn is_valid_for(&self, context: &Context) -> bool {
+        ffi::is_valid_for_plaintext(&self.inner, &context.inner)
+    }
+
+    fn is_metadata_valid_for(&self, context: &Context) -> bool {
+        ffi::is_metadata_valid_for_plaintext(&self.inner, &context.inner)
+    }
+
+    fn is_buffer_valid(&self) -> bool {
+        ffi::is_buffer_valid_plaintext(&self.inner)
+    }
+}
+
+impl ValCheck for SecretKey {
+    fn is_valid_for(&self, context: &Context) -> bool {
+        ffi::is_valid_for_secret_key(&self.inner, &context.inner)
+    }
+
+    fn is_metadata_valid_for(&self, context: &Context) -> bool {
+        ffi::is_metadata_valid_for_secret_key(&self.inner, &context.inner)
+    }
+
+    fn is_buffer_valid(&self) -> bool {
+        ffi::is_buffer_valid_secret_key(&self.inner)
+    }
+}
+
+impl ValCheck for PublicKey {
+    fn is_valid_for(&self, context: &Context) -> bool {
+        ffi::is_valid_for_public_key(&self.inner, &context.inner)
+    }
+
+    fn is_metadata_valid_for(&self, context: &Context) -> bool {
+        ffi::is_metadata_valid_for_public_key(&self.inner, &context.inner)
+    }
+
+    fn is_buffer_valid(&self) -> bool {
+        ffi::is_buffer_valid_public_key(&self.inner)
+    }
+}
+
+impl ValCheck for RelinKeys {
+    fn is_valid_for(&self, context: &Context) -> bool {
+        ffi::is_valid_for_relin_keys(&self.inner, &context.inner)
+    }
+
+    fn is_metadata_valid_for(&self, context: &Context) -> bool {
+        ffi::is_metadata_valid_for_relin_keys(&self.inner, &context.inner)
+    }
+
+    fn is_buffer_valid(&self) -> bool {
+        ffi::is_buffer_valid_relin_keys(&self.inner)
+    }
+}
+
+impl ValCheck for GaloisKeys {
+    fn is_valid_for(&self, context: &Context) -> bool {
+        ffi::is_valid_for_galois_keys(&self.inner, &context.inner)
+    }
+
+    fn is_metadata_valid_for(&self, context: &Context) -> bool {
+        ffi::is_metadata_valid_for_galois_keys(&self.inner, &context.inner)
+    }
+
+    fn is_buffer_valid(&self) -> bool {
+        ffi::is_buffer_valid_galois_keys(&self.inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bgv::testing::{setup_bgv_builder, setup_bgv_context};
+
+    // A second context with a different coefficient modulus, so none of its `parms_id`s match.
+    fn setup_other_context() -> Context {
+        Context::new(setup_bgv_builder(4096)).expect("BGV context creation")
+    }
+
+    #[test]
+    fn test_generated_objects_are_valid() {
+        let context = setup_bgv_context();
+        let mut keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let public_key = keygen.create_public_key().unwrap();
+        let encryptor = Encryptor::new(&context, &public_key).expect("Encryptor creation");
+        let encrypted = encryptor.encrypt(&Plaintext::from_hex("3").unwrap()).unwrap();
+
+        assert!(encrypted.check_valid_for(&context).is_ok());
+        assert!(public_key.check_valid_for(&context).is_ok());
+        assert!(keygen.secret_key().unwrap().check_valid_for(&context).is_ok());
+        assert!(keygen.create_relin_keys().unwrap().check_valid_for(&context).is_ok());
+        assert!(encrypted.is_buffer_valid());
+    }
+
+    #[test]
+    fn test_other_context_is_rejected() {
+        let context = setup_bgv_context();
+        let other = setup_other_context();
+        let keygen = KeyGenerator::new(&other).expect("KeyGenerator creation");
+        let encryptor = Encryptor::new(&other, &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+        let encrypted = encryptor.encrypt(&Plaintext::from_hex("3").unwrap()).unwrap();
+
+        assert!(!encrypted.is_metadata_valid_for(&context));
+        assert!(matches!(encrypted.check_valid_for(&context), Err(FFIError::InvalidData(_))));
+
+        let bytes = encrypted.save(ComprMode::None).unwrap();
+        assert!(matches!(Ciphertext::load(&context, &bytes), Err(FFIError::InvalidData(_))));
+        let bytes = keygen.create_public_key().unwrap().save(ComprMode::None).unwrap();
+        assert!(matches!(PublicKey::load(&context, &bytes), Err(FFIError::InvalidData(_))));
+    }
+
+    #[test]
+    fn test_out_of_range_data_is_rejected() {
+        let context = setup_bgv_context();
+        let keygen = KeyGenerator::new(&context).expect("KeyGenerator creation");
+        let encryptor = Encryptor::new(&context, &keygen.create_public_key().unwrap()).expect("Encryptor creation");
+        let encrypted = encryptor.encrypt(&Plaintext::from_hex("3").unwrap()).unwrap();
+
+        // Set the first coefficient to u64::MAX, above every prime of the coefficient modulus.
+        let mut bytes = encrypted.save(ComprMode::None).unwrap();
+        let data = bytes.len() - encrypted.size() * 8192 * context.first_context_data().unwrap().coeff_modulus().len() * 8;
+        bytes[data..data + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+
+        assert!(matches!(Ciphertext::load(&context, &bytes), Err(FFIError::InvalidData(_))));
+    }
+}